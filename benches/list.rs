@@ -0,0 +1,149 @@
+//! Benchmarks comparing `List` against the standard library's sequences.
+#[macro_use] extern crate criterion;
+extern crate an_zipper;
+
+use std::collections::{LinkedList, VecDeque};
+
+use an_zipper::{List, Stack};
+use criterion::{black_box, BenchmarkId, Criterion};
+
+const SIZES: &[usize] = &[100, 10_000];
+
+fn push_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop");
+    for &n in SIZES {
+        group.bench_with_input(BenchmarkId::new("List", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = List::new();
+                for i in 0..n { list.push(i); }
+                while let Some(i) = list.pop() { black_box(i); }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut vec = Vec::new();
+                for i in 0..n { vec.push(i); }
+                while let Some(i) = vec.pop() { black_box(i); }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = VecDeque::new();
+                for i in 0..n { deque.push_front(i); }
+                while let Some(i) = deque.pop_front() { black_box(i); }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("LinkedList", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..n { list.push_front(i); }
+                while let Some(i) = list.pop_front() { black_box(i); }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Repeatedly push and pop a handful of items on an existing collection,
+/// which is where reusing nodes from the freelist pays off.
+fn oscillate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("oscillate");
+    group.bench_function("List", |b| {
+        let mut list = (0..100usize).collect::<List<_>>();
+        b.iter(|| {
+            for i in 0..8 { list.push(i); }
+            for _ in 0..8 { black_box(list.pop()); }
+        })
+    });
+    group.bench_function("Vec", |b| {
+        let mut vec = (0..100usize).collect::<Vec<_>>();
+        b.iter(|| {
+            for i in 0..8 { vec.push(i); }
+            for _ in 0..8 { black_box(vec.pop()); }
+        })
+    });
+    group.bench_function("LinkedList", |b| {
+        let mut list = (0..100usize).collect::<LinkedList<_>>();
+        b.iter(|| {
+            for i in 0..8 { list.push_front(i); }
+            for _ in 0..8 { black_box(list.pop_front()); }
+        })
+    });
+    group.finish();
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+    for &n in SIZES {
+        let list = (0..n).collect::<List<_>>();
+        let vec = (0..n).collect::<Vec<_>>();
+        let deque = (0..n).collect::<VecDeque<_>>();
+        let linked = (0..n).collect::<LinkedList<_>>();
+        group.bench_with_input(BenchmarkId::new("List", n), &list, |b, list| {
+            b.iter(|| list.iter().sum::<usize>())
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &vec, |b, vec| {
+            b.iter(|| vec.iter().sum::<usize>())
+        });
+        group.bench_with_input( BenchmarkId::new("VecDeque", n), &deque
+                              , |b, deque| b.iter(|| deque.iter().sum::<usize>())
+                              );
+        group.bench_with_input( BenchmarkId::new("LinkedList", n), &linked
+                              , |b, list| b.iter(|| list.iter().sum::<usize>())
+                              );
+    }
+    group.finish();
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    for &n in SIZES {
+        let list = (0..n).collect::<List<_>>();
+        let vec = (0..n).collect::<Vec<_>>();
+        let deque = (0..n).collect::<VecDeque<_>>();
+        let linked = (0..n).collect::<LinkedList<_>>();
+        group.bench_with_input(BenchmarkId::new("List", n), &list, |b, list| {
+            b.iter(|| list.clone())
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &vec, |b, vec| {
+            b.iter(|| vec.clone())
+        });
+        group.bench_with_input( BenchmarkId::new("VecDeque", n), &deque
+                              , |b, deque| b.iter(|| deque.clone())
+                              );
+        group.bench_with_input( BenchmarkId::new("LinkedList", n), &linked
+                              , |b, list| b.iter(|| list.clone())
+                              );
+    }
+    group.finish();
+}
+
+/// Replace a handful of elements in the middle of a collection.
+fn splice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("splice");
+    for &n in SIZES {
+        let mid = n / 2;
+        group.bench_with_input(BenchmarkId::new("List", n), &n, |b, &n| {
+            let mut list = (0..n).collect::<List<_>>();
+            b.iter(|| black_box(list.splice(mid..mid + 8, 0..8)))
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &n, |b, &n| {
+            let mut vec = (0..n).collect::<Vec<_>>();
+            b.iter(|| black_box(vec.splice(mid..mid + 8, 0..8).count()))
+        });
+        group.bench_with_input(BenchmarkId::new("LinkedList", n), &n, |b, &n| {
+            let mut list = (0..n).collect::<LinkedList<_>>();
+            b.iter(|| {
+                let mut removed = list.split_off(mid);
+                let rest = removed.split_off(8);
+                list.extend(0..8);
+                list.extend(rest);
+                black_box(removed)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, push_pop, oscillate, iterate, clone, splice);
+criterion_main!(benches);
@@ -0,0 +1,120 @@
+//! Benchmarks for moving and editing at a `ZipList`'s cursor.
+//!
+//! The baselines are a pair of `Vec`s used as a zipper (the same structure
+//! as a `ZipList`, with contiguous storage), and a `Vec` or `VecDeque` with a
+//! cursor index.
+#[macro_use] extern crate criterion;
+extern crate an_zipper;
+
+use std::collections::VecDeque;
+
+use an_zipper::ZipList;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+const SIZES: &[usize] = &[100, 10_000];
+
+/// Returns a `ZipList` of `n` elements, with the cursor at the start.
+fn zip_list(n: usize) -> ZipList<usize> {
+    let mut zipper = ZipList::new();
+    for i in 0..n { zipper.push_left(i); }
+    zipper.seek_left(n);
+    zipper
+}
+
+/// Seek from one end of the sequence to the other, and back again.
+fn seek(c: &mut Criterion) {
+    let mut group = c.benchmark_group("seek");
+    for &n in SIZES {
+        group.bench_with_input(BenchmarkId::new("ZipList", n), &n, |b, &n| {
+            let mut zipper = zip_list(n);
+            b.iter(|| {
+                black_box(zipper.seek_right(n));
+                black_box(zipper.seek_left(n));
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Vec pair", n), &n, |b, &n| {
+            let mut left = Vec::new();
+            let mut right = (0..n).rev().collect::<Vec<_>>();
+            b.iter(|| {
+                while let Some(i) = right.pop() { left.push(i); }
+                while let Some(i) = left.pop() { right.push(i); }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", n), &n, |b, &n| {
+            let mut deque = (0..n).collect::<VecDeque<_>>();
+            b.iter(|| {
+                deque.rotate_left(n);
+                deque.rotate_right(n);
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Insert a run of elements at a cursor in the middle of the sequence.
+fn insert_at_cursor(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_at_cursor");
+    for &n in SIZES {
+        group.bench_with_input(BenchmarkId::new("ZipList", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut zipper = zip_list(n);
+                zipper.seek_right(n / 2);
+                for i in 0..100 { zipper.push_left(i); }
+                zipper
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut vec = (0..n).collect::<Vec<_>>();
+                for i in 0..100 { vec.insert(n / 2 + i, i); }
+                vec
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = (0..n).collect::<VecDeque<_>>();
+                for i in 0..100 { deque.insert(n / 2 + i, i); }
+                deque
+            })
+        });
+    }
+    group.finish();
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zipper_iterate");
+    for &n in SIZES {
+        let mut zipper = zip_list(n);
+        zipper.seek_right(n / 2);
+        group.bench_with_input( BenchmarkId::new("ZipList", n), &zipper
+                              , |b, zipper| b.iter(|| {
+                                    zipper.left_iter()
+                                          .chain(zipper.right_iter())
+                                          .sum::<usize>()
+                                }));
+        let vec = (0..n).collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::new("Vec", n), &vec, |b, vec| {
+            b.iter(|| vec.iter().sum::<usize>())
+        });
+    }
+    group.finish();
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zipper_clone");
+    for &n in SIZES {
+        let mut zipper = zip_list(n);
+        zipper.seek_right(n / 2);
+        group.bench_with_input( BenchmarkId::new("ZipList", n), &zipper
+                              , |b, zipper| b.iter(|| zipper.clone())
+                              );
+        let vec = (0..n).collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::new("Vec", n), &vec, |b, vec| {
+            b.iter(|| vec.clone())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, seek, insert_at_cursor, iterate, clone);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Applies a tape of stack operations decoded from the fuzzer's input to a
+//! `List`, checking it against a `Vec` after every step.
+#![no_main]
+use an_zipper::{List, Stack};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut list = List::new();
+    // the top of the stack is the end of the vec
+    let mut model = Vec::new();
+    for op in data.chunks_exact(2) {
+        let arg = op[1];
+        match op[0] % 8 {
+            0 | 1 => {
+                list.push(arg);
+                model.push(arg);
+            }
+            2 => assert_eq!(list.pop(), model.pop()),
+            3 => {
+                if let Some(top) = list.peek_mut() { *top = arg }
+                if let Some(top) = model.last_mut() { *top = arg }
+            }
+            4 => {
+                // collecting pushes each element in turn, reversing the list
+                list = list.into_iter().collect();
+                model.reverse();
+            }
+            5 => {
+                // keep the `i`th element from the top if bit `i % 8` of the
+                // argument is set
+                let mut i = 0;
+                list.retain_mut(|_| {
+                    i += 1;
+                    arg >> ((i - 1) % 8) & 1 == 1
+                });
+                let mut i = model.len();
+                model.retain(|_| {
+                    i -= 1;
+                    arg >> (i % 8) & 1 == 1
+                });
+            }
+            6 => {
+                // replace up to 3 elements, starting up to 3 from the top,
+                // with a run of up to 3 elements counting up from `arg`
+                let len = list.len();
+                let start = usize::from(arg & 3).min(len);
+                let end = (start + usize::from(arg >> 2 & 3)).min(len);
+                let run = (0..arg >> 4 & 3).map(|i| arg.wrapping_add(i))
+                                           .collect::<Vec<_>>();
+                let removed = list.splice(start..end, run.iter().cloned());
+                let removed_from_model = model
+                    .splice(len - end..len - start, run.iter().rev().cloned())
+                    .rev()
+                    .collect::<Vec<_>>();
+                assert!(removed.iter().eq(removed_from_model.iter()));
+            }
+            _ => list.shrink_to_fit(),
+        }
+        assert_eq!(list.len(), model.len());
+        assert_eq!(list.peek(), model.last());
+        assert!(list.iter().eq(model.iter().rev()));
+    }
+});
@@ -0,0 +1,11 @@
+//! Applies a tape of operations decoded from the fuzzer's input to a
+//! `ZipList`, checking it against the reference model after every step.
+#![no_main]
+use an_zipper::model::{check, decode_ops};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Err(mismatch) = check(decode_ops(data)) {
+        panic!("{}", mismatch);
+    }
+});
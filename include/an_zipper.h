@@ -0,0 +1,51 @@
+/*
+ * C API for an-zipper, built with the `ffi` feature as a static or shared
+ * library:
+ *
+ *     cargo rustc --release --lib --features ffi --crate-type staticlib
+ *     cargo rustc --release --lib --features ffi --crate-type cdylib
+ *
+ * Elements are untyped pointers which the lists store but never dereference
+ * or free. Functions returning an element write it through `out`, which may
+ * be NULL, and return whether there was one.
+ */
+#ifndef AN_ZIPPER_H
+#define AN_ZIPPER_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct anzipper_list anzipper_list;
+typedef struct anzipper_zip anzipper_zip;
+
+anzipper_list *anzipper_list_new(void);
+void anzipper_list_free(anzipper_list *list);
+size_t anzipper_list_len(const anzipper_list *list);
+void anzipper_list_push(anzipper_list *list, void *elem);
+bool anzipper_list_pop(anzipper_list *list, void **out);
+bool anzipper_list_peek(const anzipper_list *list, void **out);
+
+anzipper_zip *anzipper_zip_new(void);
+void anzipper_zip_free(anzipper_zip *zip);
+size_t anzipper_zip_len(const anzipper_zip *zip);
+size_t anzipper_zip_cursor(const anzipper_zip *zip);
+void anzipper_zip_push_left(anzipper_zip *zip, void *elem);
+void anzipper_zip_push_right(anzipper_zip *zip, void *elem);
+bool anzipper_zip_pop_left(anzipper_zip *zip, void **out);
+bool anzipper_zip_pop_right(anzipper_zip *zip, void **out);
+bool anzipper_zip_peek_left(const anzipper_zip *zip, void **out);
+bool anzipper_zip_peek_right(const anzipper_zip *zip, void **out);
+bool anzipper_zip_move_left(anzipper_zip *zip);
+bool anzipper_zip_move_right(anzipper_zip *zip);
+size_t anzipper_zip_seek_left(anzipper_zip *zip, size_t n);
+size_t anzipper_zip_seek_right(anzipper_zip *zip, size_t n);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
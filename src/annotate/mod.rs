@@ -0,0 +1,350 @@
+//! Metadata attached to ranges of a zipper's elements.
+//!
+//! An [`AnnotatedZipList`] keeps a set of spans, each covering a range of
+//! elements and carrying some metadata, such as a syntax highlighting class
+//! or a diagnostic. As elements are inserted and removed at the cursor, the
+//! spans are kept covering the same elements:
+//!
+//! - elements inserted inside a span become part of it, while elements
+//!   inserted at either end of a span don't,
+//! - removing elements shrinks the spans they were in, and spans with no
+//!   elements left are removed,
+//! - when removing elements leaves two spans with equal metadata next to
+//!   each other at the cursor, they're merged into one.
+//!
+//! Like the zipper itself, the spans are split around the cursor: those
+//! entirely to its left are positioned relative to the start, those
+//! entirely to its right are positioned relative to the end, and those
+//! around it are positioned relative to both. Edits at the cursor can't move
+//! the start or end of a span relative to what it's positioned against, so
+//! only the spans which start or end at the cursor ever need updating.
+//!
+//! [`AnnotatedZipList`]: struct.AnnotatedZipList.html
+use std::ops::{Deref, Range};
+
+use super::ZipList;
+
+#[cfg(test)] mod test;
+
+/// A span of elements, with bounds that are positioned either from the
+/// start or from the end, depending on which side of the cursor it's on.
+#[derive(Clone, Debug)]
+struct Span<M> { start: usize
+               , end: usize
+               , meta: M
+               }
+
+/// A `ZipList` with metadata attached to ranges of its elements.
+///
+/// This dereferences to the underlying `ZipList` for reading. Changes have to
+/// be made through the methods on `AnnotatedZipList`, so that the spans are
+/// updated.
+#[derive(Clone, Debug)]
+pub struct AnnotatedZipList<T, M> { zipper: ZipList<T>
+                                  , /// Spans ending at or before the cursor,
+                                    /// by position from the start, sorted
+                                    /// by end.
+                                    left: Vec<Span<M>>
+                                  , /// Spans starting before and ending
+                                    /// after the cursor, with `start` from
+                                    /// the start and `end` from the end.
+                                    around: Vec<Span<M>>
+                                  , /// Spans starting at or after the
+                                    /// cursor, by position from the end,
+                                    /// sorted so the last starts first.
+                                    right: Vec<Span<M>>
+                                  }
+
+impl<T, M> AnnotatedZipList<T, M> {
+    /// Start annotating `zipper`, with no spans.
+    pub fn new(zipper: ZipList<T>) -> Self {
+        AnnotatedZipList { zipper
+                         , left: Vec::new()
+                         , around: Vec::new()
+                         , right: Vec::new()
+                         }
+    }
+
+    /// Returns the number of spans.
+    pub fn span_count(&self) -> usize {
+        self.left.len() + self.around.len() + self.right.len()
+    }
+
+    /// Returns every span's range of positions and metadata, sorted by the
+    /// start of the range.
+    pub fn spans(&self) -> Vec<(Range<usize>, &M)> {
+        let len = self.zipper.len();
+        let mut spans = self.left.iter()
+            .map(|span| (span.start..span.end, &span.meta))
+            .chain(self.around.iter()
+                       .map(|span| (span.start..len - span.end, &span.meta)))
+            .chain(self.right.iter()
+                       .map(|span| ( len - span.start..len - span.end
+                                   , &span.meta )))
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|(range, _)| (range.start, range.end));
+        spans
+    }
+
+    /// Returns the metadata of every span containing the element at
+    /// `index`.
+    pub fn spans_at(&self, index: usize) -> Vec<&M> {
+        self.spans().into_iter()
+            .filter(|(range, _)| range.contains(&index))
+            .map(|(_, meta)| meta)
+            .collect()
+    }
+
+    /// Stop annotating, returning the zipper and the spans, sorted by the
+    /// start of their ranges.
+    pub fn into_inner(mut self) -> (ZipList<T>, Vec<(Range<usize>, M)>) {
+        let spans = self.drain_spans();
+        (self.zipper, spans)
+    }
+
+    /// Remove every span, returning their ranges and metadata sorted by the
+    /// start of their ranges.
+    fn drain_spans(&mut self) -> Vec<(Range<usize>, M)> {
+        let len = self.zipper.len();
+        let mut spans = self.left.drain(..)
+            .map(|span| (span.start..span.end, span.meta))
+            .chain(self.around.drain(..)
+                       .map(|span| (span.start..len - span.end, span.meta)))
+            .chain(self.right.drain(..)
+                       .map(|span| ( len - span.start..len - span.end
+                                   , span.meta )))
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|(range, _)| (range.start, range.end));
+        spans
+    }
+
+    /// Push `elem` to the left of the zipper.
+    ///
+    /// It becomes part of any spans around the cursor.
+    pub fn push_left(&mut self, elem: T) -> &mut Self {
+        self.zipper.push_left(elem);
+        self
+    }
+
+    /// Push `elem` to the right of the zipper.
+    ///
+    /// It becomes part of any spans around the cursor.
+    pub fn push_right(&mut self, elem: T) -> &mut Self {
+        self.zipper.push_right(elem);
+        self
+    }
+
+    /// Move the zipper one position to the left.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved to the left
+    /// - `false` if the zipper is already at the left
+    pub fn move_left(&mut self) -> bool {
+        if !self.zipper.move_left() { return false }
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        while self.left.last().map(|span| span.end) == Some(cursor + 1) {
+            let span = self.left.pop().expect("peeked a span");
+            self.around.push(Span { end: len - cursor - 1, ..span });
+        }
+        let mut i = 0;
+        while i < self.around.len() {
+            if self.around[i].start == cursor {
+                let span = self.around.swap_remove(i);
+                self.right.push(Span { start: len - cursor, ..span });
+            } else {
+                i += 1;
+            }
+        }
+        true
+    }
+
+    /// Move the zipper one position to the right.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved to the right
+    /// - `false` if the zipper is already at the right
+    pub fn move_right(&mut self) -> bool {
+        if !self.zipper.move_right() { return false }
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        let start = len - cursor + 1;
+        while self.right.last().map(|span| span.start) == Some(start) {
+            let span = self.right.pop().expect("peeked a span");
+            self.around.push(Span { start: cursor - 1, ..span });
+        }
+        let mut i = 0;
+        while i < self.around.len() {
+            if self.around[i].end == len - cursor {
+                let span = self.around.swap_remove(i);
+                self.left.push(Span { end: cursor, ..span });
+            } else {
+                i += 1;
+            }
+        }
+        true
+    }
+
+    /// Move the zipper `n` positions to the left.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   zipper reached the end of the list before it finished moving.
+    ///
+    /// # Time complexity
+    /// O(`n` × the number of spans around the cursor)
+    pub fn seek_left(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_left()).count()
+    }
+
+    /// Move the zipper `n` positions to the right.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   zipper reached the end of the list before it finished moving.
+    ///
+    /// # Time complexity
+    /// O(`n` × the number of spans around the cursor)
+    pub fn seek_right(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_right()).count()
+    }
+
+    /// Returns the cursor's position.
+    #[inline] fn cursor(&self) -> usize { self.zipper.left.len() }
+
+    /// Add a span covering `range` to whichever set of spans it belongs in.
+    fn insert(&mut self, range: Range<usize>, meta: M) {
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        if range.end <= cursor {
+            let at = self.left.iter().position(|span| span.end > range.end)
+                         .unwrap_or(self.left.len());
+            self.left.insert(at, Span { start: range.start
+                                      , end: range.end
+                                      , meta });
+        } else if range.start >= cursor {
+            let (start, end) = (len - range.start, len - range.end);
+            let at = self.right.iter().position(|span| span.start > start)
+                         .unwrap_or(self.right.len());
+            self.right.insert(at, Span { start, end, meta });
+        } else {
+            self.around.push(Span { start: range.start
+                                  , end: len - range.end
+                                  , meta });
+        }
+    }
+}
+
+impl<T, M> AnnotatedZipList<T, M>
+where M: PartialEq {
+    /// Attach `meta` to the elements in `range`.
+    ///
+    /// If the range overlaps or touches a span with equal metadata, they're
+    /// merged into one span. An empty range is ignored.
+    ///
+    /// # Panics
+    /// If `range` ends past the end of the zipper, or starts after it ends.
+    pub fn annotate(&mut self, range: Range<usize>, meta: M) {
+        assert!( range.start <= range.end && range.end <= self.zipper.len()
+               , "span {:?} out of bounds for length {}"
+               , range, self.zipper.len() );
+        if range.start == range.end { return }
+
+        // spans with equal metadata never touch, so merging with one can't
+        // make the range touch another that it didn't already
+        let mut merged = range;
+        for (range, other) in self.drain_spans() {
+            if other == meta && range.start <= merged.end
+                             && merged.start <= range.end {
+                merged = merged.start.min(range.start)
+                       ..merged.end.max(range.end);
+            } else {
+                self.insert(range, other);
+            }
+        }
+        self.insert(merged, meta);
+    }
+
+    /// Pop the item to the left of the zipper and return it.
+    ///
+    /// The spans containing it are shrunk, and removed if it was their only
+    /// element.
+    pub fn pop_left(&mut self) -> Option<T> {
+        let elem = self.zipper.pop_left()?;
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        // spans ending at the old cursor contained the element
+        let shrunk = self.left.iter().rev()
+                         .take_while(|span| span.end == cursor + 1)
+                         .count();
+        let at = self.left.len() - shrunk;
+        for mut span in self.left.split_off(at) {
+            span.end -= 1;
+            if span.start < span.end { self.left.push(span) }
+        }
+        // spans around the old cursor which started at the element now
+        // start at the cursor
+        let mut i = 0;
+        while i < self.around.len() {
+            if self.around[i].start == cursor {
+                let span = self.around.swap_remove(i);
+                self.right.push(Span { start: len - cursor, ..span });
+            } else {
+                i += 1;
+            }
+        }
+        self.merge_at_cursor();
+        Some(elem)
+    }
+
+    /// Pop the item to the right of the zipper and return it.
+    ///
+    /// The spans containing it are shrunk, and removed if it was their only
+    /// element.
+    pub fn pop_right(&mut self) -> Option<T> {
+        let elem = self.zipper.pop_right()?;
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        // spans starting at the cursor contained the element
+        let shrunk = self.right.iter().rev()
+                         .take_while(|span| span.start == len + 1 - cursor)
+                         .count();
+        let at = self.right.len() - shrunk;
+        for mut span in self.right.split_off(at) {
+            span.start -= 1;
+            if span.start > span.end { self.right.push(span) }
+        }
+        // spans around the cursor which ended at the element now end at the
+        // cursor
+        let mut i = 0;
+        while i < self.around.len() {
+            if self.around[i].end == len - cursor {
+                let span = self.around.swap_remove(i);
+                self.left.push(Span { end: cursor, ..span });
+            } else {
+                i += 1;
+            }
+        }
+        self.merge_at_cursor();
+        Some(elem)
+    }
+
+    /// Merge spans with equal metadata which end and start at the cursor.
+    fn merge_at_cursor(&mut self) {
+        let (len, cursor) = (self.zipper.len(), self.cursor());
+        let mut i = self.left.len();
+        while i > 0 && self.left[i - 1].end == cursor {
+            i -= 1;
+            let found = self.right.iter().rposition(|span| {
+                span.start == len - cursor && span.meta == self.left[i].meta
+            });
+            if let Some(j) = found {
+                let right = self.right.remove(j);
+                let left = self.left.remove(i);
+                self.around.push(Span { start: left.start
+                                      , end: right.end
+                                      , meta: left.meta });
+            }
+        }
+    }
+}
+
+impl<T, M> Deref for AnnotatedZipList<T, M> {
+    type Target = ZipList<T>;
+    #[inline] fn deref(&self) -> &ZipList<T> { &self.zipper }
+}
@@ -0,0 +1,102 @@
+use std::ops::Range;
+
+use super::AnnotatedZipList;
+use ::ZipList;
+
+/// Spans kept by position from the start, updated for each edit by brute
+/// force.
+struct Model { spans: Vec<(Range<usize>, usize)> }
+
+impl Model {
+    fn insert(&mut self, index: usize) {
+        for (range, _) in &mut self.spans {
+            if range.start >= index { range.start += 1 }
+            if range.end > index { range.end += 1 }
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        for (range, _) in &mut self.spans {
+            if range.start > index { range.start -= 1 }
+            if range.end > index { range.end -= 1 }
+        }
+        self.spans.retain(|(range, _)| range.start < range.end);
+    }
+
+    fn sorted(&self) -> Vec<(Range<usize>, usize)> {
+        let mut spans = self.spans.clone();
+        spans.sort_by_key(|(range, meta)| (range.start, range.end, *meta));
+        spans
+    }
+}
+
+quickcheck! {
+    fn spans_follow_edits( zipper: ZipList<u8>
+                         , spans: Vec<(usize, usize)>
+                         , ops: Vec<(u8, u8)> ) -> bool {
+        let len = zipper.len();
+        let mut annotated = AnnotatedZipList::new(zipper);
+        let mut model = Model { spans: Vec::new() };
+        // give every span different metadata, so that none are merged
+        for (meta, (a, b)) in spans.into_iter().enumerate() {
+            let (a, b) = (a % (len + 1), b % (len + 1));
+            let range = a.min(b)..a.max(b);
+            if range.start == range.end { continue }
+            annotated.annotate(range.clone(), meta);
+            model.spans.push((range, meta));
+        }
+        for (op, elem) in ops {
+            let cursor = annotated.left_iter().len();
+            match op % 6 {
+                0 => { annotated.push_left(elem); model.insert(cursor); }
+              , 1 => { annotated.push_right(elem); model.insert(cursor); }
+              , 2 => if annotated.pop_left().is_some() {
+                    model.remove(cursor - 1)
+                }
+              , 3 => if annotated.pop_right().is_some() {
+                    model.remove(cursor)
+                }
+              , 4 => { annotated.seek_left(elem as usize % 4); }
+              , _ => { annotated.seek_right(elem as usize % 4); }
+            }
+            let mut spans = annotated.spans().into_iter()
+                                     .map(|(range, &meta)| (range, meta))
+                                     .collect::<Vec<_>>();
+            // spans with the same range may be in any order
+            spans.sort_by_key(|(range, meta)| (range.start, range.end, *meta));
+            if spans != model.sorted() { return false }
+        }
+        true
+    }
+}
+
+#[test]
+fn insertions_at_span_ends_are_outside() {
+    let mut annotated = AnnotatedZipList::new(zipper!['a', 'b' ; 'c', 'd']);
+    annotated.annotate(1..3, "bc");
+    annotated.annotate(0..2, "ab");
+    annotated.annotate(2..4, "cd");
+    annotated.push_left('x');
+    assert_eq!( annotated.spans()
+              , vec![(0..2, &"ab"), (1..4, &"bc"), (3..5, &"cd")] );
+    assert_eq!(annotated.spans_at(2), vec![&"bc"]);
+}
+
+#[test]
+fn equal_spans_are_merged() {
+    let mut annotated = AnnotatedZipList::new(zipper![1, 2, 3 ; 4, 5]);
+    annotated.annotate(0..2, "a");
+    annotated.annotate(3..5, "a");
+    annotated.annotate(1..4, "b");
+    assert_eq!(annotated.span_count(), 3);
+    // removing the element between the two "a" spans joins them
+    annotated.pop_left();
+    assert_eq!(annotated.spans(), vec![(0..4, &"a"), (1..3, &"b")]);
+    // annotating a range touching a span with the same metadata extends it
+    annotated.annotate(0..4, "b");
+    assert_eq!(annotated.spans(), vec![(0..4, &"a"), (0..4, &"b")]);
+    let (zipper, spans) = annotated.into_inner();
+    assert_eq!(zipper.len(), 4);
+    assert_eq!(spans, vec![(0..4, "a"), (0..4, "b")]);
+}
+
@@ -0,0 +1,422 @@
+//! Stacks and zippers which store their elements inline, in a fixed-size
+//! array, and never allocate.
+//!
+//! An [`ArrayStack`] or [`ArrayZipList`] holds at most `N` elements, which
+//! live wherever the value itself does: on the stack, in a `static`, or
+//! inside another structure. Pushing to a full one fails, so they have
+//! `try_push` methods which hand the element back instead of allocating
+//! more room, and the [`Stack`] implementation panics on overflow.
+//!
+//! `ArrayZipList` is a gap buffer: elements to the left of the cursor fill
+//! the array from its start, elements to the right fill it from its end,
+//! and both sides share the free space between them.
+//!
+//! ```
+//! use an_zipper::ArrayZipList;
+//!
+//! let mut zipper = ArrayZipList::<char, 4>::new();
+//! zipper.push_left('a').push_left('c').push_right('d');
+//! zipper.move_left();
+//! zipper.push_left('b');
+//! assert!(zipper.is_full());
+//! assert_eq!(zipper.try_push_left('x'), Err('x'));
+//! assert_eq!(zipper.right_iter().collect::<String>(), "cd");
+//! ```
+//!
+//! [`ArrayStack`]: struct.ArrayStack.html
+//! [`ArrayZipList`]: struct.ArrayZipList.html
+//! [`Stack`]: ../trait.Stack.html
+use std::fmt;
+use std::iter;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+use super::{CursorMarker, Stack};
+
+#[cfg(test)] mod test;
+
+/// A stack of at most `N` elements, stored inline.
+///
+/// See the [module documentation](index.html) for more.
+pub struct ArrayStack<T, const N: usize> { elems: [MaybeUninit<T>; N]
+                                         , len: usize
+                                         }
+
+/// A zipper list of at most `N` elements, stored inline.
+///
+/// See the [module documentation](index.html) for more.
+pub struct ArrayZipList<T, const N: usize> { elems: [MaybeUninit<T>; N]
+                                           // elements to the left of the
+                                           // cursor are `elems[..left]`
+                                           , left: usize
+                                           // and elements to its right are
+                                           // `elems[N - right..]`
+                                           , right: usize
+                                           }
+
+impl<T, const N: usize> ArrayStack<T, N> {
+    /// Create a new empty `ArrayStack`.
+    pub const fn new() -> Self {
+        ArrayStack { elems: [const { MaybeUninit::uninit() }; N], len: 0 }
+    }
+
+    /// Returns the number of elements in the stack.
+    #[inline] pub fn len(&self) -> usize { self.len }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns the number of elements the stack can hold, which is `N`.
+    #[inline] pub const fn capacity(&self) -> usize { N }
+
+    /// Returns true if the stack can't hold any more elements.
+    #[inline] pub fn is_full(&self) -> bool { self.len == N }
+
+    /// Push `elem` to the stack, if there's room for it.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if the stack is full
+    pub fn try_push(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() { return Err(elem) }
+        self.elems[self.len].write(elem);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Borrow the elements as a slice, from the bottom of the stack to the
+    /// top.
+    pub fn as_slice(&self) -> &[T] {
+        // the first `len` elements are initialized
+        unsafe {
+            slice::from_raw_parts(self.elems.as_ptr() as *const T, self.len)
+        }
+    }
+
+    /// Mutably borrow the elements as a slice, from the bottom of the stack
+    /// to the top.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut( self.elems.as_mut_ptr() as *mut T
+                                     , self.len )
+        }
+    }
+
+    /// Returns an iterator over the elements, from the top of the stack.
+    pub fn iter(&self) -> iter::Rev<slice::Iter<'_, T>> {
+        self.as_slice().iter().rev()
+    }
+
+    /// Drop every element.
+    pub fn clear(&mut self) {
+        let elems = self.as_mut_slice() as *mut [T];
+        // the length is reset first, so that if an element panics when it's
+        // dropped, the rest are leaked rather than dropped twice.
+        self.len = 0;
+        unsafe { ptr::drop_in_place(elems) }
+    }
+}
+
+impl<T, const N: usize> Stack<T> for ArrayStack<T, N> {
+    /// Push `elem` to the stack.
+    ///
+    /// # Panics
+    /// If the stack is full. Use [`try_push`](#method.try_push) to handle
+    /// that instead.
+    fn push(&mut self, elem: T) -> &mut Self {
+        if self.try_push(elem).is_err() {
+            panic!("pushed to a full ArrayStack of capacity {}", N)
+        }
+        self
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 { return None }
+        self.len -= 1;
+        Some(unsafe { self.elems[self.len].assume_init_read() })
+    }
+
+    #[inline] fn peek(&self) -> Option<&T> { self.as_slice().last() }
+
+    #[inline] fn peek_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayStack<T, N> {
+    fn drop(&mut self) { self.clear() }
+}
+
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+    fn default() -> Self { ArrayStack::new() }
+}
+
+impl<T, const N: usize> Clone for ArrayStack<T, N>
+where T: Clone {
+    fn clone(&self) -> Self {
+        let mut clone = ArrayStack::new();
+        for elem in self.as_slice() {
+            clone.elems[clone.len].write(elem.clone());
+            clone.len += 1;
+        }
+        clone
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayStack<T, N>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> ArrayZipList<T, N> {
+    /// Create a new empty `ArrayZipList`.
+    pub const fn new() -> Self {
+        ArrayZipList { elems: [const { MaybeUninit::uninit() }; N]
+                     , left: 0
+                     , right: 0
+                     }
+    }
+
+    /// Returns the number of elements in the zipper.
+    #[inline] pub fn len(&self) -> usize { self.left + self.right }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the zipper can hold, which is `N`.
+    #[inline] pub const fn capacity(&self) -> usize { N }
+
+    /// Returns true if the zipper can't hold any more elements.
+    #[inline] pub fn is_full(&self) -> bool { self.len() == N }
+
+    /// Returns true if the zipper is at the left end.
+    #[inline] pub fn is_at_start(&self) -> bool { self.left == 0 }
+
+    /// Returns true if the zipper is at the right end.
+    #[inline] pub fn is_at_end(&self) -> bool { self.right == 0 }
+
+    /// Borrow the elements to the left of the zipper, in order.
+    pub fn left_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.elems.as_ptr() as *const T, self.left)
+        }
+    }
+
+    /// Borrow the elements to the right of the zipper, in order.
+    pub fn right_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts( self.elems[N - self.right..].as_ptr()
+                                       as *const T
+                                 , self.right )
+        }
+    }
+
+    /// Mutably borrow the elements to the left of the zipper, in order.
+    pub fn left_slice_mut(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut( self.elems.as_mut_ptr() as *mut T
+                                     , self.left )
+        }
+    }
+
+    /// Mutably borrow the elements to the right of the zipper, in order.
+    pub fn right_slice_mut(&mut self) -> &mut [T] {
+        let start = N - self.right;
+        unsafe {
+            slice::from_raw_parts_mut( self.elems[start..].as_mut_ptr()
+                                           as *mut T
+                                     , self.right )
+        }
+    }
+
+    /// Returns an iterator over the elements to the left of the zipper,
+    /// nearest first.
+    pub fn left_iter(&self) -> iter::Rev<slice::Iter<'_, T>> {
+        self.left_slice().iter().rev()
+    }
+
+    /// Returns an iterator over the elements to the right of the zipper,
+    /// nearest first.
+    pub fn right_iter(&self) -> slice::Iter<'_, T> {
+        self.right_slice().iter()
+    }
+
+    /// Borrow the element to the left of the zipper, if there is one.
+    pub fn peek_left(&self) -> Option<&T> { self.left_slice().last() }
+
+    /// Borrow the element to the right of the zipper, if there is one.
+    pub fn peek_right(&self) -> Option<&T> { self.right_slice().first() }
+
+    /// Mutably borrow the element to the left of the zipper, if there is
+    /// one.
+    pub fn peek_left_mut(&mut self) -> Option<&mut T> {
+        self.left_slice_mut().last_mut()
+    }
+
+    /// Mutably borrow the element to the right of the zipper, if there is
+    /// one.
+    pub fn peek_right_mut(&mut self) -> Option<&mut T> {
+        self.right_slice_mut().first_mut()
+    }
+
+    /// Push `elem` to the left of the zipper, if there's room for it.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if the zipper is full
+    pub fn try_push_left(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() { return Err(elem) }
+        self.elems[self.left].write(elem);
+        self.left += 1;
+        Ok(())
+    }
+
+    /// Push `elem` to the right of the zipper, if there's room for it.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if the zipper is full
+    pub fn try_push_right(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() { return Err(elem) }
+        self.elems[N - self.right - 1].write(elem);
+        self.right += 1;
+        Ok(())
+    }
+
+    /// Push `elem` to the left of the zipper.
+    ///
+    /// # Panics
+    /// If the zipper is full.
+    pub fn push_left(&mut self, elem: T) -> &mut Self {
+        if self.try_push_left(elem).is_err() {
+            panic!("pushed to a full ArrayZipList of capacity {}", N)
+        }
+        self
+    }
+
+    /// Push `elem` to the right of the zipper.
+    ///
+    /// # Panics
+    /// If the zipper is full.
+    pub fn push_right(&mut self, elem: T) -> &mut Self {
+        if self.try_push_right(elem).is_err() {
+            panic!("pushed to a full ArrayZipList of capacity {}", N)
+        }
+        self
+    }
+
+    /// Remove the element to the left of the zipper, if there is one.
+    pub fn pop_left(&mut self) -> Option<T> {
+        if self.left == 0 { return None }
+        self.left -= 1;
+        Some(unsafe { self.elems[self.left].assume_init_read() })
+    }
+
+    /// Remove the element to the right of the zipper, if there is one.
+    pub fn pop_right(&mut self) -> Option<T> {
+        if self.right == 0 { return None }
+        let elem = unsafe { self.elems[N - self.right].assume_init_read() };
+        self.right -= 1;
+        Some(elem)
+    }
+
+    /// Move the zipper one position to the left.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved
+    /// - `false` if it's already at the left end
+    pub fn move_left(&mut self) -> bool { self.seek_left(1) == 1 }
+
+    /// Move the zipper one position to the right.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved
+    /// - `false` if it's already at the right end
+    pub fn move_right(&mut self) -> bool { self.seek_right(1) == 1 }
+
+    /// Move the zipper up to `n` positions to the left, returning how far it
+    /// moved.
+    ///
+    /// # Time complexity
+    /// O(number of positions moved)
+    pub fn seek_left(&mut self, n: usize) -> usize {
+        let n = n.min(self.left);
+        let (from, to) = (self.left - n, N - self.right - n);
+        // moves the elements across the gap; the ranges may overlap when
+        // the zipper is nearly full
+        unsafe {
+            let base = self.elems.as_mut_ptr();
+            ptr::copy(base.add(from), base.add(to), n);
+        }
+        self.left -= n;
+        self.right += n;
+        n
+    }
+
+    /// Move the zipper up to `n` positions to the right, returning how far
+    /// it moved.
+    ///
+    /// # Time complexity
+    /// O(number of positions moved)
+    pub fn seek_right(&mut self, n: usize) -> usize {
+        let n = n.min(self.right);
+        let (from, to) = (N - self.right, self.left);
+        unsafe {
+            let base = self.elems.as_mut_ptr();
+            ptr::copy(base.add(from), base.add(to), n);
+        }
+        self.left += n;
+        self.right -= n;
+        n
+    }
+
+    /// Drop every element.
+    pub fn clear(&mut self) {
+        let left = self.left_slice_mut() as *mut [T];
+        let right = self.right_slice_mut() as *mut [T];
+        // as with `ArrayStack::clear`, a panicking element leaks the rest
+        // rather than dropping any of them twice.
+        self.left = 0;
+        self.right = 0;
+        unsafe {
+            ptr::drop_in_place(left);
+            ptr::drop_in_place(right);
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayZipList<T, N> {
+    fn drop(&mut self) { self.clear() }
+}
+
+impl<T, const N: usize> Default for ArrayZipList<T, N> {
+    fn default() -> Self { ArrayZipList::new() }
+}
+
+impl<T, const N: usize> Clone for ArrayZipList<T, N>
+where T: Clone {
+    fn clone(&self) -> Self {
+        let mut clone = ArrayZipList::new();
+        for elem in self.left_slice() {
+            clone.elems[clone.left].write(elem.clone());
+            clone.left += 1;
+        }
+        for elem in self.right_slice().iter().rev() {
+            clone.elems[N - clone.right - 1].write(elem.clone());
+            clone.right += 1;
+        }
+        clone
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayZipList<T, N>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+         .entries(self.left_slice())
+         .entry(&CursorMarker)
+         .entries(self.right_slice())
+         .finish()
+    }
+}
@@ -0,0 +1,121 @@
+use std::rc::Rc;
+
+use ::{Stack, ZipList};
+use super::{ArrayStack, ArrayZipList};
+use test::contents as model_contents;
+
+/// Returns the elements of `zipper` in order, and the cursor's position.
+fn contents<const N: usize>(zipper: &ArrayZipList<u8, N>)
+                            -> (Vec<u8>, usize) {
+    let mut elems = zipper.left_slice().to_vec();
+    elems.extend_from_slice(zipper.right_slice());
+    (elems, zipper.left_slice().len())
+}
+
+quickcheck! {
+    fn zipper_matches_zip_list(ops: Vec<(u8, u8)>) -> bool {
+        let mut array = ArrayZipList::<u8, 8>::new();
+        let mut model = ZipList::new();
+        for (op, arg) in ops {
+            let ok = match op % 6 {
+                0 => {
+                    let room = model.len() < 8;
+                    if room { model.push_left(arg); }
+                    array.try_push_left(arg).is_ok() == room
+                }
+              , 1 => {
+                    let room = model.len() < 8;
+                    if room { model.push_right(arg); }
+                    array.try_push_right(arg).is_ok() == room
+                }
+              , 2 => array.pop_left() == model.pop_left()
+              , 3 => array.pop_right() == model.pop_right()
+              , 4 => array.seek_left(arg as usize % 4) ==
+                     model.seek_left(arg as usize % 4)
+              , _ => array.seek_right(arg as usize % 4) ==
+                     model.seek_right(arg as usize % 4)
+            };
+            if !ok || contents(&array) != model_contents(&model) {
+                return false
+            }
+        }
+        array.len() == model.len() &&
+        array.left_iter().eq(model.left_iter()) &&
+        array.right_iter().eq(model.right_iter())
+    }
+
+    fn stack_matches_vec(ops: Vec<Option<u8>>) -> bool {
+        let mut stack = ArrayStack::<u8, 4>::new();
+        let mut model = Vec::new();
+        ops.into_iter().all(|op| match op {
+            Some(elem) => {
+                let room = model.len() < 4;
+                if room { model.push(elem) }
+                stack.try_push(elem).is_ok() == room &&
+                stack.as_slice() == &model[..]
+            }
+          , None => stack.pop() == model.pop()
+        })
+    }
+}
+
+#[test]
+fn stack_overflow_returns_the_element() {
+    let mut stack = ArrayStack::<&str, 2>::new();
+    stack.push("a").push("b");
+    assert!(stack.is_full());
+    assert_eq!(stack.try_push("c"), Err("c"));
+    assert_eq!(stack.peek(), Some(&"b"));
+    assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&"b", &"a"]);
+}
+
+#[test]
+#[should_panic(expected = "full ArrayStack")]
+fn stack_push_panics_when_full() {
+    let mut stack = ArrayStack::<u8, 1>::new();
+    stack.push(1).push(2);
+}
+
+#[test]
+fn elements_dropped_once() {
+    let token = Rc::new(());
+    {
+        let mut stack = ArrayStack::<_, 4>::new();
+        stack.push(token.clone()).push(token.clone()).push(token.clone());
+        drop(stack.pop());
+        let clone = stack.clone();
+        assert_eq!(Rc::strong_count(&token), 5);
+        drop(clone);
+
+        let mut zipper = ArrayZipList::<_, 4>::new();
+        zipper.push_left(token.clone()).push_right(token.clone());
+        zipper.push_right(token.clone());
+        zipper.seek_right(2);
+        zipper.seek_left(3);
+        drop(zipper.pop_right());
+        let clone = zipper.clone();
+        assert_eq!(Rc::strong_count(&token), 7);
+        drop(clone);
+    }
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[test]
+fn moves_across_a_full_zipper() {
+    let mut zipper = ArrayZipList::<u8, 3>::new();
+    zipper.push_left(1).push_left(2).push_left(3);
+    assert_eq!(zipper.seek_left(5), 3);
+    assert_eq!(contents(&zipper), (vec![1, 2, 3], 0));
+    assert!(zipper.move_right());
+    assert_eq!(contents(&zipper), (vec![1, 2, 3], 1));
+    *zipper.peek_right_mut().unwrap() = 20;
+    assert_eq!(format!("{:?}", zipper), "[1, >>> cursor <<<, 20, 3]");
+}
+
+#[test]
+fn statics_need_no_allocator() {
+    static STACK: ArrayStack<u32, 16> = ArrayStack::new();
+    static ZIPPER: ArrayZipList<u32, 16> = ArrayZipList::new();
+    assert!(STACK.is_empty());
+    assert_eq!(ZIPPER.capacity(), 16);
+}
@@ -0,0 +1,79 @@
+use std::iter;
+use std::mem;
+
+use super::{List, Stack, ZipList};
+
+/// Builds a `ZipList` from elements given in order, with the cursor at a
+/// chosen position, in a single pass.
+///
+/// ```
+/// # use an_zipper::ZipListBuilder;
+/// let mut builder = ZipListBuilder::new();
+/// builder.push(1).push(2).mark_cursor().push(3);
+/// let zipper = builder.build();
+/// assert_eq!(zipper.peek_left(), Some(&2));
+/// assert_eq!(zipper.peek_right(), Some(&3));
+/// ```
+pub struct ZipListBuilder<T> { left: List<T>
+                             , right: List<T>
+                             }
+
+impl<T> ZipListBuilder<T> {
+    /// Create a new empty `ZipListBuilder`.
+    pub const fn new() -> Self {
+        ZipListBuilder { left: List::new(), right: List::new() }
+    }
+
+    /// Add `elem` after all of the elements added so far.
+    ///
+    /// # Returns
+    /// `&mut Self` so that multiple `push`es can be chained.
+    pub fn push(&mut self, elem: T) -> &mut Self {
+        // the elements after the cursor are kept newest-first, like the
+        // left side of a zipper, and put back in order by `build`.
+        self.right.push(elem);
+        self
+    }
+
+    /// Put the cursor after all of the elements added so far.
+    ///
+    /// If the cursor was already marked, it's moved to the new position. If
+    /// it's never marked, the cursor is placed at the start.
+    ///
+    /// # Time complexity
+    /// O(elements added since the cursor was last marked)
+    pub fn mark_cursor(&mut self) -> &mut Self {
+        let right = mem::take(&mut self.right);
+        self.left.prepend(right);
+        self
+    }
+
+    /// Returns the number of elements added so far.
+    #[inline] pub fn len(&self) -> usize { self.left.len() + self.right.len() }
+
+    /// Returns true if no elements have been added.
+    #[inline] pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+
+    /// Finish building the `ZipList`.
+    ///
+    /// # Time complexity
+    /// O(elements added since the cursor was last marked)
+    pub fn build(self) -> ZipList<T> {
+        let ZipListBuilder { left, mut right } = self;
+        right.reverse();
+        ZipList { left, right, committed: 0, generation: 0, consumed: 0 }
+    }
+}
+
+impl<T> Default for ZipListBuilder<T> {
+    fn default() -> Self { ZipListBuilder::new() }
+}
+
+impl<T> iter::Extend<T> for ZipListBuilder<T> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item=T> {
+        self.right.extend(iter);
+    }
+}
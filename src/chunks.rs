@@ -0,0 +1,66 @@
+use std::iter;
+
+use super::{List, ZipList};
+
+/// An iterator which hands out a `ZipList`'s elements in owned chunks, from
+/// left to right.
+///
+/// This is created by [`ZipList::into_chunks`]. Each chunk is a `List` of
+/// the nodes detached from the zipper, so no elements are copied, and a
+/// chunk's memory is freed as soon as it's dropped rather than when the
+/// whole zipper is.
+///
+/// ```
+/// # use an_zipper::ZipList;
+/// let zipper = ZipList::from_iter_with_cursor(0..7, 3);
+/// let chunks = zipper.into_chunks(3)
+///                    .map(|chunk| chunk.into_iter().collect::<Vec<_>>())
+///                    .collect::<Vec<_>>();
+/// assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+/// ```
+///
+/// [`ZipList::into_chunks`]: struct.ZipList.html#method.into_chunks
+pub struct IntoChunks<T> { left: List<T>
+                         , right: List<T>
+                         , len: usize
+                         }
+
+impl<T> ZipList<T> {
+    /// Consume the zipper, returning an iterator over its elements in lists
+    /// of `n`, from left to right. The last list may be shorter.
+    ///
+    /// # Panics
+    /// If `n` is zero.
+    ///
+    /// # Time complexity
+    /// O(elements to the left of the cursor) to start, to put them in order,
+    /// and then O(`n`) per chunk.
+    pub fn into_chunks(self, n: usize) -> IntoChunks<T> {
+        assert!(n > 0, "chunk length must be non-zero");
+        let ZipList { mut left, right, .. } = self;
+        left.reverse();
+        IntoChunks { left, right, len: n }
+    }
+}
+
+impl<T> Iterator for IntoChunks<T> {
+    type Item = List<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = self.left.split_front(self.len);
+        if chunk.len() < self.len {
+            let rest = self.right.split_front(self.len - chunk.len());
+            chunk.append(rest);
+        }
+        if chunk.is_empty() { None } else { Some(chunk) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let elems = self.left.len() + self.right.len();
+        let chunks = elems.div_ceil(self.len);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T> iter::ExactSizeIterator for IntoChunks<T> {}
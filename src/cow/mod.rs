@@ -0,0 +1,394 @@
+//! Lists and zippers which share nodes with their clones.
+//!
+//! Cloning a [`CowList`] or [`CowZipList`] only copies a pointer, and lists
+//! that have been cloned share their common tails. Edits copy just the
+//! nodes in front of the one being changed, so snapshots are cheap, and
+//! only pay for copying when they're written to.
+//!
+//! These are a separate, much smaller pair of types from [`List`] and
+//! [`ZipList`], which always own their nodes outright, since they recycle
+//! them through a freelist. `CowList` and `CowZipList` only cover pushing,
+//! popping, peeking, iterating and moving the cursor, so for anything
+//! else, use `List` and `ZipList`.
+//!
+//! ```
+//! use an_zipper::CowZipList;
+//!
+//! let mut zipper = (0..5).collect::<CowZipList<_>>();
+//! zipper.seek_right(2);
+//! let snapshot = zipper.clone();
+//! zipper.push_left(10);
+//! assert_eq!(zipper.right_iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+//! assert_eq!(zipper.len(), 6);
+//! assert_eq!(snapshot.len(), 5);
+//! ```
+//!
+//! [`List`]: ../struct.List.html
+//! [`ZipList`]: ../struct.ZipList.html
+//! [`CowList`]: struct.CowList.html
+//! [`CowZipList`]: struct.CowZipList.html
+use std::fmt;
+use std::iter;
+use std::rc::Rc;
+
+use super::Stack;
+
+#[cfg(test)] mod test;
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+#[derive(Clone)]
+struct Node<T> { elem: T
+               , next: Link<T>
+               }
+
+/// A singly-linked list which shares nodes with its clones, and copies
+/// them when they're written to.
+///
+/// See the [module documentation](index.html) for more.
+pub struct CowList<T> { head: Link<T>
+                      , len: usize
+                      }
+
+/// An iterator over the elements of a `CowList`, from the front.
+pub struct Iter<'a, T: 'a> { next: Option<&'a Node<T>>
+                           , len: usize
+                           }
+
+impl<T> CowList<T> {
+    /// Create a new empty `CowList`.
+    pub const fn new() -> Self { CowList { head: None, len: 0 } }
+
+    /// Returns the length of the list.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn len(&self) -> usize { self.len }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.head.is_none() }
+
+    /// Borrow the element at the front of the list, if there is one.
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    /// Returns an iterator over the elements, from the front.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), len: self.len }
+    }
+
+    /// Link `node`, which mustn't be shared, onto the front of the list.
+    fn cons(&mut self, mut node: Rc<Node<T>>) {
+        Rc::get_mut(&mut node).expect("consed a shared node").next =
+            self.head.take();
+        self.head = Some(node);
+        self.len += 1;
+    }
+}
+
+impl<T> CowList<T>
+where T: Clone {
+    /// Borrow the element at the front of the list mutably, if there is one.
+    ///
+    /// If the front node is shared with another list, it's copied first, so
+    /// the other list doesn't see the change.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        let head = self.head.as_mut()?;
+        Some(&mut Rc::make_mut(head).elem)
+    }
+
+    /// Mutably borrow the element at `index`, if there is one.
+    ///
+    /// Any shared nodes up to and including the element's are copied first,
+    /// so other lists don't see the change. The nodes after it stay shared.
+    ///
+    /// # Time complexity
+    /// O(`index`)
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len { return None }
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            let node = link.as_mut().expect("index out of bounds");
+            link = &mut Rc::make_mut(node).next;
+        }
+        link.as_mut().map(|node| &mut Rc::make_mut(node).elem)
+    }
+
+    /// Reverse the order of the list in place, copying any nodes that are
+    /// shared with another list.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn reverse(&mut self) {
+        let mut reversed = CowList::new();
+        while let Some(node) = self.uncons() {
+            reversed.cons(node);
+        }
+        *self = reversed;
+    }
+
+    /// Unlink the node at the front of the list, copying it if it's shared,
+    /// so that the node returned isn't.
+    fn uncons(&mut self) -> Link<T> {
+        let mut node = self.head.take()?;
+        self.len -= 1;
+        match Rc::get_mut(&mut node) {
+            Some(unique) => self.head = unique.next.take()
+          , None => {
+                self.head = node.next.clone();
+                node = Rc::new(Node { elem: node.elem.clone(), next: None });
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<T> Stack<T> for CowList<T>
+where T: Clone {
+    fn push(&mut self, elem: T) -> &mut Self {
+        self.cons(Rc::new(Node { elem, next: None }));
+        self
+    }
+
+    /// Remove the element at the front of the list, if there is one.
+    ///
+    /// If the front node is shared with another list, its element is cloned
+    /// rather than moved out.
+    fn pop(&mut self) -> Option<T> {
+        self.uncons().map(|node| match Rc::try_unwrap(node) {
+            Ok(node) => node.elem
+          , Err(_) => unreachable!("unconsed a shared node")
+        })
+    }
+
+    #[inline] fn peek(&self) -> Option<&T> { CowList::peek(self) }
+
+    /// Borrow the element at the front of the list mutably, copying its
+    /// node first if it's shared.
+    #[inline] fn peek_mut(&mut self) -> Option<&mut T> {
+        CowList::peek_mut(self)
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.elem
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for Iter<'a, T> {
+    #[inline] fn len(&self) -> usize { self.len }
+}
+
+impl<'a, T> IntoIterator for &'a CowList<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T> iter::FromIterator<T> for CowList<T>
+where T: Clone {
+    /// Push each element of `iter` in turn, so that the last one is at the
+    /// front, as with `List`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = CowList::new();
+        for elem in iter { list.push(elem); }
+        list
+    }
+}
+
+impl<T> Clone for CowList<T> {
+    /// Clone the list, sharing its nodes.
+    ///
+    /// # Time complexity
+    /// O(1)
+    fn clone(&self) -> Self {
+        CowList { head: self.head.clone(), len: self.len }
+    }
+}
+
+impl<T> Default for CowList<T> {
+    fn default() -> Self { CowList::new() }
+}
+
+impl<T> fmt::Debug for CowList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Drop for CowList<T> {
+    fn drop(&mut self) {
+        // unlink the nodes one at a time, so that dropping a long list
+        // doesn't recurse once per node, stopping at the first node that's
+        // still in use by another list.
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            head = match Rc::try_unwrap(node) {
+                Ok(mut node) => node.next.take()
+              , Err(_) => None
+            };
+        }
+    }
+}
+
+/// A zipper list which shares nodes with its clones, and copies them when
+/// they're written to.
+///
+/// See the [module documentation](index.html) for more.
+pub struct CowZipList<T> { left: CowList<T>
+                         , right: CowList<T>
+                         }
+
+impl<T> CowZipList<T> {
+    /// Create a new empty `CowZipList`.
+    pub const fn new() -> Self {
+        CowZipList { left: CowList::new(), right: CowList::new() }
+    }
+
+    /// Returns the number of elements in the zipper.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn len(&self) -> usize { self.left.len + self.right.len }
+
+    #[inline] pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+
+    /// Returns the number of elements to the left of the cursor.
+    #[inline] pub fn cursor(&self) -> usize { self.left.len }
+
+    /// Borrow the element to the left of the cursor, if there is one.
+    pub fn peek_left(&self) -> Option<&T> { self.left.peek() }
+
+    /// Borrow the element to the right of the cursor, if there is one.
+    pub fn peek_right(&self) -> Option<&T> { self.right.peek() }
+
+    /// Returns an iterator over the elements to the left of the cursor,
+    /// nearest first.
+    pub fn left_iter(&self) -> Iter<'_, T> { self.left.iter() }
+
+    /// Returns an iterator over the elements to the right of the cursor,
+    /// nearest first.
+    pub fn right_iter(&self) -> Iter<'_, T> { self.right.iter() }
+}
+
+impl<T> CowZipList<T>
+where T: Clone {
+    /// Push `elem` to the left of the cursor.
+    pub fn push_left(&mut self, elem: T) -> &mut Self {
+        self.left.push(elem);
+        self
+    }
+
+    /// Push `elem` to the right of the cursor.
+    pub fn push_right(&mut self, elem: T) -> &mut Self {
+        self.right.push(elem);
+        self
+    }
+
+    /// Mutably borrow the element to the left of the cursor, copying its
+    /// node first if it's shared.
+    pub fn peek_left_mut(&mut self) -> Option<&mut T> {
+        self.left.peek_mut()
+    }
+
+    /// Mutably borrow the element to the right of the cursor, copying its
+    /// node first if it's shared.
+    pub fn peek_right_mut(&mut self) -> Option<&mut T> {
+        self.right.peek_mut()
+    }
+
+    /// Remove the element to the left of the cursor, if there is one.
+    pub fn pop_left(&mut self) -> Option<T> { self.left.pop() }
+
+    /// Remove the element to the right of the cursor, if there is one.
+    pub fn pop_right(&mut self) -> Option<T> { self.right.pop() }
+
+    /// Move the cursor one position to the left.
+    ///
+    /// The node is relinked if it isn't shared, or copied if it is.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved
+    /// - `false` if it's already at the left end
+    pub fn move_left(&mut self) -> bool {
+        self.left.uncons()
+            .map(|node| self.right.cons(node))
+            .is_some()
+    }
+
+    /// Move the cursor one position to the right.
+    ///
+    /// The node is relinked if it isn't shared, or copied if it is.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved
+    /// - `false` if it's already at the right end
+    pub fn move_right(&mut self) -> bool {
+        self.right.uncons()
+            .map(|node| self.left.cons(node))
+            .is_some()
+    }
+
+    /// Move the cursor up to `n` positions to the left.
+    ///
+    /// # Returns
+    /// - the number of positions moved
+    pub fn seek_left(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_left()).count()
+    }
+
+    /// Move the cursor up to `n` positions to the right.
+    ///
+    /// # Returns
+    /// - the number of positions moved
+    pub fn seek_right(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_right()).count()
+    }
+}
+
+impl<T> iter::FromIterator<T> for CowZipList<T>
+where T: Clone {
+    /// Collect the elements of `iter` in order, with the cursor at the left
+    /// end.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut right = iter.into_iter().collect::<CowList<T>>();
+        right.reverse();
+        CowZipList { left: CowList::new(), right }
+    }
+}
+
+impl<T> Clone for CowZipList<T> {
+    /// Clone the zipper, sharing its nodes.
+    fn clone(&self) -> Self {
+        CowZipList { left: self.left.clone(), right: self.right.clone() }
+    }
+}
+
+impl<T> Default for CowZipList<T> {
+    fn default() -> Self { CowZipList::new() }
+}
+
+impl<T> fmt::Debug for CowZipList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CowZipList")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish()
+    }
+}
@@ -0,0 +1,90 @@
+use std::rc::Rc;
+
+use super::{CowList, CowZipList};
+use ::{List, Stack};
+use test::joined;
+
+fn contents<T: Clone>(zipper: &CowZipList<T>) -> (Vec<T>, usize) {
+    joined(zipper.left_iter(), zipper.right_iter())
+}
+
+quickcheck! {
+    fn pops_match_list(elems: Vec<u8>, pops: usize) -> bool {
+        let mut list = elems.iter().cloned().collect::<List<_>>();
+        let mut cow = elems.iter().cloned().collect::<CowList<_>>();
+        for _ in 0..pops % (elems.len() + 2) {
+            if list.pop() != cow.pop() { return false }
+        }
+        list.len() == cow.len() && list.iter().eq(cow.iter())
+    }
+
+    // apply pushes, pops and moves to a zipper, checking that a clone of
+    // it isn't affected
+    fn clones_are_isolated(elems: Vec<u8>, ops: Vec<u8>) -> bool {
+        let mut zipper = elems.iter().cloned().collect::<CowZipList<_>>();
+        zipper.seek_right(elems.len() / 2);
+        let snapshot = zipper.clone();
+        let before = contents(&snapshot);
+        for (i, &op) in ops.iter().enumerate() {
+            match op % 6 {
+                0 => { zipper.push_left(i as u8); }
+              , 1 => { zipper.push_right(i as u8); }
+              , 2 => { zipper.pop_left(); }
+              , 3 => { zipper.pop_right(); }
+              , 4 => { zipper.move_left(); }
+              , _ => { zipper.move_right(); }
+            }
+        }
+        contents(&snapshot) == before && before.0 == elems
+    }
+}
+
+#[test]
+fn clones_share_nodes() {
+    let mut list = (0..4).collect::<CowList<_>>();
+    let shared = list.clone();
+    assert!(Rc::ptr_eq( list.head.as_ref().unwrap()
+                      , shared.head.as_ref().unwrap() ));
+    *list.peek_mut().unwrap() = 30;
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![30, 2, 1, 0]);
+    assert_eq!(shared.iter().cloned().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    // only the front node was copied
+    let (a, b) = (list.head.as_ref().unwrap(), shared.head.as_ref().unwrap());
+    assert!(Rc::ptr_eq(a.next.as_ref().unwrap(), b.next.as_ref().unwrap()));
+}
+
+#[test]
+fn long_lists_drop_without_recursing() {
+    let list = (0..1_000_000).collect::<CowList<u32>>();
+    let shared = list.clone();
+    drop(list);
+    assert_eq!(shared.len(), 1_000_000);
+}
+
+#[test]
+fn cow_writes_copy_only_the_prefix() {
+    let mut list = (0..6).collect::<CowList<_>>();
+    let snapshot = list.clone();
+    *list.get_mut(2).unwrap() = 30;
+    assert_eq!(list.get_mut(6), None);
+    let elems = |list: &CowList<i32>| list.iter().cloned().collect::<Vec<_>>();
+    assert_eq!(elems(&list), vec![5, 4, 30, 2, 1, 0]);
+    assert_eq!(elems(&snapshot), vec![5, 4, 3, 2, 1, 0]);
+    let link_after = |list: &CowList<i32>| {
+        let mut node = list.head.as_ref().unwrap();
+        for _ in 0..3 { node = node.next.as_ref().unwrap(); }
+        node.clone()
+    };
+    assert!(Rc::ptr_eq(&link_after(&list), &link_after(&snapshot)));
+}
+
+#[test]
+fn cow_zipper_snapshots() {
+    let mut zipper = (0..4).collect::<CowZipList<_>>();
+    zipper.seek_right(2);
+    let snapshot = zipper.clone();
+    *zipper.peek_right_mut().unwrap() = 20;
+    *zipper.peek_left_mut().unwrap() = 10;
+    assert_eq!(contents(&zipper), (vec![0, 10, 20, 3], 2));
+    assert_eq!(contents(&snapshot), (vec![0, 1, 2, 3], 2));
+}
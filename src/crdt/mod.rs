@@ -0,0 +1,325 @@
+//! A zipper which can be replicated and merged.
+//!
+//! A [`Replica`] is a sequence CRDT based on the Replicated Growable Array
+//! (RGA) of [Roh et al.], with a zipper's cursor for editing. Each replica
+//! can be edited independently, and concurrent edits made to different
+//! replicas can be merged in any order, with every replica ending up with
+//! the same elements.
+//!
+//! It works like this:
+//!
+//! - Every element is given an [`Id`] when it's inserted: a Lamport
+//!   timestamp, plus the ID of the replica that inserted it to break ties.
+//!   The timestamp is greater than that of every element the inserting
+//!   replica has seen, so newer elements always have greater IDs.
+//! - An insertion records the ID of the element it was inserted after. To
+//!   apply it, a replica finds that element and skips past any elements with
+//!   greater IDs following it, which must have been inserted concurrently or
+//!   later, so that every replica puts concurrent insertions in the same
+//!   order.
+//! - Removed elements are kept as "tombstones" without values, so later
+//!   insertions after them can still find them. Tombstones are skipped over
+//!   by the cursor.
+//!
+//! Every edit produces a [`Change`], which can be sent to other replicas and
+//! [applied] to them, as long as each replica applies changes after the
+//! changes they depend on. Alternatively, one replica can [merge] in all of
+//! the changes another has seen.
+//!
+//! This is meant to be easy to follow, rather than fast: applying a change
+//! from another replica takes time proportional to the number of elements,
+//! including tombstones, and tombstones are never removed.
+//!
+//! [`Replica`]: struct.Replica.html
+//! [`Id`]: struct.Id.html
+//! [`Change`]: enum.Change.html
+//! [applied]: struct.Replica.html#method.apply
+//! [merge]: struct.Replica.html#method.merge
+//! [Roh et al.]: https://doi.org/10.1016/j.jpdc.2010.12.006
+use std::fmt;
+
+use super::ZipList;
+use error::CausalityError;
+
+#[cfg(test)] mod test;
+
+/// Identifies an element of a [`Replica`](struct.Replica.html).
+///
+/// IDs are ordered by their timestamps, and then by their replica IDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id { /// The Lamport timestamp of the element's insertion.
+                pub counter: u64
+              , /// The ID of the replica that inserted the element.
+                pub replica: u64
+              }
+
+/// An edit made to a [`Replica`](struct.Replica.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change<T> {
+    /// `elem` was inserted with `id`, immediately after the element `after`,
+    /// or at the start if `after` is `None`.
+    Insert { id: Id, after: Option<Id>, elem: T }
+  , /// The element `id` was removed.
+    Remove { id: Id }
+}
+
+/// An element of a replica, or the tombstone of a removed element.
+#[derive(Clone, Debug)]
+struct Item<T> { id: Id
+               , value: Option<T>
+               }
+
+/// A replicated sequence with a cursor.
+///
+/// The cursor moves over and edits only the elements that haven't been
+/// removed, so apart from [`apply`](#method.apply) and
+/// [`merge`](#method.merge), this is used like a `ZipList`.
+#[derive(Clone, Debug)]
+pub struct Replica<T> { replica: u64
+                      , clock: u64
+                      , len: usize
+                      , items: ZipList<Item<T>>
+                      , history: Vec<Change<T>>
+                      }
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.counter, self.replica)
+    }
+}
+
+impl<T> Replica<T> {
+    /// Create a new empty replica, with the ID `replica`.
+    ///
+    /// Every replica of a sequence must have a different ID.
+    pub fn new(replica: u64) -> Self {
+        Replica { replica
+                , clock: 0
+                , len: 0
+                , items: ZipList::new()
+                , history: Vec::new()
+                }
+    }
+
+    /// Returns this replica's ID.
+    #[inline] pub fn id(&self) -> u64 { self.replica }
+
+    /// Returns the number of elements, not counting removed ones.
+    #[inline] pub fn len(&self) -> usize { self.len }
+
+    /// Returns true if there are no elements, or they have all been removed.
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns every change that has been made to or applied to this replica,
+    /// in the order they were applied.
+    #[inline] pub fn history(&self) -> &[Change<T>] { &self.history }
+
+    /// Borrow the element to the left of the cursor.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if there is an element to the left of the cursor
+    /// - `None` if there are no elements to the left of the cursor
+    pub fn peek_left(&self) -> Option<&T> {
+        self.items.left_iter().filter_map(|item| item.value.as_ref()).next()
+    }
+
+    /// Borrow the element to the right of the cursor.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if there is an element to the right of the cursor
+    /// - `None` if there are no elements to the right of the cursor
+    pub fn peek_right(&self) -> Option<&T> {
+        self.items.right_iter().filter_map(|item| item.value.as_ref()).next()
+    }
+
+    /// Move the cursor one element to the left.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved to the left
+    /// - `false` if the cursor is already at the left
+    pub fn move_left(&mut self) -> bool {
+        while self.items.move_left() {
+            let passed = self.items.peek_right();
+            if passed.and_then(|item| item.value.as_ref()).is_some() {
+                return true
+            }
+        }
+        false
+    }
+
+    /// Move the cursor one element to the right.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved to the right
+    /// - `false` if the cursor is already at the right
+    pub fn move_right(&mut self) -> bool {
+        while self.items.move_right() {
+            let passed = self.items.peek_left();
+            if passed.and_then(|item| item.value.as_ref()).is_some() {
+                return true
+            }
+        }
+        false
+    }
+
+    /// Returns the position of the element `id` among all the items,
+    /// including tombstones.
+    fn index_of(&self, id: Id) -> Option<usize> {
+        let cursor = self.items.left.len();
+        self.items.left_iter().position(|item| item.id == id)
+            .map(|i| cursor - 1 - i)
+            .or_else(|| self.items.right_iter().position(|item| item.id == id)
+                                               .map(|i| cursor + i))
+    }
+
+    /// Move the cursor to `index` among all the items, including tombstones.
+    fn seek_to(&mut self, index: usize) {
+        let cursor = self.items.left.len();
+        if index < cursor { self.items.seek_left(cursor - index); }
+        else { self.items.seek_right(index - cursor); }
+    }
+}
+
+impl<T> Replica<T>
+where T: Clone {
+    /// Create a new replica with the ID `replica`, with the same elements
+    /// and history as this one, and its cursor at the start.
+    pub fn fork(&self, replica: u64) -> Self {
+        let mut fork = self.clone();
+        fork.replica = replica;
+        fork.seek_to(0);
+        fork
+    }
+
+    /// Insert `elem` to the left of the cursor.
+    ///
+    /// # Returns
+    /// The change to send to other replicas.
+    pub fn insert(&mut self, elem: T) -> Change<T> {
+        self.clock += 1;
+        let id = Id { counter: self.clock, replica: self.replica };
+        let after = self.items.peek_left().map(|item| item.id);
+        self.items.push_left(Item { id, value: Some(elem.clone()) });
+        self.len += 1;
+        self.record(Change::Insert { id, after, elem })
+    }
+
+    /// Remove the element to the left of the cursor.
+    ///
+    /// # Returns
+    /// - `Some(Change)` to send to other replicas, if there was an element
+    ///   to the left of the cursor
+    /// - `None` if there are no elements to the left of the cursor
+    pub fn remove_left(&mut self) -> Option<Change<T>> {
+        let id = {
+            let item = self.items.left.iter_mut()
+                           .find(|item| item.value.is_some())?;
+            item.value = None;
+            item.id
+        };
+        self.len -= 1;
+        Some(self.record(Change::Remove { id }))
+    }
+
+    /// Remove the element to the right of the cursor.
+    ///
+    /// # Returns
+    /// - `Some(Change)` to send to other replicas, if there was an element
+    ///   to the right of the cursor
+    /// - `None` if there are no elements to the right of the cursor
+    pub fn remove_right(&mut self) -> Option<Change<T>> {
+        let id = {
+            let item = self.items.right.iter_mut()
+                           .find(|item| item.value.is_some())?;
+            item.value = None;
+            item.id
+        };
+        self.len -= 1;
+        Some(self.record(Change::Remove { id }))
+    }
+
+    /// Apply a change made to another replica.
+    ///
+    /// The cursor stays between the same elements, and a change that has
+    /// already been applied is ignored. Elements inserted right at the
+    /// cursor end up to its right.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the change was applied, or had been already
+    /// - `Err(CausalityError)` if the change refers to an element this
+    ///   replica hasn't seen yet, in which case it isn't applied, and can be
+    ///   applied again once the change that inserted the element has been
+    ///   applied
+    ///
+    /// # Time complexity
+    /// O(the number of elements, including removed ones)
+    pub fn apply(&mut self, change: &Change<T>) -> Result<(), CausalityError> {
+        match *change {
+            Change::Insert { id, after, ref elem } => {
+                if self.index_of(id).is_some() { return Ok(()) }
+                let start = match after {
+                    Some(after) => match self.index_of(after) {
+                        Some(index) => index + 1
+                      , None => return Err(CausalityError { missing: after })
+                    }
+                  , None => 0
+                };
+                let cursor = self.items.left.len();
+                self.seek_to(start);
+                // skip past concurrent insertions after the same element
+                while let Some(item) = self.items.peek_right() {
+                    if item.id < id { break }
+                    self.items.move_right();
+                }
+                let index = self.items.left.len();
+                self.items.push_right(Item { id, value: Some(elem.clone()) });
+                self.seek_to(if index < cursor { cursor + 1 } else { cursor });
+                self.clock = self.clock.max(id.counter);
+                self.len += 1;
+            }
+          , Change::Remove { id } => {
+                let Replica { ref mut items, .. } = *self;
+                let item = items.left.iter_mut()
+                                .chain(items.right.iter_mut())
+                                .find(|item| item.id == id)
+                                .ok_or(CausalityError { missing: id })?;
+                if item.value.take().is_none() { return Ok(()) }
+                self.len -= 1;
+            }
+        }
+        self.record(change.clone());
+        Ok(())
+    }
+
+    /// Apply every change `other` has seen that this replica hasn't.
+    ///
+    /// Merging replicas in either direction, or in any order, leaves them
+    /// with the same elements.
+    ///
+    /// # Time complexity
+    /// O(the length of `other`'s history × the number of elements)
+    pub fn merge(&mut self, other: &Replica<T>) {
+        for change in &other.history {
+            // `other`'s history is in an order it could apply changes in,
+            // so every element a change refers to has already been seen.
+            self.apply(change)
+                .expect("replica history should be causally ordered");
+        }
+    }
+
+    /// Returns clones of the elements, not counting removed ones, in order.
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut elems = self.items.left_iter()
+                            .filter_map(|item| item.value.clone())
+                            .collect::<Vec<_>>();
+        elems.reverse();
+        elems.extend(self.items.right_iter()
+                         .filter_map(|item| item.value.clone()));
+        elems
+    }
+
+    fn record(&mut self, change: Change<T>) -> Change<T> {
+        self.history.push(change.clone());
+        change
+    }
+}
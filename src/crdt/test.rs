@@ -0,0 +1,104 @@
+use super::{Change, Replica};
+use error::CausalityError;
+
+/// Make an edit to `replica`, chosen by `op`.
+fn edit(replica: &mut Replica<usize>, op: u8, elem: usize) {
+    match op % 5 {
+        0 | 1 => { replica.insert(elem); }
+      , 2 => { replica.remove_left(); }
+      , 3 => { replica.move_left(); }
+      , _ => { replica.remove_right(); replica.move_right(); }
+    }
+}
+
+quickcheck! {
+    fn concurrent_edits_converge( start: Vec<usize>
+                                , a_ops: Vec<(u8, usize)>
+                                , b_ops: Vec<(u8, usize)>
+                                , c_ops: Vec<(u8, usize)> ) -> bool {
+        let mut a = Replica::new(1);
+        for elem in start { a.insert(elem); }
+        let mut b = a.fork(2);
+        let mut c = a.fork(3);
+        for (op, elem) in a_ops { edit(&mut a, op, elem) }
+        for (op, elem) in b_ops { edit(&mut b, op, elem) }
+        for (op, elem) in c_ops { edit(&mut c, op, elem) }
+
+        let mut abc = a.clone();
+        abc.merge(&b);
+        abc.merge(&c);
+        let mut cba = c.clone();
+        cba.merge(&b);
+        cba.merge(&a);
+        b.merge(&c);
+        b.merge(&a);
+        abc.to_vec() == cba.to_vec() && abc.to_vec() == b.to_vec() &&
+        abc.len() == abc.to_vec().len()
+    }
+
+    fn cursor_edits_like_zipper(ops: Vec<(u8, usize)>) -> bool {
+        let mut replica = Replica::new(1);
+        let mut zipper = ::ZipList::new();
+        for (op, elem) in ops {
+            edit(&mut replica, op, elem);
+            match op % 5 {
+                0 | 1 => { zipper.push_left(elem); }
+              , 2 => { zipper.pop_left(); }
+              , 3 => { zipper.move_left(); }
+              , _ => { zipper.pop_right(); zipper.move_right(); }
+            }
+            if replica.peek_left() != zipper.peek_left() ||
+               replica.peek_right() != zipper.peek_right() {
+                return false
+            }
+        }
+        replica.len() == zipper.len()
+    }
+}
+
+#[test]
+fn concurrent_inserts_at_same_place_are_ordered() {
+    let mut a = Replica::new(1);
+    a.insert('a');
+    a.insert('d');
+    a.move_left();
+    let mut b = a.fork(2);
+    b.move_right();
+
+    let from_a = a.insert('b');
+    let from_b = b.insert('c');
+    a.apply(&from_b).unwrap();
+    b.apply(&from_a).unwrap();
+    assert_eq!(a.to_vec(), vec!['a', 'c', 'b', 'd']);
+    assert_eq!(b.to_vec(), a.to_vec());
+
+    // a's cursor stays to the right of the 'b' it inserted, and b's
+    // stays to the right of its 'c'
+    assert_eq!(a.peek_left(), Some(&'b'));
+    assert_eq!(b.peek_left(), Some(&'c'));
+
+    // applying a change again does nothing
+    a.apply(&from_b).unwrap();
+    assert_eq!(a.len(), 4);
+}
+
+#[test]
+fn changes_must_be_applied_in_causal_order() {
+    let mut a = Replica::new(1);
+    let first = a.insert(1);
+    let second = a.insert(2);
+    let removal = a.remove_left().unwrap();
+
+    let mut b = Replica::new(2);
+    let missing = match first {
+        Change::Insert { id, .. } => id
+      , Change::Remove { .. } => unreachable!()
+    };
+    assert_eq!(b.apply(&second), Err(CausalityError { missing }));
+    assert!(b.is_empty());
+    b.apply(&first).unwrap();
+    b.apply(&second).unwrap();
+    b.apply(&removal).unwrap();
+    assert_eq!(b.to_vec(), a.to_vec());
+    assert_eq!(b.history(), a.history());
+}
@@ -0,0 +1,446 @@
+//! Computing the differences between zippers.
+//!
+//! [`ZipList::diff`] produces an [`EditScript`], a sequence of [`Op`]s which
+//! describe how to turn one zipper's elements into another's by walking
+//! over them from left to right, and [`ZipList::apply`] carries one out.
+//!
+//! [`ZipList::apply_edits`] makes a batch of [`Edit`]s instead, each at a
+//! position in the zipper as it was before any of them were made, as a
+//! refactoring tool or a language server would produce them.
+//!
+//! [`ZipList::diff`]: ../struct.ZipList.html#method.diff
+//! [`ZipList::apply`]: ../struct.ZipList.html#method.apply
+//! [`EditScript`]: struct.EditScript.html
+//! [`Op`]: enum.Op.html
+//! [`ZipList::apply_edits`]: ../struct.ZipList.html#method.apply_edits
+//! [`Edit`]: enum.Edit.html
+use super::{Stack, ZipList};
+use error::{EditError, PatchError};
+
+#[cfg(test)] mod test;
+
+/// One step of an [`EditScript`](struct.EditScript.html).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Op<T> {
+    /// Keep the next `n` elements as they are.
+    Keep(usize)
+  , /// Remove the next `n` elements.
+    Delete(usize)
+  , /// Insert these elements, in order.
+    Insert(Vec<T>)
+}
+
+/// One edit in a batch applied by
+/// [`ZipList::apply_edits`](../struct.ZipList.html#method.apply_edits).
+///
+/// Positions are indices into the zipper as it was before the batch, so
+/// edits don't need to account for the ones before them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// Insert an element before the element at this position, or at the
+    /// right end if the position is the zipper's length.
+    Insert(usize, T)
+  , /// Delete the element at this position.
+    Delete(usize)
+  , /// Replace the element at this position.
+    Replace(usize, T)
+}
+
+impl<T> Edit<T> {
+    /// Returns the position the edit applies at.
+    #[inline] pub fn position(&self) -> usize {
+        match *self {
+            Edit::Insert(pos, _) | Edit::Delete(pos) | Edit::Replace(pos, _) =>
+                pos
+        }
+    }
+
+    /// Returns true if the edit removes the element at its position.
+    #[inline] fn removes(&self) -> bool {
+        match *self {
+            Edit::Insert(..) => false
+          , Edit::Delete(_) | Edit::Replace(..) => true
+        }
+    }
+}
+
+/// A description of how to turn one sequence of elements into another.
+///
+/// Each [`Op`](enum.Op.html) is applied at the position where the previous
+/// one left off, starting from the left end of the sequence. Elements after
+/// the last `Op` are kept as they are.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EditScript<T> { /// The steps of the script, in order.
+                           pub ops: Vec<Op<T>>
+                         , /// The cursor position (the number of elements
+                           /// to the left of the cursor) in the zipper the
+                           /// script produces.
+                           pub cursor: usize
+                         }
+
+impl<T> EditScript<T> {
+    /// Returns true if the script doesn't change any elements.
+    pub fn is_noop(&self) -> bool {
+        self.ops.iter().all(|op| match *op {
+            Op::Keep(_) => true
+          , Op::Delete(n) => n == 0
+          , Op::Insert(ref elems) => elems.is_empty()
+        })
+    }
+
+    /// Returns the number of elements the script keeps or deletes, which
+    /// is the least number of elements it can be applied to.
+    pub fn required_len(&self) -> usize {
+        self.ops.iter().map(|op| match *op {
+            Op::Keep(n) | Op::Delete(n) => n
+          , Op::Insert(_) => 0
+        }).sum()
+    }
+
+    /// Append `op` to the script, merging it into the last step if they're
+    /// the same kind of step.
+    fn push(&mut self, op: Op<T>) {
+        match (self.ops.last_mut(), op) {
+            (Some(&mut Op::Keep(ref mut n)), Op::Keep(m)) => *n += m
+          , (Some(&mut Op::Delete(ref mut n)), Op::Delete(m)) => *n += m
+          , (Some(&mut Op::Insert(ref mut elems)), Op::Insert(more)) =>
+                elems.extend(more)
+          , (_, op) => self.ops.push(op)
+        }
+    }
+}
+
+impl<T> ZipList<T>
+where T: PartialEq + Clone {
+    /// Returns an edit script which turns this zipper's elements into
+    /// `other`'s, using [Myers' diff algorithm].
+    ///
+    /// The script is as short as possible: it deletes and inserts the fewest
+    /// elements needed. It also records the position of `other`'s cursor.
+    /// Only O(n + m) extra memory is used, however different the zippers
+    /// are.
+    ///
+    /// # Time complexity
+    /// O((n + m) · d), where `d` is the number of elements deleted and
+    /// inserted, after skipping any common prefix and suffix in O(n + m).
+    ///
+    /// [Myers' diff algorithm]: http://www.xmailserver.org/diff2.pdf
+    pub fn diff(&self, other: &ZipList<T>) -> EditScript<T> {
+        let a = self.elements();
+        let b = other.elements();
+
+        let prefix = a.iter().zip(b.iter())
+                      .take_while(|&(x, y)| x == y)
+                      .count();
+        let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev())
+                                .take_while(|&(x, y)| x == y)
+                                .count();
+
+        let mut script = EditScript { ops: Vec::new()
+                                    , cursor: other.left.len() };
+        if prefix > 0 { script.push(Op::Keep(prefix)); }
+        for step in myers(&a[prefix..a.len() - suffix]
+                         , &b[prefix..b.len() - suffix]) {
+            script.push(match step {
+                Step::Keep => Op::Keep(1)
+              , Step::Delete => Op::Delete(1)
+              , Step::Insert(elem) => Op::Insert(vec![elem.clone()])
+            });
+        }
+        script.push(Op::Keep(suffix));
+        // trailing `Keep`s are implied
+        if let Some(&Op::Keep(_)) = script.ops.last() { script.ops.pop(); }
+        script
+    }
+
+    /// Returns references to all of the elements, in order.
+    fn elements(&self) -> Vec<&T> {
+        let mut elems = self.left.iter().collect::<Vec<_>>();
+        elems.reverse();
+        elems.extend(self.right.iter());
+        elems
+    }
+}
+
+impl<T> ZipList<T>
+where T: Clone {
+    /// Apply the edits in `script` to this zipper.
+    ///
+    /// The edits are made in a single pass from left to right, starting at
+    /// the first element the script changes. The cursor stays between the
+    /// same elements as before, where they still exist: if the elements on
+    /// either side of it are deleted, it ends up where they were, and
+    /// elements inserted right at the cursor end up to its right.
+    ///
+    /// To move the cursor to where it was in the zipper the script was made
+    /// from, seek to `script.cursor` afterwards.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the script was applied
+    /// - `Err(PatchError)` if the script refers to more elements than this
+    ///   zipper has, in which case the zipper isn't changed
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the first edit + the number of elements
+    /// the script keeps, deletes, and inserts after that)
+    pub fn apply(&mut self, script: &EditScript<T>) -> Result<(), PatchError> {
+        let len = self.len();
+        let required = script.required_len();
+        if required > len { return Err(PatchError { required, len }) }
+
+        let cursor = self.left.len();
+        let mut ops = script.ops.iter().peekable();
+        // a leading run of kept elements can be skipped over
+        let start = match ops.peek() {
+            Some(&&Op::Keep(n)) => { ops.next(); n }
+          , _ => 0
+        };
+        if start < cursor { self.seek_left(cursor - start); }
+        else { self.seek_right(start - cursor); }
+
+        // `pos` is the index in the original sequence the next op applies
+        // to, and `out` is the index it'll end up at in the edited sequence.
+        let (mut pos, mut out) = (start, start);
+        let mut new_cursor = if cursor <= start { Some(cursor) } else { None };
+        for op in ops {
+            if new_cursor.is_none() && pos >= cursor { new_cursor = Some(out) }
+            match *op {
+                Op::Keep(n) => {
+                    if new_cursor.is_none() && cursor <= pos + n {
+                        new_cursor = Some(out + cursor - pos)
+                    }
+                    self.seek_right(n);
+                    pos += n;
+                    out += n;
+                }
+              , Op::Delete(n) => {
+                    if new_cursor.is_none() && cursor <= pos + n {
+                        new_cursor = Some(out)
+                    }
+                    for _ in 0..n { self.right.pop(); }
+                    pos += n;
+                }
+              , Op::Insert(ref elems) => {
+                    for elem in elems { self.left.push(elem.clone()); }
+                    out += elems.len();
+                }
+            }
+        }
+        let new_cursor = new_cursor.unwrap_or_else(|| out + cursor - pos);
+
+        if new_cursor < out { self.seek_left(out - new_cursor); }
+        else { self.seek_right(new_cursor - out); }
+        Ok(())
+    }
+}
+
+impl<T> ZipList<T> {
+    /// Apply a batch of `edits`, each at a position in the zipper as it is
+    /// before any of them are made, in a single pass from left to right.
+    ///
+    /// The edits may come in any order. Elements inserted at the same
+    /// position end up in the order they were given, before the element at
+    /// that position, which can also be deleted or replaced. The cursor
+    /// stays between the same elements, as with [`apply`], and elements
+    /// inserted right at the cursor end up to its right.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the edits were applied
+    /// - `Err(EditError)` if an edit is out of bounds, or two edits remove
+    ///   the same element, in which case the zipper isn't changed
+    ///
+    /// # Time complexity
+    /// O(e log e) to sort the e edits, plus O(distance from the cursor to
+    /// the first edit + distance from the first edit to the last).
+    ///
+    /// [`apply`]: #method.apply
+    pub fn apply_edits<I>(&mut self, edits: I) -> Result<(), EditError>
+    where I: IntoIterator<Item=Edit<T>> {
+        let mut edits = edits.into_iter().collect::<Vec<_>>();
+        // the sort is stable, so inserts at the same position keep their
+        // order, and they all come before the element there is removed.
+        edits.sort_by_key(|edit| (edit.position(), edit.removes()));
+        let len = self.len();
+        let mut removed = None;
+        for edit in &edits {
+            let position = edit.position();
+            let end = if edit.removes() { position + 1 } else { position };
+            if end > len {
+                return Err(EditError::OutOfBounds { position, len })
+            }
+            if edit.removes() {
+                if removed == Some(position) {
+                    return Err(EditError::Overlap { position })
+                }
+                removed = Some(position);
+            }
+        }
+        let start = match edits.first() {
+            Some(edit) => edit.position()
+          , None => return Ok(())
+        };
+        trace_span!("apply_edits", edits = edits.len(), start, len);
+
+        // where the cursor ends up, counting the elements inserted and
+        // deleted to its left.
+        let cursor = self.left.len();
+        let new_cursor = edits.iter()
+            .take_while(|edit| edit.position() < cursor)
+            .fold(cursor, |n, edit| match *edit {
+                Edit::Insert(..) => n + 1
+              , Edit::Delete(_) => n - 1
+              , Edit::Replace(..) => n
+            });
+
+        if start < cursor { self.seek_left(cursor - start); }
+        else { self.seek_right(start - cursor); }
+        // `pos` is the index in the original sequence of the element to the
+        // right of the cursor, and `out` is the cursor's index now.
+        let (mut pos, mut out) = (start, start);
+        for edit in edits {
+            let skip = edit.position() - pos;
+            self.seek_right(skip);
+            pos += skip;
+            out += skip;
+            match edit {
+                Edit::Insert(_, elem) => {
+                    self.left.push(elem);
+                    out += 1;
+                }
+              , Edit::Delete(_) => {
+                    self.right.pop();
+                    pos += 1;
+                }
+              , Edit::Replace(_, elem) => {
+                    self.right.pop();
+                    self.left.push(elem);
+                    pos += 1;
+                    out += 1;
+                }
+            }
+        }
+
+        if new_cursor < out { self.seek_left(out - new_cursor); }
+        else { self.seek_right(new_cursor - out); }
+        Ok(())
+    }
+}
+
+/// One element's worth of a Myers diff.
+enum Step<'a, T: 'a> { Keep, Delete, Insert(&'a T) }
+
+/// Returns the shortest sequence of steps which turns `a` into `b`.
+///
+/// This is the linear space version of the algorithm, which finds the
+/// middle of the path from each end at once and recurses on either side of
+/// it, rather than keeping every step of the search to walk back through.
+fn myers<'a, T>(a: &[&'a T], b: &[&'a T]) -> Vec<Step<'a, T>>
+where T: PartialEq {
+    // the furthest reaching paths, forwards and backwards, on diagonals
+    // `-d - 1..=d + 1`, where `d` is at most half of `a.len() + b.len()`
+    let len = (a.len() + b.len()) / 2 + 2;
+    let mut paths = Paths { forward: vec![0; 2 * len + 1]
+                          , backward: vec![0; 2 * len + 1]
+                          , offset: len as isize
+                          };
+    let mut steps = Vec::with_capacity(a.len().max(b.len()));
+    paths.conquer(a, b, &mut steps);
+    steps
+}
+
+/// The furthest reaching paths found by a search for a middle snake.
+///
+/// `forward[k + offset]` is the furthest `x` reached on diagonal `k`
+/// (where `k = x - y`) from the start, and `backward[k + offset]` is the
+/// furthest reached on diagonal `k` from the end, counting backwards.
+struct Paths { forward: Vec<usize>
+             , backward: Vec<usize>
+             , offset: isize
+             }
+
+impl Paths {
+    /// Push the shortest sequence of steps which turns `a` into `b` onto
+    /// `steps`.
+    fn conquer<'a, T>( &mut self, a: &[&'a T], b: &[&'a T]
+                     , steps: &mut Vec<Step<'a, T>>)
+    where T: PartialEq {
+        let prefix = a.iter().zip(b.iter())
+                      .take_while(|&(x, y)| x == y)
+                      .count();
+        let (a, b) = (&a[prefix..], &b[prefix..]);
+        let suffix = a.iter().rev().zip(b.iter().rev())
+                      .take_while(|&(x, y)| x == y)
+                      .count();
+        let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+        steps.extend((0..prefix).map(|_| Step::Keep));
+        if a.is_empty() {
+            steps.extend(b.iter().map(|elem| Step::Insert(*elem)));
+        } else if b.is_empty() {
+            steps.extend(a.iter().map(|_| Step::Delete));
+        } else {
+            let (x, y) = self.middle_snake(a, b);
+            self.conquer(&a[..x], &b[..y], steps);
+            self.conquer(&a[x..], &b[y..], steps);
+        }
+        steps.extend((0..suffix).map(|_| Step::Keep));
+    }
+
+    /// Returns a point on a shortest path from the start of `a` and `b` to
+    /// their end, other than either end of it.
+    ///
+    /// `a` and `b` mustn't be empty, or start or end with the same element.
+    fn middle_snake<T>(&mut self, a: &[&T], b: &[&T]) -> (usize, usize)
+    where T: PartialEq {
+        let (n, m) = (a.len() as isize, b.len() as isize);
+        let delta = n - m;
+        let odd = delta % 2 != 0;
+        let offset = self.offset;
+        let idx = |k: isize| (k + offset) as usize;
+        self.forward[idx(1)] = 0;
+        self.backward[idx(1)] = 0;
+
+        for d in 0..(n + m + 1) / 2 + 1 {
+            let mut k = -d;
+            while k <= d {
+                let v = &mut self.forward;
+                let x = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)])
+                            { v[idx(k + 1)] }
+                        else { v[idx(k - 1)] + 1 } as isize;
+                let (x0, y0) = (x, x - k);
+                let (mut x, mut y) = (x0, y0);
+                while x < n && y < m && a[x as usize] == b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx(k)] = x as usize;
+                if odd && (k - delta).abs() < d &&
+                   x + self.backward[idx(delta - k)] as isize >= n {
+                    return (x0 as usize, y0 as usize)
+                }
+                k += 2;
+            }
+
+            let mut k = -d;
+            while k <= d {
+                let v = &mut self.backward;
+                let x = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)])
+                            { v[idx(k + 1)] }
+                        else { v[idx(k - 1)] + 1 } as isize;
+                let (mut x, mut y) = (x, x - k);
+                while x < n && y < m &&
+                      a[(n - x - 1) as usize] == b[(m - y - 1) as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx(k)] = x as usize;
+                if !odd && (k - delta).abs() <= d &&
+                   x + self.forward[idx(delta - k)] as isize >= n {
+                    return ((n - x) as usize, (m - y) as usize)
+                }
+                k += 2;
+            }
+        }
+        unreachable!("the paths from either end always meet")
+    }
+}
@@ -0,0 +1,227 @@
+use ::ZipList;
+use error::{EditError, PatchError};
+use super::{Edit, EditScript, Op};
+use test::contents;
+
+/// Apply `script` to `elems`, as a reference for what a script should do.
+fn apply(elems: &[usize], script: &EditScript<usize>) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    for op in &script.ops {
+        match *op {
+            Op::Keep(n) => {
+                result.extend_from_slice(&elems[i..i + n]);
+                i += n;
+            }
+          , Op::Delete(n) => i += n
+          , Op::Insert(ref inserted) => result.extend_from_slice(inserted)
+        }
+    }
+    result.extend_from_slice(&elems[i..]);
+    result
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`.
+fn lcs(a: &[usize], b: &[usize]) -> usize {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            table[i + 1][j + 1] = if a[i] == b[j] { table[i][j] + 1 }
+                                  else { table[i][j + 1].max(table[i + 1][j]) };
+        }
+    }
+    table[a.len()][b.len()]
+}
+
+fn changed(script: &EditScript<usize>) -> usize {
+    script.ops.iter().map(|op| match *op {
+        Op::Keep(_) => 0
+      , Op::Delete(n) => n
+      , Op::Insert(ref elems) => elems.len()
+    }).sum()
+}
+
+quickcheck! {
+    fn diff_turns_self_into_other(a: ZipList<usize>, b: ZipList<usize>)
+                                  -> bool {
+        let script = a.diff(&b);
+        apply(&contents(&a).0, &script) == contents(&b).0 &&
+        script.cursor == b.left_iter().len()
+    }
+
+    fn diff_is_minimal(a: ZipList<u8>, b: ZipList<u8>) -> bool {
+        // small elements, so that there's something in common
+        let small = |zipper: &ZipList<u8>| ZipList::from_iter_with_cursor(
+            zipper.left_iter().chain(zipper.right_iter())
+                  .map(|&x| (x % 4) as usize), 0);
+        let (a, b) = (small(&a), small(&b));
+        let (xs, ys) = (contents(&a).0, contents(&b).0);
+        changed(&a.diff(&b)) == xs.len() + ys.len() - 2 * lcs(&xs, &ys)
+    }
+
+    fn diff_with_self_is_noop(a: ZipList<usize>) -> bool {
+        a.diff(&a).is_noop()
+    }
+
+    fn diff_has_no_empty_steps(a: ZipList<u8>, b: ZipList<u8>) -> bool {
+        a.diff(&b).ops.iter().all(|op| match *op {
+            Op::Keep(n) | Op::Delete(n) => n > 0
+          , Op::Insert(ref elems) => !elems.is_empty()
+        })
+    }
+}
+
+quickcheck! {
+    fn apply_diff_turns_self_into_other(a: ZipList<usize>, b: ZipList<usize>)
+                                        -> bool {
+        let script = a.diff(&b);
+        let mut patched = a;
+        patched.apply(&script).is_ok() && contents(&patched).0 == contents(&b).0
+    }
+
+    fn apply_matches_reference(a: ZipList<usize>, b: ZipList<usize>) -> bool {
+        let script = a.diff(&b);
+        let mut patched = a.clone();
+        patched.apply(&script).unwrap();
+        contents(&patched).0 == apply(&contents(&a).0, &script) &&
+        patched.left_iter().len() <= patched.len()
+    }
+
+    fn apply_too_long_script_fails(a: ZipList<usize>, extra: usize) -> bool {
+        let extra = extra % 10 + 1;
+        let script = EditScript { ops: vec![Op::Keep(a.len() + extra)]
+                                , cursor: 0 };
+        let mut patched = a.clone();
+        patched.apply(&script) ==
+            Err(PatchError { required: a.len() + extra, len: a.len() }) &&
+        patched.left_iter().eq(a.left_iter()) &&
+        patched.right_iter().eq(a.right_iter())
+    }
+}
+
+#[test]
+fn apply_keeps_cursor_between_same_elements() {
+    // edits on both sides of the cursor
+    let mut zipper = zipper![1, 2, 3 ; 4, 5, 6];
+    zipper.apply(&EditScript { ops: vec![ Op::Delete(1)
+                                        , Op::Keep(2)
+                                        , Op::Insert(vec![7])
+                                        , Op::Keep(1)
+                                        , Op::Delete(1)
+                                        ]
+                             , cursor: 0 }).unwrap();
+    assert_eq!(zipper.left_iter().collect::<Vec<_>>(), vec![&3, &2]);
+    assert_eq!(zipper.right_iter().collect::<Vec<_>>(), vec![&7, &4, &6]);
+
+    // deleting around the cursor leaves it where the elements were
+    let mut zipper = zipper![1, 2 ; 3, 4];
+    zipper.apply(&EditScript { ops: vec![Op::Keep(1), Op::Delete(2)]
+                             , cursor: 0 }).unwrap();
+    assert_eq!(zipper.left_iter().collect::<Vec<_>>(), vec![&1]);
+    assert_eq!(zipper.right_iter().collect::<Vec<_>>(), vec![&4]);
+}
+
+#[test]
+fn diff_merges_runs() {
+    let a = zipper![1, 2, 3, 4 ;];
+    let b = zipper![1 ; 5, 6, 4];
+    assert_eq!( a.diff(&b)
+              , EditScript { ops: vec![ Op::Keep(1)
+                                      , Op::Delete(2)
+                                      , Op::Insert(vec![5, 6])
+                                      ]
+                           , cursor: 1 });
+}
+
+#[test]
+fn diff_of_disjoint_zippers() {
+    // the search goes as far as it can here, which used to take memory
+    // quadratic in the lengths
+    let a = ZipList::from_iter_with_cursor(0..5000, 0);
+    let b = ZipList::from_iter_with_cursor(5000..10000, 0);
+    assert_eq!( a.diff(&b)
+              , EditScript { ops: vec![ Op::Delete(5000)
+                                      , Op::Insert((5000..10000).collect())
+                                      ]
+                           , cursor: 0 });
+}
+
+/// Apply `edits` to `elems` with the cursor at `cursor`, as a reference for
+/// what `apply_edits` should do.
+fn apply_edits(elems: &[usize], cursor: usize, edits: &[Edit<usize>])
+               -> (Vec<usize>, usize) {
+    let mut result = Vec::new();
+    let mut new_cursor = None;
+    for i in 0..elems.len() + 1 {
+        if i == cursor { new_cursor = Some(result.len()) }
+        let mut kept = i < elems.len();
+        for edit in edits.iter().filter(|edit| edit.position() == i) {
+            if let Edit::Insert(_, elem) = *edit { result.push(elem) }
+        }
+        for edit in edits.iter().filter(|edit| edit.position() == i) {
+            match *edit {
+                Edit::Insert(..) => {}
+              , Edit::Delete(_) => kept = false
+              , Edit::Replace(_, elem) => { kept = false; result.push(elem) }
+            }
+        }
+        if kept { result.push(elems[i]) }
+    }
+    (result, new_cursor.unwrap())
+}
+
+/// Turn arbitrary triples into edits which fit a zipper of `len` elements,
+/// removing each element at most once.
+fn valid_edits(len: usize, raw: Vec<(u8, usize, usize)>) -> Vec<Edit<usize>> {
+    let mut removed = Vec::new();
+    raw.into_iter().filter_map(|(kind, pos, elem)| {
+        if kind % 3 == 0 || len == 0 {
+            return Some(Edit::Insert(pos % (len + 1), elem))
+        }
+        let pos = pos % len;
+        if removed.contains(&pos) { return None }
+        removed.push(pos);
+        Some(if kind % 3 == 1 { Edit::Delete(pos) }
+             else { Edit::Replace(pos, elem) })
+    }).collect()
+}
+
+quickcheck! {
+    fn apply_edits_matches_reference( a: ZipList<usize>
+                                    , raw: Vec<(u8, usize, usize)> )
+                                    -> bool {
+        let elems = contents(&a).0;
+        let cursor = a.left_iter().len();
+        let edits = valid_edits(elems.len(), raw);
+        let expected = apply_edits(&elems, cursor, &edits);
+        let mut edited = a.clone();
+        edited.apply_edits(edits).is_ok() &&
+        (contents(&edited).0, edited.left_iter().len()) == expected
+    }
+}
+
+#[test]
+fn apply_edits_in_any_order() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..6, 3);
+    zipper.apply_edits(vec![ Edit::Replace(4, 40)
+                           , Edit::Insert(3, 30)
+                           , Edit::Delete(0)
+                           , Edit::Insert(6, 60)
+                           , Edit::Insert(3, 31)
+                           , Edit::Delete(2)
+                           ]).unwrap();
+    assert_eq!(contents(&zipper).0, vec![1, 30, 31, 3, 40, 5, 60]);
+    assert_eq!(zipper.left_iter().collect::<Vec<_>>(), vec![&1]);
+}
+
+#[test]
+fn apply_edits_rejects_bad_batches() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..3, 1);
+    assert_eq!( zipper.apply_edits(vec![Edit::Delete(0), Edit::Delete(3)])
+              , Err(EditError::OutOfBounds { position: 3, len: 3 }) );
+    assert_eq!( zipper.apply_edits(vec![Edit::Delete(1), Edit::Replace(1, 9)])
+              , Err(EditError::Overlap { position: 1 }) );
+    assert_eq!(zipper.apply_edits(vec![Edit::Insert(3, 3)]), Ok(()));
+    assert_eq!(contents(&zipper).0, vec![0, 1, 2, 3]);
+    assert_eq!(zipper.left_iter().collect::<Vec<_>>(), vec![&0]);
+}
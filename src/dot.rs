@@ -0,0 +1,114 @@
+//! Drawing lists and zippers with Graphviz.
+//!
+//! [`List::to_dot`] and [`ZipList::to_dot`] describe the actual nodes of a
+//! list or zipper, and the links between them, in the [DOT language]. The
+//! output can be rendered with e.g. `dot -Tsvg`.
+//!
+//! [`List::to_dot`]: ../struct.List.html#method.to_dot
+//! [`ZipList::to_dot`]: ../struct.ZipList.html#method.to_dot
+//! [DOT language]: https://graphviz.org/doc/info/lang.html
+use std::fmt::{self, Write};
+
+use super::{List, ZipList};
+
+/// Graph attributes shared by lists and zippers.
+const HEADER: &str = "    rankdir=LR;\n    node [shape=record];\n";
+
+/// Formats a value with `Debug`, escaped for use in a record label.
+struct Label<'a, T: 'a>(&'a T);
+
+impl<'a, T> fmt::Display for Label<'a, T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in format!("{:?}", self.0).chars() {
+            match c {
+                '"' | '\\' | '{' | '}' | '|' | '<' | '>' | ' ' =>
+                    write!(f, "\\{}", c)?
+              , '\n' => f.write_str("\\n")?
+              , c => f.write_char(c)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a node for each element of `list`, named `prefix` followed by the
+/// element's index, and the links between them.
+///
+/// If `backwards` is true, the links are drawn from right to left.
+fn write_nodes<T>(out: &mut String, prefix: &str, list: &List<T>
+                 , backwards: bool) -> fmt::Result
+where T: fmt::Debug {
+    for (i, elem) in list.iter().enumerate() {
+        if backwards {
+            writeln!(out, "    {}{} [label=\"<next>|<elem> {}\"];"
+                    , prefix, i, Label(elem))?;
+        } else {
+            writeln!(out, "    {}{} [label=\"<elem> {}|<next>\"];"
+                    , prefix, i, Label(elem))?;
+        }
+        if i == 0 { continue }
+        if backwards {
+            writeln!(out, "    {}{}:elem -> {}{}:next [dir=back];"
+                    , prefix, i, prefix, i - 1)?;
+        } else {
+            writeln!(out, "    {}{}:next -> {}{}:elem;"
+                    , prefix, i - 1, prefix, i)?;
+        }
+    }
+    Ok(())
+}
+
+impl<T> List<T>
+where T: fmt::Debug {
+    /// Returns a DOT graph of this list's nodes and the links between them.
+    ///
+    /// Each node is labelled with its element's `Debug` representation.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        self.write_dot(&mut out).expect("writing to a String can't fail");
+        out
+    }
+
+    fn write_dot(&self, out: &mut String) -> fmt::Result {
+        out.push_str("digraph list {\n");
+        out.push_str(HEADER);
+        writeln!(out, "    list [label=\"List|len: {}\"];", self.len())?;
+        write_nodes(out, "n", self, false)?;
+        if !self.is_empty() { out.push_str("    list -> n0:elem;\n"); }
+        out.push_str("}\n");
+        Ok(())
+    }
+}
+
+impl<T> ZipList<T>
+where T: fmt::Debug {
+    /// Returns a DOT graph of this zipper's nodes and the links between
+    /// them, with the cursor highlighted.
+    ///
+    /// The left side's nodes are drawn to the left of the cursor, in the
+    /// order of the elements, so its links point from right to left.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        self.write_dot(&mut out).expect("writing to a String can't fail");
+        out
+    }
+
+    fn write_dot(&self, out: &mut String) -> fmt::Result {
+        out.push_str("digraph zipper {\n");
+        out.push_str(HEADER);
+        writeln!( out, "    cursor [label=\"cursor|{{left: {}|right: {}}}\", \
+                        style=filled, fillcolor=yellow];"
+                , self.left.len(), self.right.len() )?;
+        write_nodes(out, "left", &self.left, true)?;
+        write_nodes(out, "right", &self.right, false)?;
+        if !self.left.is_empty() {
+            out.push_str("    left0:elem -> cursor [dir=back];\n");
+        }
+        if !self.right.is_empty() {
+            out.push_str("    cursor -> right0:elem;\n");
+        }
+        out.push_str("}\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use super::{List, Stack, ZipList};
+
+/// The position immediately to one side of a `ZipList`'s cursor, which may
+/// or may not hold an element.
+///
+/// This is created by [`ZipList::left_entry`] or [`ZipList::right_entry`],
+/// and allows editing the element next to the cursor if there is one, or
+/// inserting one if there isn't, without branching on a `peek`.
+///
+/// ```
+/// # use an_zipper::ZipList;
+/// let mut zipper = ZipList::new();
+/// zipper.right_entry().and_modify(|n| *n += 1).or_insert(0);
+/// zipper.right_entry().and_modify(|n| *n += 1).or_insert(0);
+/// assert_eq!(zipper.peek_right(), Some(&1));
+/// ```
+///
+/// [`ZipList::left_entry`]: struct.ZipList.html#method.left_entry
+/// [`ZipList::right_entry`]: struct.ZipList.html#method.right_entry
+pub struct Entry<'a, T: 'a> { side: &'a mut List<T> }
+
+impl<T> ZipList<T> {
+    /// Returns the entry for the position immediately left of the cursor.
+    #[inline] pub fn left_entry(&mut self) -> Entry<'_, T> {
+        Entry { side: &mut self.left }
+    }
+
+    /// Returns the entry for the position immediately right of the cursor.
+    #[inline] pub fn right_entry(&mut self) -> Entry<'_, T> {
+        Entry { side: &mut self.right }
+    }
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Returns true if there is an element at this entry.
+    #[inline] pub fn is_occupied(&self) -> bool { !self.side.is_empty() }
+
+    /// Borrow the element at this entry, if there is one.
+    #[inline] pub fn get(&self) -> Option<&T> { self.side.peek() }
+
+    /// Call `f` on the element at this entry, if there is one.
+    ///
+    /// # Returns
+    /// `Self`, so that it can be followed by one of the `or_insert` methods.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where F: FnOnce(&mut T) {
+        if let Some(elem) = self.side.peek_mut() { f(elem) }
+        self
+    }
+
+    /// Insert `default` at this entry if it's empty.
+    ///
+    /// # Returns
+    /// A mutable reference to the element at this entry.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `default` at this entry if it's empty.
+    ///
+    /// # Returns
+    /// A mutable reference to the element at this entry.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut T
+    where F: FnOnce() -> T {
+        if self.side.is_empty() { self.side.push(default()); }
+        self.side.peek_mut().unwrap()
+    }
+
+    /// Insert `elem` at this entry, pushing any element already there
+    /// further away from the cursor.
+    ///
+    /// # Returns
+    /// A mutable reference to the inserted element.
+    pub fn insert(self, elem: T) -> &'a mut T {
+        self.side.push(elem).peek_mut().unwrap()
+    }
+}
+
+impl<'a, T> Entry<'a, T>
+where T: Default {
+    /// Insert `T::default()` at this entry if it's empty.
+    ///
+    /// # Returns
+    /// A mutable reference to the element at this entry.
+    pub fn or_default(self) -> &'a mut T {
+        self.or_insert_with(T::default)
+    }
+}
@@ -0,0 +1,359 @@
+//! Errors returned by fallible operations on lists and zippers.
+use std::error;
+use std::fmt;
+
+use crdt::Id;
+
+/// Any error returned by this crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A seek went past the end of a zipper.
+    Seek(SeekError)
+  , /// An edit script didn't fit the zipper it was applied to.
+    Patch(PatchError)
+  , /// A node couldn't be allocated.
+    Alloc(AllocError)
+  , /// A change was applied to a replica before a change it depends on.
+    Causality(CausalityError)
+  , /// An element couldn't be removed because it's anchored.
+    Anchored(AnchoredError)
+  , /// A batch of edits didn't fit the zipper it was applied to.
+    Edit(EditError)
+  , /// An index or range was out of bounds.
+    Range(RangeError)
+  , /// A mark was used after the zipper it was made for had changed.
+    StaleMark(StaleMarkError)
+  , /// An operation log didn't fit the zipper it was replayed on.
+    #[cfg(feature = "oplog")]
+    Replay(ReplayError)
+}
+
+/// Error returned when a zipper's cursor can't be moved as far as requested.
+///
+/// The cursor isn't moved when this is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeekError { /// The number of positions the cursor was asked to move.
+                       pub requested: usize
+                     , /// The number of positions the seek got before it
+                       /// reached the end of the zipper.
+                       pub moved: usize
+                     }
+
+/// Error returned when an edit script refers to more elements than the
+/// zipper it's applied to has.
+///
+/// The zipper isn't changed when this is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PatchError { /// The number of elements the script keeps or deletes.
+                        pub required: usize
+                      , /// The number of elements in the zipper.
+                        pub len: usize
+                      }
+
+/// Error returned when nodes for a list can't be allocated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocError { /// The number of nodes that were requested.
+                        pub requested: usize
+                      , /// The number of nodes that were reserved.
+                        pub reserved: usize
+                      }
+
+/// Error returned when a change refers to an element that the replica it's
+/// applied to hasn't seen.
+///
+/// The change isn't applied when this is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CausalityError { /// The ID of the element that wasn't found.
+                            pub missing: Id
+                          }
+
+/// Error returned when an element of a [`StableZipList`] can't be removed,
+/// because there are [`Anchor`]s to it.
+///
+/// The element isn't removed when this is returned.
+///
+/// [`StableZipList`]: ../struct.StableZipList.html
+/// [`Anchor`]: ../struct.Anchor.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnchoredError { /// The number of anchors to the element.
+                           pub anchors: usize
+                         }
+
+/// Error returned when an index or range is out of bounds, or a range
+/// starts after it ends.
+///
+/// Nothing is changed when this is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeError { /// The start of the range.
+                        pub start: isize
+                      , /// The end of the range.
+                        pub end: isize
+                      , /// The least valid start.
+                        pub lower: isize
+                      , /// The greatest valid end.
+                        pub upper: isize
+                      }
+
+/// Error returned when a [`Mark`] is used after elements have been added
+/// to, removed from, or reordered in its zipper.
+///
+/// [`Mark`]: ../struct.Mark.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleMarkError { /// The zipper's generation when the mark was
+                            /// made.
+                            pub made: u64
+                          , /// The zipper's generation now.
+                            pub current: u64
+                          }
+
+/// Error returned when a batch of [`Edit`]s can't be applied to a zipper.
+///
+/// The zipper isn't changed when this is returned.
+///
+/// [`Edit`]: ../diff/enum.Edit.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// An edit refers to a position past the end of the zipper.
+    OutOfBounds { /// The position the edit refers to.
+                  position: usize
+                , /// The number of elements in the zipper.
+                  len: usize
+                }
+  , /// More than one edit deletes or replaces the same element.
+    Overlap { /// The position of the element.
+              position: usize
+            }
+}
+
+/// Error returned when a string can't be parsed as a [`List`].
+///
+/// Lists are parsed from the format they're displayed in: elements
+/// separated by commas, surrounded by square brackets.
+///
+/// [`List`]: ../struct.List.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseListError<E> {
+    /// The string wasn't surrounded by square brackets.
+    Brackets
+  , /// An element couldn't be parsed.
+    Elem { /// The index of the element in the list.
+           index: usize
+         , /// The error returned when parsing it.
+           err: E
+         }
+}
+
+/// Error returned when a logged operation refers to a position past the end
+/// of the zipper it's replayed on.
+///
+/// The operations before it have been applied when this is returned.
+#[cfg(feature = "oplog")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayError { /// The number of operations that were applied.
+                         pub applied: usize
+                       , /// The position the failed operation refers to.
+                         pub index: usize
+                       , /// The number of elements in the zipper.
+                         pub len: usize
+                       }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Seek(ref err) => fmt::Display::fmt(err, f)
+          , Error::Patch(ref err) => fmt::Display::fmt(err, f)
+          , Error::Alloc(ref err) => fmt::Display::fmt(err, f)
+          , Error::Causality(ref err) => fmt::Display::fmt(err, f)
+          , Error::Anchored(ref err) => fmt::Display::fmt(err, f)
+          , Error::Edit(ref err) => fmt::Display::fmt(err, f)
+          , Error::Range(ref err) => fmt::Display::fmt(err, f)
+          , Error::StaleMark(ref err) => fmt::Display::fmt(err, f)
+          , #[cfg(feature = "oplog")]
+            Error::Replay(ref err) => fmt::Display::fmt(err, f)
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Seek(ref err) => Some(err)
+          , Error::Patch(ref err) => Some(err)
+          , Error::Alloc(ref err) => Some(err)
+          , Error::Causality(ref err) => Some(err)
+          , Error::Anchored(ref err) => Some(err)
+          , Error::Edit(ref err) => Some(err)
+          , Error::Range(ref err) => Some(err)
+          , Error::StaleMark(ref err) => Some(err)
+          , #[cfg(feature = "oplog")]
+            Error::Replay(ref err) => Some(err)
+        }
+    }
+}
+
+impl fmt::Display for SeekError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "can't seek {} positions, the end is only {} away"
+              , self.requested, self.moved )
+    }
+}
+
+impl error::Error for SeekError {}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "edit script needs {} elements, but there are only {}"
+              , self.required, self.len )
+    }
+}
+
+impl error::Error for PatchError {}
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "failed to allocate {} nodes, only {} were reserved"
+              , self.requested, self.reserved )
+    }
+}
+
+impl error::Error for AllocError {}
+
+impl fmt::Display for CausalityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "change refers to unknown element {}", self.missing)
+    }
+}
+
+impl error::Error for CausalityError {}
+
+impl fmt::Display for AnchoredError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "can't remove an element with {} anchors", self.anchors)
+    }
+}
+
+impl error::Error for AnchoredError {}
+
+impl RangeError {
+    /// Check that `start..end` is a range within `lower..upper`.
+    pub(crate) fn check(start: isize, end: isize, lower: isize, upper: isize)
+                        -> Result<(), RangeError> {
+        if lower <= start && start <= end && end <= upper { Ok(()) }
+        else { Err(RangeError { start, end, lower, upper }) }
+    }
+}
+
+/// Convert an index to the signed type `RangeError` reports, saturating
+/// indices too large to represent.
+pub(crate) fn signed(n: usize) -> isize { n.min(isize::MAX as usize) as isize }
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.start > self.end {
+            write!(f, "range starts at {} but ends at {}", self.start, self.end)
+        } else {
+            write!( f, "range {}..{} is out of bounds {}..{}"
+                  , self.start, self.end, self.lower, self.upper )
+        }
+    }
+}
+
+impl error::Error for RangeError {}
+
+impl fmt::Display for StaleMarkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "mark was made at generation {}, but the zipper is at \
+                    generation {}"
+              , self.made, self.current )
+    }
+}
+
+impl error::Error for StaleMarkError {}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditError::OutOfBounds { position, len } =>
+                write!( f, "edit at position {}, but there are only {} \
+                            elements"
+                      , position, len )
+          , EditError::Overlap { position } =>
+                write!( f, "more than one edit removes the element at \
+                            position {}"
+                      , position )
+        }
+    }
+}
+
+impl error::Error for EditError {}
+
+impl<E> fmt::Display for ParseListError<E>
+where E: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseListError::Brackets =>
+                f.write_str("list must be surrounded by `[` and `]`")
+          , ParseListError::Elem { index, ref err } =>
+                write!(f, "can't parse list element {}: {}", index, err)
+        }
+    }
+}
+
+impl<E> error::Error for ParseListError<E>
+where E: error::Error + 'static {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ParseListError::Brackets => None
+          , ParseListError::Elem { ref err, .. } => Some(err)
+        }
+    }
+}
+
+#[cfg(feature = "oplog")]
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "operation {} refers to position {}, but there are only {} \
+                    elements"
+              , self.applied, self.index, self.len )
+    }
+}
+
+#[cfg(feature = "oplog")]
+impl error::Error for ReplayError {}
+
+impl From<SeekError> for Error {
+    fn from(err: SeekError) -> Self { Error::Seek(err) }
+}
+
+impl From<PatchError> for Error {
+    fn from(err: PatchError) -> Self { Error::Patch(err) }
+}
+
+impl From<AllocError> for Error {
+    fn from(err: AllocError) -> Self { Error::Alloc(err) }
+}
+
+impl From<CausalityError> for Error {
+    fn from(err: CausalityError) -> Self { Error::Causality(err) }
+}
+
+impl From<AnchoredError> for Error {
+    fn from(err: AnchoredError) -> Self { Error::Anchored(err) }
+}
+
+impl From<EditError> for Error {
+    fn from(err: EditError) -> Self { Error::Edit(err) }
+}
+
+impl From<RangeError> for Error {
+    fn from(err: RangeError) -> Self { Error::Range(err) }
+}
+
+impl From<StaleMarkError> for Error {
+    fn from(err: StaleMarkError) -> Self { Error::StaleMark(err) }
+}
+
+#[cfg(feature = "oplog")]
+impl From<ReplayError> for Error {
+    fn from(err: ReplayError) -> Self { Error::Replay(err) }
+}
@@ -0,0 +1,272 @@
+//! A C API for lists and zippers.
+//!
+//! This module is only built with the `ffi` feature. It exposes `List` and
+//! `ZipList` to C and C++ hosts (and anything else that can call C) through
+//! opaque handles, with every function prefixed by `anzipper_`. The matching
+//! declarations are in `include/an_zipper.h`.
+//!
+//! The crate only builds as an `rlib` by default, so Rust users don't pay
+//! for the other library types. To get a library to link against, ask for
+//! a `staticlib` or `cdylib` explicitly:
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type staticlib
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+//!
+//! Elements are untyped pointers, which the lists store but never
+//! dereference or free: the host owns whatever they point to, and has to
+//! pop and free the elements itself before freeing a handle if need be.
+//!
+//! Functions that hand back an element do so through an `out` pointer and
+//! return whether there was one, since a null pointer is a valid element.
+//! `out` may be null, in which case the element is discarded.
+use std::os::raw::c_void;
+
+use super::{List, Stack, ZipList};
+
+#[cfg(test)] mod test;
+
+/// An element of a list or zipper handed across the C API.
+pub type Elem = *mut c_void;
+
+/// The list type behind an `anzipper_list *` handle.
+pub type AnZipperList = List<Elem>;
+
+/// The zipper type behind an `anzipper_zip *` handle.
+pub type AnZipperZip = ZipList<Elem>;
+
+unsafe fn write_out(out: *mut Elem, elem: Option<Elem>) -> bool {
+    match elem {
+        Some(elem) => {
+            if !out.is_null() { *out = elem }
+            true
+        }
+      , None => false
+    }
+}
+
+//==- lists -=================================================================
+
+/// Create a new empty list, returning a handle that must be passed to
+/// `anzipper_list_free` when the host is done with it.
+#[no_mangle]
+pub extern "C" fn anzipper_list_new() -> *mut AnZipperList {
+    Box::into_raw(Box::new(List::new()))
+}
+
+/// Free a list, and all of its nodes. Does nothing if `list` is null.
+///
+/// # Safety
+/// `list` must be null or a live handle from `anzipper_list_new`, and is
+/// dangling afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_list_free(list: *mut AnZipperList) {
+    if !list.is_null() { drop(Box::from_raw(list)) }
+}
+
+/// Returns the number of elements in `list`.
+///
+/// # Safety
+/// `list` must be a live handle from `anzipper_list_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_list_len(list: *const AnZipperList)
+                                          -> usize {
+    (*list).len()
+}
+
+/// Push `elem` onto the front of `list`.
+///
+/// # Safety
+/// `list` must be a live handle from `anzipper_list_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_list_push( list: *mut AnZipperList
+                                           , elem: Elem) {
+    (*list).push(elem);
+}
+
+/// Pop the element at the front of `list` into `out`.
+///
+/// Returns `false` if the list was empty, in which case `out` isn't written.
+///
+/// # Safety
+/// `list` must be a live handle from `anzipper_list_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_list_pop( list: *mut AnZipperList
+                                          , out: *mut Elem) -> bool {
+    write_out(out, (*list).pop())
+}
+
+/// Copy the element at the front of `list` into `out`, without removing it.
+///
+/// Returns `false` if the list was empty, in which case `out` isn't written.
+///
+/// # Safety
+/// `list` must be a live handle from `anzipper_list_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_list_peek( list: *const AnZipperList
+                                           , out: *mut Elem) -> bool {
+    write_out(out, (*list).peek().cloned())
+}
+
+//==- zippers -===============================================================
+
+/// Create a new empty zipper, returning a handle that must be passed to
+/// `anzipper_zip_free` when the host is done with it.
+#[no_mangle]
+pub extern "C" fn anzipper_zip_new() -> *mut AnZipperZip {
+    Box::into_raw(Box::new(ZipList::new()))
+}
+
+/// Free a zipper, and all of its nodes. Does nothing if `zip` is null.
+///
+/// # Safety
+/// `zip` must be null or a live handle from `anzipper_zip_new`, and is
+/// dangling afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_free(zip: *mut AnZipperZip) {
+    if !zip.is_null() { drop(Box::from_raw(zip)) }
+}
+
+/// Returns the number of elements in `zip`.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_len(zip: *const AnZipperZip) -> usize {
+    (*zip).len()
+}
+
+/// Returns the number of elements to the left of the cursor in `zip`.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_cursor(zip: *const AnZipperZip)
+                                            -> usize {
+    (*zip).left_iter().len()
+}
+
+/// Insert `elem` immediately to the left of the cursor in `zip`.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_push_left( zip: *mut AnZipperZip
+                                               , elem: Elem) {
+    (*zip).push_left(elem);
+}
+
+/// Insert `elem` immediately to the right of the cursor in `zip`.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_push_right( zip: *mut AnZipperZip
+                                                , elem: Elem) {
+    (*zip).push_right(elem);
+}
+
+/// Remove the element to the left of the cursor in `zip` into `out`.
+///
+/// Returns `false` if the cursor was at the start, in which case `out` isn't
+/// written.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_pop_left( zip: *mut AnZipperZip
+                                              , out: *mut Elem) -> bool {
+    write_out(out, (*zip).pop_left())
+}
+
+/// Remove the element to the right of the cursor in `zip` into `out`.
+///
+/// Returns `false` if the cursor was at the end, in which case `out` isn't
+/// written.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_pop_right( zip: *mut AnZipperZip
+                                               , out: *mut Elem) -> bool {
+    write_out(out, (*zip).pop_right())
+}
+
+/// Copy the element to the left of the cursor in `zip` into `out`.
+///
+/// Returns `false` if the cursor was at the start, in which case `out` isn't
+/// written.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_peek_left( zip: *const AnZipperZip
+                                               , out: *mut Elem) -> bool {
+    write_out(out, (*zip).peek_left().cloned())
+}
+
+/// Copy the element to the right of the cursor in `zip` into `out`.
+///
+/// Returns `false` if the cursor was at the end, in which case `out` isn't
+/// written.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`, and `out` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_peek_right( zip: *const AnZipperZip
+                                                , out: *mut Elem) -> bool {
+    write_out(out, (*zip).peek_right().cloned())
+}
+
+/// Move the cursor in `zip` one position to the left.
+///
+/// Returns `false` if it was already at the start.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_move_left(zip: *mut AnZipperZip)
+                                               -> bool {
+    (*zip).move_left()
+}
+
+/// Move the cursor in `zip` one position to the right.
+///
+/// Returns `false` if it was already at the end.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_move_right(zip: *mut AnZipperZip)
+                                                -> bool {
+    (*zip).move_right()
+}
+
+/// Move the cursor in `zip` up to `n` positions to the left, returning the
+/// number of positions it moved.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_seek_left( zip: *mut AnZipperZip
+                                               , n: usize) -> usize {
+    (*zip).seek_left(n)
+}
+
+/// Move the cursor in `zip` up to `n` positions to the right, returning the
+/// number of positions it moved.
+///
+/// # Safety
+/// `zip` must be a live handle from `anzipper_zip_new`.
+#[no_mangle]
+pub unsafe extern "C" fn anzipper_zip_seek_right( zip: *mut AnZipperZip
+                                                , n: usize) -> usize {
+    (*zip).seek_right(n)
+}
@@ -0,0 +1,50 @@
+use std::ptr;
+
+use super::*;
+
+fn elem(n: usize) -> Elem { n as Elem }
+
+#[test]
+fn list_round_trip() {
+    unsafe {
+        let list = anzipper_list_new();
+        let mut out = ptr::null_mut();
+        assert!(!anzipper_list_pop(list, &mut out));
+        anzipper_list_push(list, elem(1));
+        anzipper_list_push(list, ptr::null_mut());
+        assert_eq!(anzipper_list_len(list), 2);
+        assert!(anzipper_list_peek(list, &mut out));
+        assert!(out.is_null());
+        assert!(anzipper_list_pop(list, ptr::null_mut()));
+        assert!(anzipper_list_pop(list, &mut out));
+        assert_eq!(out, elem(1));
+        assert_eq!(anzipper_list_len(list), 0);
+        anzipper_list_free(list);
+        anzipper_list_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn zip_round_trip() {
+    unsafe {
+        let zip = anzipper_zip_new();
+        let mut out = ptr::null_mut();
+        for i in 1..5 { anzipper_zip_push_left(zip, elem(i)) }
+        assert_eq!(anzipper_zip_cursor(zip), 4);
+        assert_eq!(anzipper_zip_seek_left(zip, 10), 4);
+        assert!(!anzipper_zip_move_left(zip));
+        assert!(anzipper_zip_move_right(zip));
+        assert!(anzipper_zip_peek_left(zip, &mut out));
+        assert_eq!(out, elem(1));
+        assert!(anzipper_zip_pop_right(zip, &mut out));
+        assert_eq!(out, elem(2));
+        anzipper_zip_push_right(zip, elem(7));
+        assert!(anzipper_zip_peek_right(zip, &mut out));
+        assert_eq!(out, elem(7));
+        assert_eq!(anzipper_zip_seek_right(zip, 1), 1);
+        assert!(anzipper_zip_pop_left(zip, &mut out));
+        assert_eq!(out, elem(7));
+        assert_eq!(anzipper_zip_len(zip), 3);
+        anzipper_zip_free(zip);
+    }
+}
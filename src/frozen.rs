@@ -0,0 +1,70 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use super::ZipList;
+
+/// An immutable, shared snapshot of a `ZipList`.
+///
+/// This is created by [`ZipList::freeze`]. Cloning a frozen zipper only
+/// bumps a reference count, so it's cheap to publish a snapshot to other
+/// threads as often as needed. It derefs to the `ZipList`, so anything that
+/// only reads a zipper works on it as well.
+///
+/// ```
+/// # use an_zipper::ZipList;
+/// use std::thread;
+///
+/// let frozen = ZipList::from_iter_with_cursor(0..5, 2).freeze();
+/// let shared = frozen.clone();
+/// let sum = thread::spawn(move || shared.right_iter().sum::<i32>());
+/// assert_eq!(sum.join().unwrap(), 9);
+///
+/// let mut zipper = frozen.thaw();
+/// zipper.push_left(10);
+/// assert_eq!(zipper.len(), 6);
+/// ```
+///
+/// [`ZipList::freeze`]: struct.ZipList.html#method.freeze
+#[derive(Debug)]
+pub struct FrozenZipList<T>(Arc<ZipList<T>>);
+
+impl<T> ZipList<T> {
+    /// Consume the zipper, returning an immutable snapshot of it which can
+    /// be cloned in O(1).
+    ///
+    /// Nodes kept on the freelists for reuse are released, since a frozen
+    /// zipper will never push anything.
+    pub fn freeze(mut self) -> FrozenZipList<T> {
+        self.left.shrink_to_fit();
+        self.right.shrink_to_fit();
+        FrozenZipList(Arc::new(self))
+    }
+}
+
+impl<T> FrozenZipList<T> {
+    /// Returns true if `self` and `other` are clones of the same snapshot.
+    #[inline] pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> FrozenZipList<T>
+where T: Clone {
+    /// Turn the snapshot back into a zipper that can be edited.
+    ///
+    /// # Time complexity
+    /// O(1) if this is the only clone of the snapshot, which is then
+    /// unwrapped, or O(`self.len()`) to copy the zipper otherwise.
+    pub fn thaw(self) -> ZipList<T> {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<T> Clone for FrozenZipList<T> {
+    #[inline] fn clone(&self) -> Self { FrozenZipList(self.0.clone()) }
+}
+
+impl<T> Deref for FrozenZipList<T> {
+    type Target = ZipList<T>;
+    #[inline] fn deref(&self) -> &ZipList<T> { &self.0 }
+}
@@ -0,0 +1,172 @@
+//! Several cursors over the same list, which are checked at compile time.
+//!
+//! [`List::with_cursors`] lends out a [`Token`] which holds every element
+//! of the list, and hands out [`Cursor`]s: plain positions, which can be
+//! copied and moved around freely. Reading an element through a cursor
+//! borrows the token, and writing one borrows it mutably, so the token
+//! enforces the usual aliasing rules across all the cursors at once.
+//!
+//! Each call to `with_cursors` has its own *brand*, a lifetime that can't be
+//! named outside the closure, and cursors and tokens carry it. A cursor from
+//! one list can't be used with another list's token, so a cursor's position
+//! is always in bounds.
+//!
+//! ```
+//! use an_zipper::List;
+//!
+//! let mut list = (1..6).rev().collect::<List<_>>();
+//! // reverse the list in place with two cursors walking towards each other
+//! list.with_cursors(|mut token| {
+//!     let (mut front, mut back) = match (token.front(), token.back()) {
+//!         (Some(front), Some(back)) => (front, back),
+//!         _ => return,
+//!     };
+//!     while front < back {
+//!         token.swap(front, back);
+//!         front = token.next(front).unwrap();
+//!         back = token.prev(back).unwrap();
+//!     }
+//! });
+//! assert_eq!(list.iter().collect::<Vec<_>>(), vec![&5, &4, &3, &2, &1]);
+//! ```
+//!
+//! Cursors don't outlive the closure, and can't be mixed between lists:
+//!
+//! ```compile_fail
+//! use an_zipper::List;
+//!
+//! let mut a = (0..3).collect::<List<_>>();
+//! let mut b = (0..3).collect::<List<_>>();
+//! a.with_cursors(|token_a| {
+//!     let front = token_a.front().unwrap();
+//!     b.with_cursors(|token_b| { token_b.get(front); });
+//! });
+//! ```
+//!
+//! [`List::with_cursors`]: ../struct.List.html#method.with_cursors
+//! [`Token`]: struct.Token.html
+//! [`Cursor`]: struct.Cursor.html
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::mem;
+
+use super::List;
+
+/// An invariant lifetime, which ties cursors to the token they came from.
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+/// Access to the elements of a list, through any number of [`Cursor`]s with
+/// the same brand.
+///
+/// This is created by [`List::with_cursors`].
+///
+/// [`Cursor`]: struct.Cursor.html
+/// [`List::with_cursors`]: ../struct.List.html#method.with_cursors
+pub struct Token<'brand, 'a, T: 'a> { elems: Vec<&'a mut T>
+                                    , brand: Brand<'brand>
+                                    }
+
+/// A position in a list, which can only be used with the [`Token`] it came
+/// from.
+///
+/// Cursors are ordered by their position in the list.
+///
+/// [`Token`]: struct.Token.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cursor<'brand> { index: usize
+                          , brand: Brand<'brand>
+                          }
+
+impl<T> List<T> {
+    /// Call `f` with a [`Token`] for this list's elements, from which any
+    /// number of cursors can be made.
+    ///
+    /// The list's structure can't change while the token exists, but its
+    /// elements can be read and written through cursors in any order.
+    ///
+    /// # Time complexity
+    /// O(n) to set up the token, and O(1) for each cursor operation.
+    ///
+    /// [`Token`]: ghost/struct.Token.html
+    pub fn with_cursors<F, R>(&mut self, f: F) -> R
+    where F: for<'brand> FnOnce(Token<'brand, '_, T>) -> R {
+        f(Token { elems: self.iter_mut().collect(), brand: PhantomData })
+    }
+}
+
+impl<'brand, 'a, T> Token<'brand, 'a, T> {
+    /// Returns the number of elements in the list.
+    #[inline] pub fn len(&self) -> usize { self.elems.len() }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.elems.is_empty() }
+
+    /// Returns a cursor at the front of the list, if it isn't empty.
+    pub fn front(&self) -> Option<Cursor<'brand>> { self.cursor(0) }
+
+    /// Returns a cursor at the back of the list, if it isn't empty.
+    pub fn back(&self) -> Option<Cursor<'brand>> {
+        self.len().checked_sub(1).and_then(|i| self.cursor(i))
+    }
+
+    /// Returns a cursor at `index`, if it's in bounds.
+    pub fn cursor(&self, index: usize) -> Option<Cursor<'brand>> {
+        if index < self.len() {
+            Some(Cursor { index, brand: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a cursor at the element after `cursor`, if there is one.
+    pub fn next(&self, cursor: Cursor<'brand>) -> Option<Cursor<'brand>> {
+        self.cursor(cursor.index + 1)
+    }
+
+    /// Returns a cursor at the element before `cursor`, if there is one.
+    pub fn prev(&self, cursor: Cursor<'brand>) -> Option<Cursor<'brand>> {
+        cursor.index.checked_sub(1).and_then(|i| self.cursor(i))
+    }
+
+    /// Borrow the element at `cursor`.
+    #[inline] pub fn get(&self, cursor: Cursor<'brand>) -> &T {
+        self.elems[cursor.index]
+    }
+
+    /// Mutably borrow the element at `cursor`.
+    #[inline] pub fn get_mut(&mut self, cursor: Cursor<'brand>) -> &mut T {
+        self.elems[cursor.index]
+    }
+
+    /// Mutably borrow the elements at two different cursors at once.
+    ///
+    /// # Returns
+    /// - `Some((&mut T, &mut T))` with the elements at `a` and `b`
+    /// - `None` if `a` and `b` are at the same position
+    pub fn get_pair_mut(&mut self, a: Cursor<'brand>, b: Cursor<'brand>)
+                        -> Option<(&mut T, &mut T)> {
+        let (i, j) = (a.index, b.index);
+        match i.cmp(&j) {
+            Ordering::Equal => None
+          , Ordering::Less => {
+                let (front, back) = self.elems.split_at_mut(j);
+                Some((&mut *front[i], &mut *back[0]))
+            }
+          , Ordering::Greater => {
+                let (front, back) = self.elems.split_at_mut(i);
+                Some((&mut *back[0], &mut *front[j]))
+            }
+        }
+    }
+
+    /// Swap the elements at `a` and `b`.
+    pub fn swap(&mut self, a: Cursor<'brand>, b: Cursor<'brand>) {
+        if let Some((a, b)) = self.get_pair_mut(a, b) {
+            mem::swap(a, b);
+        }
+    }
+}
+
+impl<'brand> Cursor<'brand> {
+    /// Returns the index of the cursor's position in the list.
+    #[inline] pub fn index(self) -> usize { self.index }
+}
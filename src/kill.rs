@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+
+use super::{List, ZipList};
+
+impl<T> ZipList<T> {
+    /// Remove up to `n` elements immediately to the left of the zipper,
+    /// returning them as a list, in order.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn cut_left(&mut self, n: usize) -> List<T> {
+        let mut cut = self.left.split_front(n);
+        cut.reverse();
+        cut
+    }
+
+    /// Remove up to `n` elements immediately to the right of the zipper,
+    /// returning them as a list, in order.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn cut_right(&mut self, n: usize) -> List<T> {
+        self.right.split_front(n)
+    }
+
+    /// Insert the elements of `list` at the cursor, in order, leaving the
+    /// cursor after them.
+    ///
+    /// The nodes are relinked rather than reallocated, so pasting a list
+    /// returned by [`cut_left`](#method.cut_left) or
+    /// [`cut_right`](#method.cut_right) doesn't allocate.
+    ///
+    /// # Time complexity
+    /// O(`list.len()`)
+    pub fn paste(&mut self, mut list: List<T>) {
+        list.reverse();
+        self.left.prepend(list);
+    }
+}
+
+/// A bounded stack of recently cut lists, for pasting back with "yank" and
+/// cycling through with "yank-pop", as in Emacs.
+///
+/// ```
+/// # use an_zipper::{KillRing, ZipList};
+/// let mut zipper = ZipList::from_iter_with_cursor("one two".chars(), 3);
+/// let mut ring = KillRing::new(8);
+/// ring.kill(zipper.cut_left(3));
+/// zipper.move_right();
+/// ring.kill(zipper.cut_right(3));
+/// assert!(ring.yank(&mut zipper));
+/// assert!(ring.yank_pop(&mut zipper));
+/// let text = zipper.cut_left(zipper.len()).into_iter().collect::<String>();
+/// assert_eq!(text, " one");
+/// ```
+#[derive(Clone, Debug)]
+pub struct KillRing<T> { kills: VecDeque<List<T>>
+                       , capacity: usize
+                       , /// The index of the last kill yanked, and its
+                         /// length, if the last operation was a yank.
+                         yanked: Option<(usize, usize)>
+                       }
+
+impl<T> KillRing<T> {
+    /// Create a new empty `KillRing` which keeps up to `capacity` kills.
+    pub fn new(capacity: usize) -> Self {
+        KillRing { kills: VecDeque::with_capacity(capacity)
+                 , capacity
+                 , yanked: None
+                 }
+    }
+
+    /// Returns the number of kills in the ring.
+    #[inline] pub fn len(&self) -> usize { self.kills.len() }
+
+    /// Returns true if there are no kills in the ring.
+    #[inline] pub fn is_empty(&self) -> bool { self.kills.is_empty() }
+
+    /// Returns the most recent kill.
+    pub fn latest(&self) -> Option<&List<T>> { self.kills.front() }
+
+    /// Add `cut` to the ring as the most recent kill, dropping the oldest
+    /// kill if the ring is full.
+    ///
+    /// Empty lists aren't added.
+    pub fn kill(&mut self, cut: List<T>) {
+        self.yanked = None;
+        if cut.is_empty() || self.capacity == 0 { return }
+        if self.kills.len() == self.capacity { self.kills.pop_back(); }
+        self.kills.push_front(cut);
+    }
+}
+
+impl<T> KillRing<T>
+where T: Clone {
+    /// Paste a copy of the most recent kill into `zipper`, at the cursor.
+    ///
+    /// # Returns
+    /// - `true` if a kill was pasted
+    /// - `false` if the ring is empty
+    pub fn yank(&mut self, zipper: &mut ZipList<T>) -> bool {
+        self.yank_at(zipper, 0)
+    }
+
+    /// Replace the elements pasted by the last `yank` or `yank_pop` with a
+    /// copy of the kill before the one they came from, cycling back to the
+    /// most recent kill after the oldest.
+    ///
+    /// The pasted elements are assumed to still be immediately to the left
+    /// of the cursor.
+    ///
+    /// # Returns
+    /// - `true` if the pasted elements were replaced
+    /// - `false` if there's been a kill since the last yank, or no yank
+    pub fn yank_pop(&mut self, zipper: &mut ZipList<T>) -> bool {
+        match self.yanked {
+            Some((index, len)) => {
+                zipper.cut_left(len);
+                let next = (index + 1) % self.kills.len();
+                self.yank_at(zipper, next)
+            }
+          , None => false
+        }
+    }
+
+    fn yank_at(&mut self, zipper: &mut ZipList<T>, index: usize) -> bool {
+        match self.kills.get(index) {
+            Some(kill) => {
+                zipper.paste(kill.clone());
+                self.yanked = Some((index, kill.len()));
+                true
+            }
+          , None => false
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Iterators which lend out items borrowed from themselves.
+//!
+//! A standard `Iterator` can't yield mutable references that overlap,
+//! because every item it yields may be kept around for as long as the
+//! iterator's own borrow lasts. A [`LendingIterator`] ties each item to the
+//! call to `next` that produced it instead, so the previous item has to be
+//! dropped before the next is borrowed. That's what makes it possible to
+//! slide a window of mutable references along a zipper with
+//! [`ZipList::windows_mut`].
+//!
+//! [`LendingIterator`]: trait.LendingIterator.html
+//! [`ZipList::windows_mut`]: ../struct.ZipList.html#method.windows_mut
+use super::ZipList;
+
+/// An iterator whose items borrow from the iterator itself.
+pub trait LendingIterator {
+    /// The type of item lent out by each call to `next`.
+    type Item<'a> where Self: 'a;
+
+    /// Advance the iterator and lend out the next item.
+    ///
+    /// Items can't be passed to a closure generic over their lifetime
+    /// without requiring `Self: 'static`, so lending iterators are consumed
+    /// with a `while let` loop rather than adapters like `for_each`.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+/// Overlapping windows of mutable references to a zipper's elements.
+///
+/// This is created by [`ZipList::windows_mut`].
+///
+/// [`ZipList::windows_mut`]: ../struct.ZipList.html#method.windows_mut
+pub struct WindowsMut<'a, T: 'a> { elems: Vec<&'a mut T>
+                                 , len: usize
+                                 , next: usize
+                                 }
+
+impl<T> ZipList<T> {
+    /// Returns a lending iterator over every run of `n` consecutive elements,
+    /// from left to right, as slices of mutable references.
+    ///
+    /// Each window overlaps the last by `n - 1` elements, as with
+    /// `slice::windows`. If there are fewer than `n` elements, there are no
+    /// windows.
+    ///
+    /// ```
+    /// # use an_zipper::ZipList;
+    /// use an_zipper::lending::LendingIterator;
+    ///
+    /// let mut zipper = ZipList::from_iter_with_cursor(vec![1, 2, 3, 4], 2);
+    /// let mut windows = zipper.windows_mut(2);
+    /// while let Some(window) = windows.next() {
+    ///     *window[1] += *window[0];
+    /// }
+    /// assert_eq!(zipper.right_iter().collect::<Vec<_>>(), vec![&6, &10]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `n` is zero.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`) to start, and O(1) per window. This also takes
+    /// O(`self.len()`) extra memory for the references.
+    pub fn windows_mut(&mut self, n: usize) -> WindowsMut<'_, T> {
+        assert!(n > 0, "window length must be non-zero");
+        let mut elems = self.left.iter_mut().collect::<Vec<_>>();
+        elems.reverse();
+        elems.extend(self.right.iter_mut());
+        WindowsMut { elems, len: n, next: 0 }
+    }
+}
+
+impl<'a, T> LendingIterator for WindowsMut<'a, T> {
+    type Item<'b> = &'b mut [&'a mut T] where Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let start = self.next;
+        let end = start + self.len;
+        if end > self.elems.len() { return None }
+        self.next += 1;
+        Some(&mut self.elems[start..end])
+    }
+}
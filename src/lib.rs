@@ -13,11 +13,30 @@
 #![cfg_attr( feature = "clippy", feature(plugin) )]
 #![cfg_attr( feature = "clippy", plugin(clippy) )]
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::iter;
+use std::ops;
+use std::str;
+use std::alloc::{self, Layout};
+use std::mem::{self, MaybeUninit};
+
+use error::{AllocError, ParseListError, RangeError, SeekError,
+            StaleMarkError};
 
-#[macro_use] extern crate unstable_macros;
 #[cfg(test)] #[macro_use] extern crate quickcheck;
+#[cfg(feature = "rayon")] extern crate rayon;
+#[cfg(feature = "crossbeam-epoch")] extern crate crossbeam_epoch;
+#[cfg(feature = "oplog")] extern crate serde;
+#[cfg(feature = "regex")] extern crate regex;
+#[cfg(feature = "futures")] extern crate futures;
+#[cfg(feature = "rand")] extern crate rand;
+#[cfg(feature = "zeroize")] extern crate zeroize;
+#[cfg(feature = "tracing")] extern crate tracing;
+
+#[macro_use] mod macros;
+#[cfg(test)] mod miri;
+#[cfg(test)] mod test;
 
 /// Trait describing stack behaviour
 pub trait Stack<T> {
@@ -53,17 +72,60 @@ pub trait Stack<T> {
 }
 
 //==- singly-linked list -===================================================
+pub mod annotate;
+pub mod array;
+pub mod cow;
+pub mod crdt;
+pub mod diff;
+pub mod dot;
+pub mod error;
+#[cfg(feature = "ffi")] pub mod ffi;
+pub mod ghost;
+pub mod lending;
 pub mod list;
+#[cfg(feature = "test-support")] pub mod model;
+pub mod observe;
+#[cfg(feature = "oplog")] pub mod oplog;
+pub mod size;
+pub mod skip;
+pub mod snapshot;
+pub mod spill;
+#[cfg(feature = "stats")] pub mod stats;
+#[cfg(feature = "futures")] pub mod stream;
+#[cfg(feature = "rayon")] pub mod par;
+pub mod sync;
+pub mod thin;
+
+pub use array::{ArrayStack, ArrayZipList};
+pub use cow::{CowList, CowZipList};
+pub use skip::SkipList;
+pub use thin::ThinList;
+
 /// A simple singly-linked list
-#[derive(Clone)]
+///
+/// Nodes removed by `pop` are kept on an internal freelist and reused by
+/// later `push`es, so oscillating workloads don't round-trip through the
+/// allocator. Use [`shrink_to_fit`](#method.shrink_to_fit) to release them.
 pub struct List<T> { head: Link<T>
                    , len: usize
+                   , free: FreeLink<T>
+                   // bumped whenever elements are added, removed or
+                   // reordered, so that zipper marks can tell they're stale
+                   , generation: u64
+                   , #[cfg(feature = "stats")]
+                     stats: stats::Stats
                    }
 
 type Link<T> = Option<Box<Node<T>>>;
 
+/// A link in a list's freelist. The `elem` of a free node has already been
+/// moved out, so it is only ever treated as uninitialized.
+type FreeLink<T> = Option<Box<Node<MaybeUninit<T>>>>;
 
+// `repr(C)` guarantees that `Node<T>` and `Node<MaybeUninit<T>>` have the
+// same layout, which the freelist relies on when converting between them.
 #[derive(Clone)]
+#[repr(C)]
 struct Node<T> { elem: T
                , next: Link<T>
                }
@@ -94,19 +156,18 @@ where T: fmt::Display {
 
 impl<T> Node<T> {
 
-    unstable_const_fn!{
-        pub const fn new(elem: T) -> Self { Node { elem: elem, next: None } }
-    }
+    pub const fn new(elem: T) -> Self { Node { elem, next: None } }
 
 }
 
 impl<T> Stack<T> for List<T> {
     fn push(&mut self, elem: T) -> &mut Self {
-        self.cons(Box::new(Node::new(elem)))
+        let node = self.alloc(elem);
+        self.cons(node)
     }
 
     fn pop(&mut self) -> Option<T> {
-        self.uncons().map(|node| node.elem)
+        self.uncons().map(|node| self.recycle(node))
     }
 
     #[inline]
@@ -130,13 +191,217 @@ impl<T> List<T> {
 
     #[inline] pub fn is_empty(&self) -> bool { self.head.is_none() }
 
-    unstable_const_fn! {
-        pub const fn new() -> Self {
-            List { head: None
-                 , len: 0 }
+    /// Create a new empty `List`.
+    ///
+    /// This is a `const fn`, so it can initialize a `static`.
+    pub const fn new() -> Self {
+        List { head: None
+             , len: 0
+             , free: None
+             , generation: 0
+             , #[cfg(feature = "stats")]
+               stats: stats::Stats::new() }
+    }
+
+    /// Create a `List` of `n` clones of `value`, like `vec![value; n]`.
+    ///
+    /// `value` itself is moved into the last node, so it's cloned `n - 1`
+    /// times, and dropped if `n` is 0.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn from_elem(n: usize, value: T) -> Self
+    where T: Clone {
+        let mut value = Some(value);
+        List::from_fn(n, |i| if i + 1 == n { value.take() }
+                             else { value.clone() }.unwrap())
+    }
+
+    /// Create a `List` of `n` elements, where the element at index `i` is
+    /// `f(i)`.
+    ///
+    /// `f` is called with each index in ascending order, and the nodes are
+    /// linked in that order as they're made, so the list doesn't need to be
+    /// reversed afterwards as a collected one would.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn from_fn<F>(n: usize, mut f: F) -> Self
+    where F: FnMut(usize) -> T {
+        let mut list = List::new();
+        let mut link = &mut list.head;
+        for i in 0..n {
+            // if `f` panics, the nodes made so far are still owned by the
+            // list, which frees them without looking at its length.
+            link = &mut link.insert(Box::new(Node::new(f(i)))).next;
+        }
+        list.len = n;
+        count!(list, pushes += n as u64, allocations += n as u64);
+        list
+    }
+
+    /// Release all node allocations held on the freelist for reuse.
+    pub fn shrink_to_fit(&mut self) {
+        let mut free = self.free.take();
+        while let Some(mut node) = free {
+            free = node.next.take();
+        }
+    }
+
+    /// Consume the list and leak it, returning a mutable reference to it
+    /// which lives for the rest of the program.
+    ///
+    /// Like `Vec::leak`, this is useful for lists built at startup which are
+    /// needed until the program exits. Nodes on the freelist are released
+    /// first, since they can't be reclaimed afterwards.
+    pub fn leak<'a>(mut self) -> &'a mut List<T>
+    where T: 'a {
+        self.shrink_to_fit();
+        Box::leak(Box::new(self))
+    }
+
+    /// Returns the number of bytes of heap memory held by the list's nodes,
+    /// including nodes on the freelist.
+    ///
+    /// This doesn't include any heap memory owned by the elements
+    /// themselves; use [`MemSize::heap_size`] for that.
+    ///
+    /// # Time complexity
+    /// O(number of nodes on the freelist)
+    ///
+    /// [`MemSize::heap_size`]: size/trait.MemSize.html#tymethod.heap_size
+    pub fn heap_size_of_children(&self) -> usize {
+        let mut nodes = self.len;
+        let mut free = self.free.as_ref();
+        while let Some(node) = free {
+            nodes += 1;
+            free = node.next.as_ref();
+        }
+        nodes * mem::size_of::<Node<T>>()
+    }
+
+    /// Make sure that the next `n` pushes won't need to allocate, by
+    /// allocating nodes onto the freelist.
+    ///
+    /// Unlike pushing, this reports allocation failure rather than aborting.
+    ///
+    /// # Returns
+    /// - `Ok(())` if there are at least `n` nodes on the freelist
+    /// - `Err(AllocError)` if a node couldn't be allocated. Any nodes that
+    ///   were allocated are kept on the freelist.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn try_reserve_nodes(&mut self, n: usize) -> Result<(), AllocError> {
+        let mut reserved = 0;
+        let mut free = self.free.as_ref();
+        while let Some(node) = free {
+            if reserved == n { return Ok(()) }
+            reserved += 1;
+            free = node.next.as_ref();
+        }
+        while reserved < n {
+            let layout = Layout::new::<Node<MaybeUninit<T>>>();
+            // this is safe because nodes always contain a pointer, so the
+            // layout has a non-zero size.
+            let node = unsafe { alloc::alloc(layout) }
+                       as *mut Node<MaybeUninit<T>>;
+            if node.is_null() {
+                return Err(AllocError { requested: n, reserved })
+            }
+            // this is safe because `node` was allocated by the global
+            // allocator with the layout of the type it's written as.
+            unsafe {
+                node.write(Node { elem: MaybeUninit::uninit()
+                                , next: self.free.take() });
+                self.free = Some(Box::from_raw(node));
+            }
+            count!(self, allocations += 1);
+            reserved += 1;
+        }
+        Ok(())
+    }
+
+    /// Make sure that the next `n` pushes won't need to allocate, by
+    /// allocating nodes onto the freelist.
+    ///
+    /// This is for code which can't call the allocator while pushing, such
+    /// as a real-time thread: reserve ahead of time, and the pushes only
+    /// relink nodes. Nodes freed by popping are reused the same way.
+    ///
+    /// # Panics
+    /// Aborts if a node can't be allocated, like pushing does. Use
+    /// [`try_reserve_nodes`](#method.try_reserve_nodes) to handle that.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn reserve(&mut self, n: usize) {
+        if self.try_reserve_nodes(n).is_err() {
+            alloc::handle_alloc_error(Layout::new::<Node<MaybeUninit<T>>>())
         }
     }
 
+    /// Push `elem` to the list, reporting allocation failure rather than
+    /// aborting.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if a node couldn't be allocated for it
+    pub fn try_push(&mut self, elem: T) -> Result<(), T> {
+        match self.try_reserve_nodes(1) {
+            Ok(()) => { self.push(elem); Ok(()) }
+          , Err(_) => Err(elem)
+        }
+    }
+
+    /// Allocate a node holding `elem`, reusing one from the freelist if
+    /// possible.
+    fn alloc(&mut self, elem: T) -> Box<Node<T>> {
+        self.changed();
+        count!(self, pushes += 1);
+        match self.free.take() {
+            Some(mut node) => {
+                self.free = node.next.take();
+                node.elem = MaybeUninit::new(elem);
+                // this is safe because `elem` was just initialized and the
+                // two node types have the same layout.
+                unsafe { Box::from_raw(Box::into_raw(node) as *mut Node<T>) }
+            }
+          , None => {
+                count!(self, allocations += 1);
+                Box::new(Node::new(elem))
+            }
+        }
+    }
+
+    /// Move the element out of an unlinked `node`, putting the node's
+    /// allocation on the freelist.
+    fn recycle(&mut self, node: Box<Node<T>>) -> T {
+        debug_assert!(node.next.is_none(), "recycled a linked node");
+        self.changed();
+        count!(self, pops += 1);
+        // this is safe because the two node types have the same layout, and
+        // after `elem` is read out the node is only treated as uninitialized.
+        let mut node = unsafe {
+            Box::from_raw(Box::into_raw(node) as *mut Node<MaybeUninit<T>>)
+        };
+        let elem = unsafe { node.elem.as_ptr().read() };
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut node.elem);
+        node.next = self.free.take();
+        self.free = Some(node);
+        elem
+    }
+
+    /// Record that elements have been added, removed or reordered.
+    ///
+    /// `cons`, `uncons` and `transfer` don't count, since zippers use them
+    /// to move their cursors, and moving the cursor doesn't invalidate a
+    /// mark. Anything else that relinks nodes should call this.
+    #[inline] fn changed(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
     fn cons(&mut self, mut node: Box<Node<T>>) -> &mut Self {
         node.next = self.head.take();
         self.head = Some(node);
@@ -151,6 +416,265 @@ impl<T> List<T> {
             node
         })
     }
+
+    /// Split the list in two at index `at`, returning a list of the elements
+    /// from `at` onwards. If `at` is past the end of the list, the returned
+    /// list is empty.
+    ///
+    /// # Time complexity
+    /// O(`at`)
+    fn split_off(&mut self, at: usize) -> List<T> {
+        let mut rest = List::new();
+        if at >= self.len { return rest; }
+        self.changed();
+        {
+            let mut link = &mut self.head;
+            for _ in 0..at {
+                link = &mut link.as_mut().unwrap().next;
+            }
+            rest.head = link.take();
+        }
+        rest.len = self.len - at;
+        self.len = at;
+        rest
+    }
+
+    /// Move up to `n` nodes from the front of this list to the front of
+    /// `other`, one after another, so that they end up in reverse order.
+    ///
+    /// This is the same as `n` `uncons`/`cons` pairs, but relinks the whole
+    /// run in one walk and adjusts the lengths once.
+    ///
+    /// # Returns
+    /// - the number of nodes moved
+    fn transfer(&mut self, other: &mut List<T>, n: usize) -> usize {
+        let n = n.min(self.len);
+        let mut moved = other.head.take();
+        let mut head = self.head.take();
+        for _ in 0..n {
+            if let Some(mut node) = head {
+                head = mem::replace(&mut node.next, moved);
+                moved = Some(node);
+            } else { unreachable!("list shorter than its length") }
+        }
+        self.head = head;
+        other.head = moved;
+        self.len -= n;
+        other.len += n;
+        n
+    }
+
+    /// Move up to `n` nodes to the front of `other`, like
+    /// [`transfer`](#method.transfer), calling `f` on each element just
+    /// before it's moved.
+    ///
+    /// The nodes are moved one at a time, so that if `f` panics, both lists
+    /// are left whole, with the element `f` panicked on not yet moved.
+    ///
+    /// # Returns
+    /// - the number of nodes moved
+    fn transfer_with<F>(&mut self, other: &mut List<T>, n: usize, mut f: F)
+                        -> usize
+    where F: FnMut(&mut T) {
+        for moved in 0..n {
+            match self.head {
+                Some(ref mut node) => f(&mut node.elem)
+              , None => return moved
+            }
+            let node = self.uncons().unwrap();
+            other.cons(node);
+        }
+        n
+    }
+
+    /// Push every element of `iter` in turn, so that the last one ends up at
+    /// the top of the list.
+    ///
+    /// Nodes for the lower bound of the iterator's `size_hint` are reserved
+    /// on the freelist up front, so an `ExactSizeIterator` is pushed without
+    /// calling the allocator in between elements. The new nodes are linked
+    /// together off to the side, and the run is attached to the list with a
+    /// single length update once the iterator is exhausted (or if it
+    /// panics).
+    fn push_all<I>(&mut self, iter: I)
+    where I: IntoIterator<Item=T> {
+        let iter = iter.into_iter();
+        // if the nodes can't all be reserved, the pushes allocate the rest
+        // one at a time, and abort if that fails too.
+        let _ = self.try_reserve_nodes(iter.size_hint().0);
+        let head = self.head.take();
+        let mut chain = Chain { list: self, head, pushed: 0 };
+        for elem in iter {
+            let mut node = chain.list.alloc(elem);
+            node.next = chain.head.take();
+            chain.head = Some(node);
+            chain.pushed += 1;
+        }
+    }
+
+    /// Returns the empty link at the end of the list.
+    ///
+    /// # Time complexity
+    /// O(n)
+    fn tail_link(&mut self) -> &mut Link<T> {
+        let mut link = &mut self.head;
+        while link.is_some() {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        link
+    }
+
+    /// Returns the link to the node at index `i`, which must be at most the
+    /// length of the list.
+    ///
+    /// # Time complexity
+    /// O(`i`)
+    fn link_at(&mut self, i: usize) -> &mut Link<T> {
+        let mut link = &mut self.head;
+        for _ in 0..i {
+            link = &mut link.as_mut().expect("index out of bounds").next;
+        }
+        link
+    }
+
+    /// Insert `elem` at index `i`, which must be at most the length of the
+    /// list.
+    ///
+    /// # Time complexity
+    /// O(`i`)
+    fn insert_nth(&mut self, i: usize, elem: T) {
+        let mut node = self.alloc(elem);
+        let link = self.link_at(i);
+        node.next = link.take();
+        *link = Some(node);
+        self.len += 1;
+    }
+
+    /// Remove the element at index `i`, if there is one.
+    ///
+    /// # Time complexity
+    /// O(`i`)
+    fn remove_nth(&mut self, i: usize) -> Option<T> {
+        if i >= self.len { return None }
+        let link = self.link_at(i);
+        let mut node = link.take().expect("index out of bounds");
+        *link = node.next.take();
+        self.len -= 1;
+        Some(self.recycle(node))
+    }
+
+    /// Move all the nodes on this list's freelist onto `other`'s.
+    ///
+    /// # Time complexity
+    /// O(number of nodes on the freelist)
+    fn give_free(&mut self, other: &mut List<T>) {
+        let mut free = self.free.take();
+        while let Some(mut node) = free {
+            free = node.next.take();
+            node.next = other.free.take();
+            other.free = Some(node);
+        }
+    }
+
+    /// Link all of `other`'s nodes onto the front of this list, in order.
+    ///
+    /// # Time complexity
+    /// O(`other.len()`)
+    fn prepend(&mut self, mut other: List<T>) {
+        if other.is_empty() { return; }
+        self.changed();
+        *other.tail_link() = self.head.take();
+        self.head = other.head.take();
+        self.len += mem::replace(&mut other.len, 0);
+    }
+
+    /// Link all of `other`'s nodes onto the end of this list, in order.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`)
+    fn append(&mut self, mut other: List<T>) {
+        if other.is_empty() { return; }
+        self.changed();
+        *self.tail_link() = other.head.take();
+        self.len += mem::replace(&mut other.len, 0);
+    }
+
+    /// Detach the first `n` elements of the list (or all of them, if there
+    /// are fewer than `n`), returning them as a new list.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    fn split_front(&mut self, n: usize) -> List<T> {
+        // split off the back, and then swap the nodes around, so that this
+        // list keeps its freelist.
+        let mut front = self.split_off(n);
+        mem::swap(&mut self.head, &mut front.head);
+        mem::swap(&mut self.len, &mut front.len);
+        front
+    }
+
+    /// Merge the nodes of `other` into this list, taking the front node of
+    /// `other` whenever `first(theirs, ours)` is `true` for the two front
+    /// elements, and the front node of this list otherwise.
+    ///
+    /// If both lists are in the order `first` describes, the result is too.
+    /// If `first` panics, the nodes which hadn't been merged yet are
+    /// dropped.
+    ///
+    /// # Time complexity
+    /// O(number of nodes taken before either list runs out)
+    fn merge_by<F>(&mut self, mut other: List<T>, mut first: F)
+    where F: FnMut(&T, &T) -> bool {
+        if other.is_empty() { return; }
+        self.changed();
+        // the length only counts merged nodes until one list runs out, so
+        // that it's still right if `first` panics.
+        let mut rest = (mem::replace(&mut self.len, 0)
+                       , mem::replace(&mut other.len, 0));
+        let mut ours = self.head.take();
+        let mut theirs = other.head.take();
+        let mut link = &mut self.head;
+        loop {
+            let next = match (ours.as_ref(), theirs.as_ref()) {
+                (Some(a), Some(b)) =>
+                    if first(&b.elem, &a.elem) { rest.1 -= 1; &mut theirs }
+                    else { rest.0 -= 1; &mut ours }
+              , (Some(_), None) => { *link = ours; self.len += rest.0; break }
+              , (None, _) => { *link = theirs; self.len += rest.1; break }
+            };
+            let mut node = next.take().unwrap();
+            *next = node.next.take();
+            link = &mut link.insert(node).next;
+            self.len += 1;
+        }
+    }
+
+    /// Sort the list with a stable merge sort, by relinking its nodes.
+    ///
+    /// # Time complexity
+    /// O(n log n)
+    fn merge_sort_by<F>(&mut self, compare: &mut F)
+    where F: FnMut(&T, &T) -> Ordering {
+        if self.len < 2 { return; }
+        let mut back = self.split_off(self.len / 2);
+        self.merge_sort_by(compare);
+        back.merge_sort_by(compare);
+        self.merge_by(back, |theirs, ours| {
+            compare(theirs, ours) == Ordering::Less
+        });
+    }
+
+    /// Reverse the order of the list in place by relinking its nodes.
+    fn reverse(&mut self) {
+        self.changed();
+        let mut reversed = None;
+        let mut head = self.head.take();
+        while let Some(mut node) = head {
+            head = mem::replace(&mut node.next, reversed);
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
 }
 
 impl<'a, T> IntoIterator for &'a List<T> {
@@ -188,7 +712,7 @@ impl<T> iter::FromIterator<T> for List<T> {
     fn from_iter<I>(iter: I) -> Self
     where I: IntoIterator<Item=T> {
         let mut list = List::new();
-        for i in iter { list.push(i); }
+        list.push_all(iter);
         list
     }
 }
@@ -196,7 +720,7 @@ impl<T> iter::FromIterator<T> for List<T> {
 impl<T> iter::Extend<T> for List<T>  {
     fn extend<I>(&mut self, iter: I)
     where I: IntoIterator<Item=T> {
-        for i in iter { self.push(i); }
+        self.push_all(iter);
     }
 }
 
@@ -205,13 +729,52 @@ where T: Copy + 'a {
 
     fn extend<I>(&mut self, iter: I)
     where I: IntoIterator<Item=&'a T> {
-        for i in iter { self.push(*i); }
+        self.push_all(iter.into_iter().copied());
+    }
+}
+
+impl<T> ops::Add for List<T> {
+    type Output = List<T>;
+
+    /// Concatenate two lists by linking `other` onto the end of `self`.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`)
+    fn add(mut self, other: List<T>) -> List<T> {
+        self.append(other);
+        self
+    }
+}
+
+impl<T> ops::AddAssign for List<T> {
+    /// Link `other` onto the end of `self`.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`)
+    fn add_assign(&mut self, other: List<T>) { self.append(other); }
+}
+
+/// A run of nodes being pushed onto a list, which is attached to the list
+/// when it's dropped.
+struct Chain<'a, T: 'a> { list: &'a mut List<T>
+                        , head: Link<T>
+                        , pushed: usize
+                        }
+
+impl<'a, T> Drop for Chain<'a, T> {
+    fn drop(&mut self) {
+        self.list.head = self.head.take();
+        self.list.len += self.pushed;
     }
 }
 
 impl<T> fmt::Debug for List<T>
 where T: fmt::Debug {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            // `{:#?}` prints one element per line
+            return f.debug_list().entries(self.iter()).finish()
+        }
         write!( f, "[{:?}]"
               , self.head.as_ref()
                     .map(|head| format!("{:?}", head))
@@ -231,16 +794,136 @@ where T: fmt::Display {
     }
 }
 
+impl<T> str::FromStr for List<T>
+where T: str::FromStr {
+    type Err = ParseListError<T::Err>;
+
+    /// Parse a list in the format it's displayed in, like `[1, 2, 3]`.
+    ///
+    /// Elements are separated by commas, and whitespace around each one is
+    /// ignored, so elements which contain commas or begin or end with
+    /// whitespace can't be parsed back from their displayed form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !s.starts_with('[') || !s.ends_with(']') || s.len() < 2 {
+            return Err(ParseListError::Brackets)
+        }
+        let inner = &s[1..s.len() - 1];
+        let mut list = List::new();
+        if inner.trim().is_empty() { return Ok(list) }
+        for (index, elem) in inner.split(',').enumerate() {
+            let elem = elem.trim().parse()
+                           .map_err(|err| ParseListError::Elem { index, err })?;
+            list.push(elem);
+        }
+        // pushing stacks the elements up last-first
+        list.reverse();
+        Ok(list)
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self { List::new() }
+}
+
+impl<T> Clone for List<T>
+where T: Clone {
+    fn clone(&self) -> Self {
+        let mut list = List::new();
+        {
+            let mut tail = &mut list.head;
+            for elem in self {
+                *tail = Some(Box::new(Node::new(elem.clone())));
+                tail = &mut tail.as_mut().unwrap().next;
+            }
+        }
+        list.len = self.len;
+        count!(list, pushes += list.len as u64, allocations += list.len as u64);
+        list
+    }
+
+    /// Make this list a copy of `source`, reusing its nodes.
+    ///
+    /// Elements in both lists are overwritten with `T::clone_from`, so they
+    /// can reuse their own allocations too. Nodes left over go onto the
+    /// freelist, and any extra nodes needed are taken from it first.
+    ///
+    /// # Time complexity
+    /// O(`self.len()` + `source.len()`)
+    fn clone_from(&mut self, source: &Self) {
+        let common = self.len.min(source.len);
+        let mut spare = self.split_off(common);
+        for (elem, src) in self.iter_mut().zip(source.iter()) {
+            elem.clone_from(src);
+        }
+        while let Some(node) = spare.uncons() {
+            drop(self.recycle(node));
+        }
+        let mut extra = List::new();
+        extra.free = self.free.take();
+        extra.extend(source.iter().skip(common).cloned());
+        self.free = extra.free.take();
+        extra.reverse();
+        self.append(extra);
+    }
+}
+
 impl<T> Drop for List<T> {
     fn drop(&mut self) {
-        for _ in self { }
+        // unlink the nodes one at a time, so that dropping a long list
+        // doesn't recurse once per node.
+        let mut head = self.head.take();
+        while let Some(mut node) = head {
+            head = node.next.take();
+            #[cfg(feature = "zeroize")]
+            wipe::drop_node(node);
+        }
+        self.shrink_to_fit();
     }
 }
 
 //==- zip list -=============================================================
+mod builder;
+mod chunks;
+mod entry;
+mod frozen;
+mod kill;
+mod mtf;
+#[cfg(feature = "rand")] mod random;
+mod read;
+mod stable;
+mod text;
+mod view;
+mod window;
+mod zip_iter;
+#[cfg(feature = "zeroize")] mod wipe;
+pub use builder::ZipListBuilder;
+pub use chunks::IntoChunks;
+pub use entry::Entry;
+pub use frozen::FrozenZipList;
+pub use kill::KillRing;
+pub use mtf::MoveToFrontList;
+pub use read::{ReadLeft, ReadRight};
+pub use stable::{Anchor, StableZipList};
+pub use text::TrackedZipper;
+pub use view::ZipView;
+pub use window::SlidingWindow;
+pub use zip_iter::{EnumerateFromCursor, ZipIter};
+
+
 /// A linked list with a zipper
 pub struct ZipList<T> { left: List<T>
                       , right: List<T>
+                        // the number of elements dropped by `commit_left`
+                      , committed: usize
+                        // added to the lists' own generations. The lists
+                        // handed out by `as_lists_mut` can be replaced by
+                        // ones with lower generations, so theirs are folded
+                        // into this one first.
+                      , generation: u64
+                        // the number of elements which have passed the
+                        // cursor rightward
+                      , consumed: usize
                       }
 
 impl<T> ZipList<T> {
@@ -277,13 +960,159 @@ impl<T> ZipList<T> {
         self.right.iter_mut()
     }
 
-    unstable_const_fn!{
-        /// Create a new empty `ZipList`.
-        pub const fn new() -> Self {
-            ZipList { left: List::new(), right: List::new() }
+    /// Borrow the lists of elements to the left and right of the zipper.
+    ///
+    /// Both lists start with the element nearest the zipper, so the left
+    /// list is in the reverse of the zipper's order.
+    #[inline] pub fn as_lists(&self) -> (&List<T>, &List<T>) {
+        (&self.left, &self.right)
+    }
+
+    /// Mutably borrow the lists of elements to the left and right of the
+    /// zipper, to edit each side with `List` methods.
+    ///
+    /// Both lists start with the element nearest the zipper, so the left
+    /// list is in the reverse of the zipper's order. Elements added to or
+    /// removed from the left list move the zipper's position accordingly.
+    ///
+    /// Marks made before this is called are stale afterwards, since the
+    /// lists could be swapped or replaced wholesale.
+    #[inline] pub fn as_lists_mut(&mut self) -> (&mut List<T>, &mut List<T>) {
+        self.generation = self.generation().wrapping_add(1);
+        self.left.generation = 0;
+        self.right.generation = 0;
+        (&mut self.left, &mut self.right)
+    }
+
+    /// Call `f` on every element, along with its signed distance from the
+    /// zipper.
+    ///
+    /// Elements to the left of the zipper have negative offsets, starting at
+    /// -1 for the element immediately to its left, and elements to the right
+    /// have positive offsets, starting at 1. Each side is visited nearest
+    /// element first, starting with the left.
+    pub fn for_each_with_offset<F>(&self, mut f: F)
+    where F: FnMut(isize, &T) {
+        for (i, elem) in self.left.iter().enumerate() {
+            f(-(i as isize) - 1, elem);
+        }
+        for (i, elem) in self.right.iter().enumerate() {
+            f(i as isize + 1, elem);
         }
     }
 
+    /// Call `f` on every element mutably, along with its signed distance
+    /// from the zipper.
+    ///
+    /// Offsets and visiting order are the same as for
+    /// [`for_each_with_offset`](#method.for_each_with_offset).
+    pub fn for_each_with_offset_mut<F>(&mut self, mut f: F)
+    where F: FnMut(isize, &mut T) {
+        for (i, elem) in self.left.iter_mut().enumerate() {
+            f(-(i as isize) - 1, elem);
+        }
+        for (i, elem) in self.right.iter_mut().enumerate() {
+            f(i as isize + 1, elem);
+        }
+    }
+
+    /// Call `f` on each element along with its signed distance from the
+    /// zipper, until it returns `Break`.
+    ///
+    /// Offsets and visiting order are the same as for
+    /// [`for_each_with_offset`](#method.for_each_with_offset).
+    ///
+    /// # Returns
+    /// - `ControlFlow::Break(B)` with the value `f` broke with
+    /// - `ControlFlow::Continue(())` if `f` visited every element
+    pub fn walk<'a, B, F>(&'a self, mut f: F) -> ops::ControlFlow<B>
+    where F: FnMut(isize, &'a T) -> ops::ControlFlow<B> {
+        let mut offset = 0;
+        self.left.walk(|elem| { offset -= 1; f(offset, elem) })?;
+        offset = 0;
+        self.right.walk(|elem| { offset += 1; f(offset, elem) })
+    }
+
+    /// Call `f` on each element mutably along with its signed distance from
+    /// the zipper, until it returns `Break`.
+    ///
+    /// Offsets and visiting order are the same as for
+    /// [`for_each_with_offset`](#method.for_each_with_offset).
+    pub fn walk_mut<'a, B, F>(&'a mut self, mut f: F) -> ops::ControlFlow<B>
+    where F: FnMut(isize, &'a mut T) -> ops::ControlFlow<B> {
+        let mut offset = 0;
+        self.left.walk_mut(|elem| { offset -= 1; f(offset, elem) })?;
+        offset = 0;
+        self.right.walk_mut(|elem| { offset += 1; f(offset, elem) })
+    }
+
+    /// Consume the zipper, applying `f` to each element from left to right,
+    /// and stop at the first error.
+    ///
+    /// # Returns
+    /// - `Ok(ZipList<U>)` with the results in the same order and the cursor
+    ///   in the same position, if `f` succeeded for every element
+    /// - `Err(E)` with the first error `f` returned
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<ZipList<U>, E>
+    where F: FnMut(T) -> Result<U, E> {
+        let ZipList { mut left, right, committed, generation, consumed } = self;
+        // the left list is nearest-first, so it's flipped around to map its
+        // elements from left to right, and flipped back afterwards
+        left.reverse();
+        let mut left = left.try_map(&mut f)?;
+        left.reverse();
+        let right = right.try_map(f)?;
+        Ok(ZipList { left, right, committed, generation, consumed })
+    }
+
+    /// Create a new empty `ZipList`.
+    ///
+    /// This is a `const fn`, so it can initialize a `static`.
+    pub const fn new() -> Self {
+        ZipList { left: List::new()
+                , right: List::new()
+                , committed: 0
+                , generation: 0
+                , consumed: 0 }
+    }
+
+    /// Create a `ZipList` of `n` clones of `value`, with the zipper at the
+    /// left end.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn from_elem(n: usize, value: T) -> Self
+    where T: Clone {
+        ZipList { left: List::new()
+                , right: List::from_elem(n, value)
+                , committed: 0
+                , generation: 0
+                , consumed: 0 }
+    }
+
+    /// Create a `ZipList` from the elements of `iter`, in order, with the
+    /// zipper placed before the element at index `cursor`.
+    ///
+    /// If `iter` has `cursor` elements or fewer, the zipper is placed at the
+    /// right end of the list.
+    ///
+    /// # Time complexity
+    /// O(n), consuming `iter` once.
+    pub fn from_iter_with_cursor<I>(iter: I, cursor: usize) -> Self
+    where I: IntoIterator<Item=T> {
+        let mut iter = iter.into_iter();
+        let mut left = List::new();
+        left.extend(iter.by_ref().take(cursor));
+        // collecting the rest of the elements stacks them up newest-first,
+        // so they have to be flipped around to come after the cursor in order
+        let mut right = iter.collect::<List<_>>();
+        right.reverse();
+        ZipList { left, right, committed: 0, generation: 0, consumed: 0 }
+    }
+
     // -- wrappers around sublist methods -----------------------------------
     /// Pop the item to the left of the zipper and return it.
     ///
@@ -339,10 +1168,651 @@ impl<T> ZipList<T> {
 
     /// Push `elem` to the right of the zipper.
     #[inline] pub fn push_right(&mut self, elem: T) -> &mut Self {
-        self.left.push(elem);
+        self.right.push(elem);
         self
     }
 
+    /// Push `elem` to the left of the zipper, reporting allocation failure
+    /// rather than aborting.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if a node couldn't be allocated for it
+    #[inline] pub fn try_push_left(&mut self, elem: T) -> Result<(), T> {
+        self.left.try_push(elem)
+    }
+
+    /// Push `elem` to the right of the zipper, reporting allocation failure
+    /// rather than aborting.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was pushed
+    /// - `Err(elem)` if a node couldn't be allocated for it
+    #[inline] pub fn try_push_right(&mut self, elem: T) -> Result<(), T> {
+        self.right.try_push(elem)
+    }
+
+    /// Make sure that the next `n` pushes to the left of the zipper won't
+    /// need to allocate.
+    ///
+    /// Moving the cursor doesn't use up reserved nodes, but nodes are
+    /// reserved separately for each side.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the nodes were reserved
+    /// - `Err(AllocError)` if a node couldn't be allocated
+    pub fn try_reserve_left(&mut self, n: usize) -> Result<(), AllocError> {
+        self.left.try_reserve_nodes(n)
+    }
+
+    /// Make sure that the next `n` pushes to the right of the zipper won't
+    /// need to allocate.
+    ///
+    /// Moving the cursor doesn't use up reserved nodes, but nodes are
+    /// reserved separately for each side.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the nodes were reserved
+    /// - `Err(AllocError)` if a node couldn't be allocated
+    pub fn try_reserve_right(&mut self, n: usize) -> Result<(), AllocError> {
+        self.right.try_reserve_nodes(n)
+    }
+
+    /// Make sure that the next `n` pushes to either side of the zipper won't
+    /// need to allocate, by reserving `n` nodes for each side.
+    ///
+    /// # Panics
+    /// Aborts if a node can't be allocated, like pushing does.
+    pub fn reserve(&mut self, n: usize) {
+        self.left.reserve(n);
+        self.right.reserve(n);
+    }
+
+    /// Make sure that the next `n` pushes to the left of the zipper won't
+    /// need to allocate.
+    ///
+    /// # Panics
+    /// Aborts if a node can't be allocated, like pushing does.
+    pub fn reserve_left(&mut self, n: usize) { self.left.reserve(n) }
+
+    /// Make sure that the next `n` pushes to the right of the zipper won't
+    /// need to allocate.
+    ///
+    /// # Panics
+    /// Aborts if a node can't be allocated, like pushing does.
+    pub fn reserve_right(&mut self, n: usize) { self.right.reserve(n) }
+
+    /// Insert all of `other`'s elements at the cursor, in order.
+    ///
+    /// The cursor ends up between the elements that were to the left and to
+    /// the right of `other`'s cursor. Nodes are moved by relinking, rather
+    /// than popping and pushing each element.
+    ///
+    /// # Time complexity
+    /// O(`other.len()`), to find the ends of `other`'s sides.
+    pub fn absorb(&mut self, other: ZipList<T>) {
+        let ZipList { left, right, .. } = other;
+        self.left.prepend(left);
+        self.right.prepend(right);
+    }
+
+    /// Merge the elements of `other`, which must be sorted, into this
+    /// zipper, which must be sorted too, by relinking their nodes.
+    ///
+    /// The cursor stays just after the element that was to its left (or at
+    /// the left end, if there wasn't one), so elements of `other` which are
+    /// inserted next to the cursor end up to its right. Elements of this
+    /// zipper come before equal elements of `other`. If either zipper isn't
+    /// sorted, all the elements are still kept, in an unspecified order.
+    ///
+    /// # Time complexity
+    /// O(`other.len()` + the distance from the cursor to the furthest
+    /// position an element of `other` is merged into)
+    pub fn merge_sorted(&mut self, other: ZipList<T>)
+    where T: Ord {
+        let ZipList { left: mut incoming, right, .. } = other;
+        incoming.reverse();
+        incoming.append(right);
+        let before = match self.left.peek() {
+            Some(last) => incoming.iter().take_while(|elem| *elem < last)
+                                 .count()
+          , None => 0
+        };
+        let after = incoming.split_off(before);
+        incoming.reverse();
+        // the left side is nearest-first, so it's in descending order, and
+        // equal elements from `other` go nearer to the cursor
+        self.left.merge_by(incoming, |theirs, ours| theirs >= ours);
+        self.right.merge_by(after, |theirs, ours| theirs < ours);
+    }
+
+    /// Sort the zipper's elements, keeping the cursor just after the element
+    /// that was to its left.
+    ///
+    /// The sort is stable, so the cursor's place among equal elements is
+    /// kept too. If the cursor was at the left end, it stays there.
+    ///
+    /// # Time complexity
+    /// O(n log n)
+    pub fn sort(&mut self)
+    where T: Ord {
+        self.sort_by(Ord::cmp)
+    }
+
+    /// Sort the zipper's elements with the comparison function `compare`,
+    /// like [`sort`](#method.sort).
+    ///
+    /// The nodes are relinked rather than moved, so the element which was to
+    /// the cursor's left is found again by its address afterwards. If
+    /// `compare` panics, some of the elements may be dropped.
+    ///
+    /// # Time complexity
+    /// O(n log n)
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where F: FnMut(&T, &T) -> Ordering {
+        let before = self.left.peek().map(|elem| elem as *const T);
+        self.left.reverse();
+        let right = self.right.split_off(0);
+        self.left.append(right);
+        self.left.merge_sort_by(&mut compare);
+        let cursor = before.map_or(0, |before| {
+            let same = |elem: &T| ::std::ptr::eq(elem, before);
+            1 + self.left.iter().position(same)
+                    .expect("sorting lost an element")
+        });
+        let after = self.left.split_off(cursor);
+        self.left.reverse();
+        self.right.append(after);
+    }
+
+    /// Interleave the elements of this zipper with those of `other`,
+    /// alternating between them starting with this zipper's first element,
+    /// by relinking their nodes.
+    ///
+    /// Once the shorter of the two runs out, the rest of the longer one
+    /// follows in order. The cursor is placed just after the element of
+    /// this zipper that was to its left, or at the left end if there wasn't
+    /// one; `other`'s cursor is ignored.
+    ///
+    /// # Time complexity
+    /// O(`self.len()` + `other.len()`)
+    pub fn interleave(self, other: ZipList<T>) -> ZipList<T> {
+        let ZipList { mut left, right, committed, generation, consumed } = self;
+        let cursor = match left.len() {
+            0 => 0
+          , before if before <= other.len() => 2 * before - 1
+          , before => before + other.len()
+        };
+        left.reverse();
+        left.append(right);
+        let ZipList { left: mut theirs, right: their_right, .. } = other;
+        theirs.reverse();
+        theirs.append(their_right);
+        let mut turn = false;
+        left.merge_by(theirs, |_, _| { turn = !turn; !turn });
+        let right = left.split_off(cursor);
+        left.reverse();
+        ZipList { left, right, committed, generation, consumed }
+    }
+
+    /// Detach up to `n` elements immediately to the left of the zipper,
+    /// returning them as a new `ZipList`, with its zipper at the right end.
+    ///
+    /// The zipper stays between the same elements of this list.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn take_left(&mut self, n: usize) -> ZipList<T> {
+        ZipList { left: self.left.split_front(n)
+                , right: List::new()
+                , committed: 0
+                , generation: 0
+                , consumed: 0 }
+    }
+
+    /// Detach up to `n` elements immediately to the right of the zipper,
+    /// returning them as a new `ZipList`, with its zipper at the left end.
+    ///
+    /// The zipper stays between the same elements of this list.
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn take_right(&mut self, n: usize) -> ZipList<T> {
+        ZipList { left: List::new()
+                , right: self.right.split_front(n)
+                , committed: 0
+                , generation: 0
+                , consumed: 0 }
+    }
+
+    /// Replace up to `n` elements immediately to the right of the zipper
+    /// with the elements of `items`, returning the elements that were
+    /// removed, in order.
+    ///
+    /// The replacements go to the right of the zipper in the order `items`
+    /// yields them, so this covers overwriting text in place as well as
+    /// replacing a match found by searching. The zipper stays to the left of
+    /// the replacements; seek right past them to continue after them.
+    ///
+    /// `items` is consumed before any elements are removed, so if it panics,
+    /// the zipper is left as it was. The replacements' nodes are taken from
+    /// the right side's freelist when there are any.
+    ///
+    /// # Time complexity
+    /// O(`n` + the number of elements in `items`)
+    pub fn replace_right_n<I>(&mut self, n: usize, items: I) -> List<T>
+    where I: IntoIterator<Item=T> {
+        trace_span!("replace_right_n", n, right = self.right.len);
+        let mut replacement = List::new();
+        replacement.free = self.right.free.take();
+        replacement.extend(items);
+        self.right.free = replacement.free.take();
+        replacement.reverse();
+        let removed = self.right.split_front(n);
+        self.right.prepend(replacement);
+        removed
+    }
+
+    /// Drop every element to the left of the zipper, releasing their nodes.
+    ///
+    /// This is for streaming parsers which will never backtrack past the
+    /// cursor again: without it, consumed input piles up on the left.
+    /// Nodes kept on the left side's freelist are released as well.
+    ///
+    /// # Returns
+    /// - the number of elements dropped
+    ///
+    /// # Time complexity
+    /// O(number of elements dropped)
+    pub fn commit_left(&mut self) -> usize {
+        let dropped = self.left.len();
+        // the new list carries on from the old one's generation, so marks
+        // made before the commit are stale afterwards.
+        let generation = self.left.generation;
+        #[cfg(feature = "stats")]
+        let stats = self.left.stats;
+        self.left = List::new();
+        self.left.generation = generation;
+        #[cfg(feature = "stats")]
+        { self.left.stats = stats; }
+        self.left.changed();
+        self.committed += dropped;
+        dropped
+    }
+
+    /// Drop any elements more than `left` positions to the left of the
+    /// cursor or more than `right` positions to its right, releasing their
+    /// nodes.
+    ///
+    /// Calling this after each step of a stream keeps the zipper as a
+    /// sliding window of bounded size around the cursor. Elements dropped
+    /// from the left still count towards the cursor's
+    /// [`offset`](#method.offset), as if they had been committed.
+    ///
+    /// # Returns
+    /// - the number of elements dropped
+    ///
+    /// # Time complexity
+    /// O(`left` + `right` + the number of elements dropped)
+    pub fn keep_window(&mut self, left: usize, right: usize) -> usize {
+        let from_left = self.left.split_off(left).len();
+        let from_right = self.right.split_off(right).len();
+        self.committed += from_left;
+        from_left + from_right
+    }
+
+    /// Move the zipper right until the element to its right matches `pred`,
+    /// or it reaches the right end, detaching the elements it passes.
+    ///
+    /// This is for error recovery in parsers: skip to the next
+    /// synchronization point, keeping what was skipped for diagnostics.
+    /// Skipped elements count towards the cursor's
+    /// [`offset`](#method.offset), as if they had been committed, and
+    /// towards [`consumed`](#method.consumed).
+    ///
+    /// # Returns
+    /// - the skipped elements, in order
+    ///
+    /// # Time complexity
+    /// O(number of elements skipped)
+    pub fn skip_right_until<P>(&mut self, pred: P) -> List<T>
+    where P: FnMut(&T) -> bool {
+        let n = self.right.iter().position(pred)
+                    .unwrap_or_else(|| self.right.len());
+        self.committed += n;
+        self.consumed += n;
+        self.right.split_front(n)
+    }
+
+    /// Insert `elem` at `index`, counting from the left end, without moving
+    /// the cursor.
+    ///
+    /// The cursor stays between the same elements. If `index` is the
+    /// cursor's position, `elem` is inserted to the right of the cursor.
+    ///
+    /// # Panics
+    /// If `index` is greater than `self.len()`.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to `index`)
+    pub fn insert_at(&mut self, index: usize, elem: T) {
+        let len = self.len();
+        if self.try_insert_at(index, elem).is_err() {
+            panic!( "insertion index {} is past the end of a zipper with {} \
+                     elements", index, len )
+        }
+    }
+
+    /// Insert `elem` at `index`, like [`insert_at`](#method.insert_at), if
+    /// `index` is at most `self.len()`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if `elem` was inserted
+    /// - `Err(elem)` if `index` is past the end of the zipper
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to `index`)
+    pub fn try_insert_at(&mut self, index: usize, elem: T) -> Result<(), T> {
+        if index > self.len() { return Err(elem) }
+        let cursor = self.left.len();
+        if index < cursor {
+            self.left.insert_nth(cursor - index, elem);
+        } else {
+            self.right.insert_nth(index - cursor, elem);
+        }
+        Ok(())
+    }
+
+    /// Remove the element at `index`, counting from the left end, without
+    /// moving the cursor.
+    ///
+    /// The cursor stays between the same elements that remain.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the element that was at `index`
+    /// - `None` if `index` is past the end of the zipper
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to `index`)
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        let cursor = self.left.len();
+        if index < cursor {
+            self.left.remove_nth(cursor - 1 - index)
+        } else {
+            self.right.remove_nth(index - cursor)
+        }
+    }
+
+    /// Drop elements from the right end until there are at most `len`.
+    ///
+    /// If the zipper was past the new end, it's moved to the right end.
+    ///
+    /// # Time complexity
+    /// O(`len`), to find the new end, plus the number of elements dropped.
+    pub fn truncate(&mut self, len: usize) {
+        let cursor = self.left.len();
+        if len >= cursor {
+            drop(self.right.split_off(len - cursor));
+        } else {
+            drop(self.right.split_off(0));
+            drop(self.left.split_front(cursor - len));
+        }
+    }
+
+    /// Truncate the zipper to `len` elements, or pad its right end with
+    /// clones of `value` until it has `len` elements, like `Vec::resize`.
+    ///
+    /// If the zipper was past the new end, it's moved to the right end.
+    /// Padding reuses nodes from the right side's freelist when there are
+    /// any.
+    ///
+    /// # Time complexity
+    /// O(`len`)
+    pub fn resize(&mut self, len: usize, value: T)
+    where T: Clone {
+        let current = self.len();
+        if len <= current { return self.truncate(len) }
+        let mut padding = List::new();
+        padding.free = self.right.free.take();
+        padding.extend(iter::repeat_n(value, len - current));
+        self.right.free = padding.free.take();
+        self.right.append(padding);
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, letting `keep`
+    /// modify each one, like `Vec::retain_mut`.
+    ///
+    /// The elements are visited from left to right, and the cursor stays
+    /// between the elements that were either side of it. If `keep` panics,
+    /// the elements left of the cursor may be left in reverse order.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn retain_mut<F>(&mut self, mut keep: F)
+    where F: FnMut(&mut T) -> bool {
+        // the left side is stored nearest-first, so it's flipped around to
+        // visit its elements in order.
+        self.left.reverse();
+        self.left.retain_mut(&mut keep);
+        self.left.reverse();
+        self.right.retain_mut(keep);
+    }
+
+    /// Move the elements for which `pred` returns `true` to the left of the
+    /// cursor, and the rest to its right, keeping the order of the elements
+    /// within each group, in one pass by relinking their nodes.
+    ///
+    /// The elements are visited from left to right. If `pred` panics, the
+    /// elements it hadn't finished with are dropped.
+    ///
+    /// # Returns
+    /// - the number of elements for which `pred` returned `true`, which is
+    ///   where the cursor ends up
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn partition_at_cursor<P>(&mut self, mut pred: P) -> usize
+    where P: FnMut(&T) -> bool {
+        self.left.reverse();
+        self.right.changed();
+        let left = self.left.head.take();
+        let right = self.right.head.take();
+        self.left.len = 0;
+        self.right.len = 0;
+        // matching nodes are pushed onto the left, which leaves them
+        // nearest-first, and the rest are linked onto the end of the right.
+        let mut link = &mut self.right.head;
+        for mut next in [left, right] {
+            while let Some(mut node) = next {
+                next = node.next.take();
+                if pred(&node.elem) { self.left.cons(node); }
+                else {
+                    link = &mut link.insert(node).next;
+                    self.right.len += 1;
+                }
+            }
+        }
+        self.left.len
+    }
+
+    /// Swap the elements at indices `i` and `j`, counting from the left end,
+    /// without moving the cursor.
+    ///
+    /// # Panics
+    /// If either index is past the end of the zipper.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the further of `i` and `j`)
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        if self.try_swap(i, j).is_err() {
+            panic!( "can't swap indices {} and {} in a zipper with {} elements"
+                  , i, j, len )
+        }
+    }
+
+    /// Swap the elements at indices `i` and `j`, like [`swap`](#method.swap),
+    /// or return an error if either index is past the end of the zipper.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the further of `i` and `j`)
+    pub fn try_swap(&mut self, i: usize, j: usize) -> Result<(), RangeError> {
+        let far = i.max(j);
+        RangeError::check( error::signed(far), error::signed(far) + 1
+                         , 0, error::signed(self.len()) )?;
+        let cursor = self.left.len();
+        // where each index falls, as a side and a position in that side
+        let side = |index: usize| {
+            if index < cursor { (true, cursor - 1 - index) }
+            else { (false, index - cursor) }
+        };
+        let ((i_left, i), (j_left, j)) = (side(i), side(j));
+        if i_left != j_left {
+            let (left, right) = if i_left { (i, j) } else { (j, i) };
+            let a = self.left.iter_mut().nth(left).expect("index in bounds");
+            let b = self.right.iter_mut().nth(right).expect("index in bounds");
+            mem::swap(a, b);
+        } else if i != j {
+            let list = if i_left { &mut self.left } else { &mut self.right };
+            let (near, far) = (i.min(j), i.max(j));
+            let mut elems = list.iter_mut();
+            let a = elems.nth(near).expect("index in bounds");
+            let b = elems.nth(far - near - 1).expect("index in bounds");
+            mem::swap(a, b);
+        }
+        Ok(())
+    }
+
+    /// Remove the elements in `range` of offsets from the cursor, returning
+    /// an iterator over them from left to right.
+    ///
+    /// Offsets count the gaps between elements, with the cursor at 0, so
+    /// `-3..5` removes the three elements to the left of the cursor and the
+    /// five to its right. The cursor stays between the same elements that
+    /// remain. As with [`List::drain`], elements that haven't been yielded
+    /// when the iterator is dropped are dropped with it.
+    ///
+    /// # Panics
+    /// If the range starts after it ends, or reaches past either end of the
+    /// zipper.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the far end of `range`)
+    ///
+    /// [`List::drain`]: struct.List.html#method.drain
+    pub fn drain<R>(&mut self, range: R) -> list::Drain<'_, T>
+    where R: ops::RangeBounds<isize> {
+        self.try_drain(range).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Remove the elements in `range` of offsets from the cursor, like
+    /// [`drain`](#method.drain), or return an error if the range starts
+    /// after it ends or reaches past either end of the zipper.
+    ///
+    /// The error's bounds are offsets from the cursor too.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the far end of `range`)
+    pub fn try_drain<R>(&mut self, range: R)
+                        -> Result<list::Drain<'_, T>, RangeError>
+    where R: ops::RangeBounds<isize> {
+        let left = error::signed(self.left.len());
+        let right = error::signed(self.right.len());
+        let start = match range.start_bound() {
+            ops::Bound::Included(&n) => n
+          , ops::Bound::Excluded(&n) => n.saturating_add(1)
+          , ops::Bound::Unbounded => -left
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&n) => n.saturating_add(1)
+          , ops::Bound::Excluded(&n) => n
+          , ops::Bound::Unbounded => right
+        };
+        RangeError::check(start, end, -left, right)?;
+        trace_event!(start, end, left, right, "drain");
+        // the left side is nearest-first, so the element at index `i` of it
+        // sits between offsets `-i - 1` and `-i`.
+        let (near, far) = ((-end).max(0) as usize, (-start).max(0) as usize);
+        let mut drained = self.left.split_off(near);
+        let rest = drained.split_off(far - near);
+        self.left.append(rest);
+        drained.reverse();
+        let (near, far) = (start.max(0) as usize, end.max(0) as usize);
+        let mut from_right = self.right.split_off(near);
+        let rest = from_right.split_off(far - near);
+        self.right.append(rest);
+        drained.append(from_right);
+        Ok(list::Drain::new(&mut self.right, drained))
+    }
+
+    /// Returns the cursor's absolute position: the number of elements to
+    /// its left, including any dropped by
+    /// [`commit_left`](#method.commit_left).
+    ///
+    /// This is the zipper's offset from the start of the input, which is
+    /// unaffected by committing. It isn't monotonic: moving the cursor left,
+    /// or popping or pushing to its left, changes it like any other
+    /// position. Parsers can record it to report source spans.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn offset(&self) -> usize {
+        self.committed + self.left.len()
+    }
+
+    /// Returns the number of elements which have passed the cursor moving
+    /// rightward, through [`move_right`](#method.move_right),
+    /// [`seek_right`](#method.seek_right) and the methods built on them.
+    ///
+    /// Unlike [`offset`](#method.offset), this never decreases: moving the
+    /// cursor left, or removing elements, leaves it as it is, and an element
+    /// which passes the cursor again is counted again. Elements pushed to
+    /// the left of the cursor don't pass it, so they aren't counted.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn consumed(&self) -> usize { self.consumed }
+
+    /// Returns a copy of the consumed elements, to the left of the cursor,
+    /// in `range`, in order, where `range` is measured in the same offsets
+    /// as [`offset`](#method.offset).
+    ///
+    /// # Panics
+    /// If `range` starts before the elements dropped by
+    /// [`commit_left`](#method.commit_left), ends past the zipper, or
+    /// starts after it ends.
+    ///
+    /// # Time complexity
+    /// O(`self.offset() - range.start`)
+    pub fn slice_consumed(&self, range: ops::Range<usize>) -> List<T>
+    where T: Clone {
+        self.try_slice_consumed(range).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Returns a copy of the consumed elements in `range`, like
+    /// [`slice_consumed`](#method.slice_consumed), or an error if `range`
+    /// starts before the elements dropped by
+    /// [`commit_left`](#method.commit_left), ends past the zipper, or
+    /// starts after it ends.
+    ///
+    /// # Time complexity
+    /// O(`self.offset() - range.start`)
+    pub fn try_slice_consumed(&self, range: ops::Range<usize>)
+                              -> Result<List<T>, RangeError>
+    where T: Clone {
+        let consumed = self.offset();
+        RangeError::check( error::signed(range.start), error::signed(range.end)
+                         , error::signed(self.committed)
+                         , error::signed(consumed) )?;
+        // the left side is nearest-first, so pushing the slice on in that
+        // order leaves its first element at the head.
+        Ok(self.left.iter()
+               .skip(consumed - range.end)
+               .take(range.end - range.start)
+               .cloned()
+               .collect())
+    }
+
     /// Returns the length of the `ZipList`
     #[inline] pub fn len(&self) -> usize { self.left.len() + self.right.len() }
 
@@ -351,15 +1821,105 @@ impl<T> ZipList<T> {
         self.left.is_empty() && self.right.is_empty()
     }
 
+    /// Returns true if there are no items to the left of the zipper.
+    #[inline] pub fn is_at_start(&self) -> bool { self.left.is_empty() }
+
+    /// Returns true if there are no items to the right of the zipper.
+    #[inline] pub fn is_at_end(&self) -> bool { self.right.is_empty() }
+
+    /// Returns where the zipper is in the list.
+    pub fn position_kind(&self) -> Position {
+        match (self.is_at_start(), self.is_at_end()) {
+            (true, true) => Position::Empty
+          , (true, false) => Position::Start
+          , (false, true) => Position::End
+          , (false, false) => Position::Middle
+        }
+    }
+
+    /// Returns a number which changes whenever elements are added to,
+    /// removed from, or reordered in the zipper.
+    ///
+    /// Moving the cursor and modifying elements in place don't change it.
+    /// This is what [`Mark`]s are checked against.
+    ///
+    /// [`Mark`]: struct.Mark.html
+    #[inline] pub fn generation(&self) -> u64 {
+        self.generation.wrapping_add(self.left.generation)
+                       .wrapping_add(self.right.generation)
+    }
+
+    /// Returns a [`Mark`] at the cursor's position, which the cursor can be
+    /// moved back to as long as no elements are added, removed or reordered
+    /// in the meantime.
+    ///
+    /// [`Mark`]: struct.Mark.html
+    pub fn mark(&self) -> Mark {
+        Mark { index: self.left.len(), generation: self.generation() }
+    }
+
+    /// Returns a [`Mark`] at `index`, counting from the left end, if it's at
+    /// most `self.len()`.
+    ///
+    /// [`Mark`]: struct.Mark.html
+    pub fn mark_at(&self, index: usize) -> Option<Mark> {
+        if index > self.len() { return None }
+        Some(Mark { index, generation: self.generation() })
+    }
+
+    /// Move the cursor to `mark`, unless the zipper has changed since the
+    /// mark was made.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the cursor was moved
+    /// - `Err(StaleMarkError)` if elements have been added, removed or
+    ///   reordered since, in which case the cursor isn't moved
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to `mark`)
+    pub fn seek_to_mark(&mut self, mark: Mark) -> Result<(), StaleMarkError> {
+        self.check_mark(mark)?;
+        let cursor = self.left.len();
+        if mark.index < cursor { self.seek_left(cursor - mark.index); }
+        else { self.seek_right(mark.index - cursor); }
+        Ok(())
+    }
+
+    /// Check that `mark` still refers to the same position it was made at.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the mark's index, counting from the left end
+    /// - `Err(StaleMarkError)` if elements have been added, removed or
+    ///   reordered since the mark was made
+    pub fn check_mark(&self, mark: Mark) -> Result<usize, StaleMarkError> {
+        let current = self.generation();
+        if mark.generation == current { Ok(mark.index) }
+        else { Err(StaleMarkError { made: mark.generation, current }) }
+    }
+
+    /// Returns the total number of bytes used by the `ZipList` and its nodes.
+    ///
+    /// Like [`List::heap_size_of_children`], this doesn't include any heap
+    /// memory owned by the elements themselves.
+    ///
+    /// [`List::heap_size_of_children`]:
+    ///     struct.List.html#method.heap_size_of_children
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>() + self.left.heap_size_of_children()
+                               + self.right.heap_size_of_children()
+    }
+
     /// Move the zipper one position to the left.
     ///
     /// # Returns
     /// - `true` if the zipper was moved to the left
     /// - `false` if the zipper is already at the left
     pub fn move_left(&mut self) -> bool {
-        self.left.uncons()
-            .map(|n| self.right.cons(n))
-            .is_some()
+        let moved = self.left.uncons()
+                        .map(|n| self.right.cons(n))
+                        .is_some();
+        count!(self.left, seeks += moved as u64, moved += moved as u64);
+        moved
     }
 
     /// Move the zipper one position to the right
@@ -368,9 +1928,12 @@ impl<T> ZipList<T> {
     /// - `true` if the zipper was moved to the right
     /// - `false` if the zipper is already at the right
     pub fn move_right(&mut self) -> bool {
-        self.right.uncons()
-            .map(|n| self.left.cons(n))
-            .is_some()
+        let moved = self.right.uncons()
+                        .map(|n| self.left.cons(n))
+                        .is_some();
+        self.consumed += moved as usize;
+        count!(self.right, seeks += moved as u64, moved += moved as u64);
+        moved
     }
 
     /// Move the zipper `n` positions to the left
@@ -379,11 +1942,10 @@ impl<T> ZipList<T> {
     /// - the number of positions moved. If this is less than `n`, then the
     ///   zipper reached the end of the list before it finished moving.
     pub fn seek_left(&mut self, n: usize) -> usize {
-        let mut amount = 0;
-        while amount < n && self.move_left() {
-            amount += 1
-        }
-        amount
+        let moved = self.left.transfer(&mut self.right, n);
+        count!(self.left, seeks += (moved > 0) as u64, moved += moved as u64);
+        trace_event!(requested = n, moved, "seek_left");
+        moved
     }
 
     /// Move the zipper `n` positions to the right
@@ -392,19 +1954,307 @@ impl<T> ZipList<T> {
     /// - the number of positions moved. If this is less than `n`, then the
     ///   zipper reached the end of the list before it finished moving.
     pub fn seek_right(&mut self, n: usize) -> usize {
-        let mut amount = 0;
-        while amount < n && self.move_right() {
-            amount += 1;
+        let moved = self.right.transfer(&mut self.left, n);
+        self.consumed += moved;
+        count!(self.right, seeks += (moved > 0) as u64, moved += moved as u64);
+        trace_event!(requested = n, moved, "seek_right");
+        moved
+    }
+
+    /// Move the zipper `n` positions to the left, calling `f` on each
+    /// element it passes, in the order it passes them.
+    ///
+    /// This is the same as calling `f` on the `n` elements to the left of
+    /// the zipper and then seeking, but takes a single walk.
+    ///
+    /// # Returns
+    /// - the number of positions moved, as for
+    ///   [`seek_left`](#method.seek_left)
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn seek_left_map<F>(&mut self, n: usize, f: F) -> usize
+    where F: FnMut(&mut T) {
+        let moved = self.left.transfer_with(&mut self.right, n, f);
+        count!(self.left, seeks += (moved > 0) as u64, moved += moved as u64);
+        trace_event!(requested = n, moved, "seek_left_map");
+        moved
+    }
+
+    /// Move the zipper `n` positions to the right, calling `f` on each
+    /// element it passes, in the order it passes them.
+    ///
+    /// This is the same as calling `f` on the `n` elements to the right of
+    /// the zipper and then seeking, but takes a single walk.
+    ///
+    /// # Returns
+    /// - the number of positions moved, as for
+    ///   [`seek_right`](#method.seek_right)
+    ///
+    /// # Time complexity
+    /// O(`n`)
+    pub fn seek_right_map<F>(&mut self, n: usize, f: F) -> usize
+    where F: FnMut(&mut T) {
+        let moved = self.right.transfer_with(&mut self.left, n, f);
+        self.consumed += moved;
+        count!(self.right, seeks += (moved > 0) as u64, moved += moved as u64);
+        trace_event!(requested = n, moved, "seek_right_map");
+        moved
+    }
+
+    /// Move the zipper exactly `n` positions to the left.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the zipper was moved
+    /// - `Err(SeekError)` if there are fewer than `n` items to the left of
+    ///   the zipper, in which case it isn't moved at all
+    pub fn try_seek_left(&mut self, n: usize) -> Result<(), SeekError> {
+        let moved = self.left.len();
+        if n > moved {
+            return Err(SeekError { requested: n, moved })
+        }
+        self.seek_left(n);
+        Ok(())
+    }
+
+    /// Move the zipper exactly `n` positions to the right.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the zipper was moved
+    /// - `Err(SeekError)` if there are fewer than `n` items to the right of
+    ///   the zipper, in which case it isn't moved at all
+    pub fn try_seek_right(&mut self, n: usize) -> Result<(), SeekError> {
+        let moved = self.right.len();
+        if n > moved {
+            return Err(SeekError { requested: n, moved })
+        }
+        self.seek_right(n);
+        Ok(())
+    }
+
+    /// Move the zipper to the delimiter matching the one to its right.
+    ///
+    /// If the element to the right of the zipper is an opening delimiter,
+    /// the zipper is moved right until the matching closing delimiter is to
+    /// its right. If it's a closing delimiter, the zipper is moved left
+    /// until the matching opening delimiter is to its right. Delimiters in
+    /// between are matched up by nesting depth.
+    ///
+    /// An element which is both an opening and a closing delimiter, like a
+    /// quote mark, is matched with the next one in the direction of travel.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved to the matching delimiter
+    /// - `false` if there's no delimiter to the right of the zipper, or it
+    ///   has no match, in which case the zipper isn't moved
+    ///
+    /// # Time complexity
+    /// O(distance to the matching delimiter), or O(`self.len()`) if there is
+    /// no match
+    pub fn seek_to_matching<O, C>(&mut self, open: O, close: C) -> bool
+    where O: Fn(&T) -> bool
+        , C: Fn(&T) -> bool {
+        let delimiter = match self.right.peek() {
+            Some(elem) => elem
+          , None => return false
+        };
+        if open(delimiter) {
+            let mut depth = 0;
+            let found = self.right.iter().skip(1).position(|elem| {
+                if close(elem) {
+                    if depth == 0 { return true }
+                    depth -= 1;
+                } else if open(elem) {
+                    depth += 1;
+                }
+                false
+            });
+            found.map(|i| self.seek_right(i + 1)).is_some()
+        } else if close(delimiter) {
+            let mut depth = 0;
+            let found = self.left.iter().position(|elem| {
+                if open(elem) {
+                    if depth == 0 { return true }
+                    depth -= 1;
+                } else if close(elem) {
+                    depth += 1;
+                }
+                false
+            });
+            found.map(|i| self.seek_left(i + 1)).is_some()
+        } else {
+            false
         }
-        amount
     }
 
 }
 
+impl<T> ZipList<T>
+where T: Ord {
+    /// Move the zipper to where `x` would be inserted, if the elements are
+    /// in sorted order.
+    ///
+    /// The zipper ends up after any elements equal to `x`, so pushing `x`
+    /// to the left afterwards keeps equal elements in the order they were
+    /// inserted. It's searched for by scanning outwards from the current
+    /// position, so this is quick when `x` belongs near the zipper.
+    ///
+    /// If the elements aren't sorted, the zipper stops at the first position
+    /// it finds where the element to its left, if any, is at most `x`, and
+    /// the element to its right, if any, is greater than `x`.
+    ///
+    /// # Returns
+    /// - the number of positions moved
+    ///
+    /// # Time complexity
+    /// O(number of positions moved)
+    pub fn seek_to_sorted_position(&mut self, x: &T) -> usize {
+        let mut moved = 0;
+        while let Some(left) = self.peek_left() {
+            if left <= x { break }
+            self.move_left();
+            moved += 1;
+        }
+        if moved > 0 { return moved }
+        while let Some(right) = self.peek_right() {
+            if right > x { break }
+            self.move_right();
+            moved += 1;
+        }
+        moved
+    }
+}
+
+impl<T> Default for ZipList<T> {
+    fn default() -> Self { ZipList::new() }
+}
+
+impl<T> Clone for ZipList<T>
+where T: Clone {
+    fn clone(&self) -> Self {
+        ZipList { left: self.left.clone()
+                , right: self.right.clone()
+                , committed: self.committed
+                , generation: self.generation
+                , consumed: self.consumed
+                }
+    }
+
+    /// Make this zipper a copy of `source`, reusing its nodes.
+    ///
+    /// The side that's getting shorter is copied first, and its spare nodes
+    /// are handed to the other side, so that nodes are reused even when the
+    /// cursors are in different places.
+    fn clone_from(&mut self, source: &Self) {
+        let ZipList { ref mut left, ref mut right, .. } = *self;
+        let (first, second, first_src, second_src) =
+            if left.len >= source.left.len {
+                (left, right, &source.left, &source.right)
+            } else {
+                (right, left, &source.right, &source.left)
+            };
+        first.clone_from(first_src);
+        first.give_free(second);
+        second.clone_from(second_src);
+        self.committed = source.committed;
+        self.consumed = source.consumed;
+    }
+}
+
+impl<T> ops::Add for ZipList<T> {
+    type Output = ZipList<T>;
+
+    /// Concatenate two zippers by linking all of `other`'s elements onto the
+    /// right end of `self`. The cursor stays where it was in `self`.
+    ///
+    /// # Time complexity
+    /// O(`self.right_iter().len() + other.left_iter().len()`)
+    fn add(mut self, other: ZipList<T>) -> ZipList<T> {
+        self += other;
+        self
+    }
+}
+
+impl<T> ops::AddAssign for ZipList<T> {
+    /// Link all of `other`'s elements onto the right end of `self`. The
+    /// cursor stays where it was in `self`.
+    ///
+    /// # Time complexity
+    /// O(`self.right_iter().len() + other.left_iter().len()`)
+    fn add_assign(&mut self, other: ZipList<T>) {
+        let ZipList { mut left, right, .. } = other;
+        left.reverse();
+        left.append(right);
+        self.right.append(left);
+    }
+}
+
+/// A position in a `ZipList`, which remembers the zipper's
+/// [`generation`] when it was made.
+///
+/// Unlike a reference, a mark doesn't borrow the zipper, so the zipper can
+/// be moved and edited while it's held. A mark made before elements were
+/// added, removed or reordered is *stale*, and using it returns a
+/// [`StaleMarkError`] rather than a position that may have shifted.
+/// Marks aren't tied to the zipper they came from, so using one with a
+/// different zipper isn't detected.
+///
+/// ```
+/// # use an_zipper::ZipList;
+/// let mut zipper = ZipList::from_iter_with_cursor(0..5, 2);
+/// let mark = zipper.mark();
+/// zipper.seek_right(3);
+/// *zipper.peek_left_mut().unwrap() = 40;
+/// assert!(zipper.seek_to_mark(mark).is_ok());
+/// assert_eq!(zipper.peek_right(), Some(&2));
+/// zipper.push_left(10);
+/// assert!(zipper.seek_to_mark(mark).is_err());
+/// ```
+///
+/// [`generation`]: struct.ZipList.html#method.generation
+/// [`StaleMarkError`]: error/struct.StaleMarkError.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mark { index: usize
+                , generation: u64
+                }
+
+/// Where a `ZipList`'s cursor is, as returned by
+/// [`ZipList::position_kind`](struct.ZipList.html#method.position_kind).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The cursor is before the first item.
+    Start
+  , /// The cursor is between two items.
+    Middle
+  , /// The cursor is after the last item.
+    End
+  , /// The list is empty, so the cursor is at both the start and the end.
+    Empty
+}
+
+/// Marks the position of the cursor in a zipper's alternate `Debug` output.
+struct CursorMarker;
+
+impl fmt::Debug for CursorMarker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(">>> cursor <<<")
+    }
+}
 
 impl<T> fmt::Debug for ZipList<T>
 where T: fmt::Debug {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            // `{:#?}` prints one element per line, in order, with the
+            // cursor's position marked between them
+            let mut left = self.left_iter().collect::<Vec<_>>();
+            left.reverse();
+            return f.debug_list()
+                    .entries(left)
+                    .entry(&CursorMarker)
+                    .entries(self.right_iter())
+                    .finish()
+        }
         write!( f, "[{:?}_{:?}]"
               , self.left.head.as_ref()
                     .map(|head| format!("{:?}, ", head))
@@ -1,5 +1,8 @@
 use std::iter;
+use std::mem;
+use std::ops::{Bound, ControlFlow, RangeBounds};
 use super::{List, Node, Stack};
+use error::{self, RangeError};
 
 #[cfg(test)] mod test;
 
@@ -17,6 +20,454 @@ impl<T> List<T> {
     pub fn into_iter(self) -> IntoIter<T> {
         IntoIter(self)
     }
+
+    /// Returns an iterator over the elements in `range` of indices.
+    ///
+    /// # Panics
+    /// If the range starts after it ends, or ends past the end of the list.
+    ///
+    /// # Time complexity
+    /// O(start of `range`) to create the iterator.
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where R: RangeBounds<usize> {
+        self.try_range(range).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Returns an iterator over the elements in `range` of indices, or an
+    /// error if the range is out of bounds.
+    ///
+    /// # Time complexity
+    /// O(start of `range`) to create the iterator.
+    pub fn try_range<R>(&self, range: R) -> Result<Iter<'_, T>, RangeError>
+    where R: RangeBounds<usize> {
+        let (start, end) = self.try_bounds(range)?;
+        let (_, rest) = self.iter().split_at(start);
+        Ok(rest.split_at(end - start).0)
+    }
+
+    /// Returns an iterator over mutable references to the elements in
+    /// `range` of indices.
+    ///
+    /// # Panics
+    /// If the range starts after it ends, or ends past the end of the list.
+    ///
+    /// # Time complexity
+    /// O(start of `range`) to create the iterator.
+    pub fn range_mut<R>(&mut self, range: R) -> IterMut<'_, T>
+    where R: RangeBounds<usize> {
+        self.try_range_mut(range).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Returns an iterator over mutable references to the elements in
+    /// `range` of indices, or an error if the range is out of bounds.
+    ///
+    /// # Time complexity
+    /// O(start of `range`) to create the iterator.
+    pub fn try_range_mut<R>(&mut self, range: R)
+                            -> Result<IterMut<'_, T>, RangeError>
+    where R: RangeBounds<usize> {
+        let (start, end) = self.try_bounds(range)?;
+        let mut next = self.head.as_deref_mut();
+        for _ in 0..start {
+            next = next.and_then(|node| node.next.as_deref_mut());
+        }
+        Ok(IterMut { next, len: end - start })
+    }
+
+    /// Replace the elements in `range` of indices with the elements of
+    /// `replace_with`, returning the elements that were removed as a list.
+    ///
+    /// Like `Vec::splice`, except that the removed elements are returned all
+    /// at once rather than as an iterator. Nodes are relinked rather than
+    /// moved, and the replacement's nodes are taken from the freelist when
+    /// there are any.
+    ///
+    /// `replace_with` is consumed before any elements are moved, so if it
+    /// panics, the list's elements are left as they were.
+    ///
+    /// # Panics
+    /// If the range starts after it ends, or ends past the end of the list.
+    ///
+    /// # Time complexity
+    /// O(end of `range` + the number of elements in `replace_with`)
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> List<T>
+    where R: RangeBounds<usize>
+        , I: IntoIterator<Item=T> {
+        self.try_splice(range, replace_with)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Replace the elements in `range` of indices with the elements of
+    /// `replace_with`, like [`splice`](#method.splice), or return an error
+    /// if the range is out of bounds.
+    ///
+    /// The range is checked before `replace_with` is consumed, so if it's
+    /// out of bounds, `replace_with` isn't iterated at all.
+    ///
+    /// # Time complexity
+    /// O(end of `range` + the number of elements in `replace_with`)
+    pub fn try_splice<R, I>(&mut self, range: R, replace_with: I)
+                            -> Result<List<T>, RangeError>
+    where R: RangeBounds<usize>
+        , I: IntoIterator<Item=T> {
+        let (start, end) = self.try_bounds(range)?;
+        trace_span!("splice", start, end, len = self.len);
+        // build the replacement with this list's freelist, so it can reuse
+        // any free nodes, and then give the freelist back.
+        let mut replacement = List::new();
+        replacement.free = self.free.take();
+        replacement.extend(replace_with);
+        self.free = replacement.free.take();
+        replacement.reverse();
+        let mut removed = self.split_off(start);
+        let rest = removed.split_off(end - start);
+        replacement.append(rest);
+        self.append(replacement);
+        Ok(removed)
+    }
+
+    /// Remove the elements in `range` of indices, returning an iterator over
+    /// them.
+    ///
+    /// The elements are unlinked from the list straight away, and any that
+    /// haven't been yielded when the iterator is dropped are dropped with it.
+    /// Nodes freed as the elements are taken out go back onto this list's
+    /// freelist, to be reused by later pushes.
+    ///
+    /// # Panics
+    /// If the range starts after it ends, or ends past the end of the list.
+    ///
+    /// # Time complexity
+    /// O(end of `range`) to create the iterator, and O(1) per element.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where R: RangeBounds<usize> {
+        self.try_drain(range).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Remove the elements in `range` of indices, returning an iterator over
+    /// them like [`drain`](#method.drain), or return an error if the range
+    /// is out of bounds.
+    ///
+    /// # Time complexity
+    /// O(end of `range`) to create the iterator, and O(1) per element.
+    pub fn try_drain<R>(&mut self, range: R)
+                        -> Result<Drain<'_, T>, RangeError>
+    where R: RangeBounds<usize> {
+        let (start, end) = self.try_bounds(range)?;
+        trace_event!(start, end, len = self.len, "drain");
+        let mut drained = self.split_off(start);
+        let rest = drained.split_off(end - start);
+        self.append(rest);
+        Ok(Drain::new(self, drained))
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, visiting them
+    /// in order and letting `keep` modify each one, like `Vec::retain_mut`.
+    ///
+    /// Nodes of the elements that are removed go onto the freelist.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn retain_mut<F>(&mut self, mut keep: F)
+    where F: FnMut(&mut T) -> bool {
+        // rejected nodes are moved onto `removed`, which borrows this list's
+        // freelist, so that they can be recycled once the walk is done.
+        let mut removed = List::new();
+        removed.free = self.free.take();
+        self.changed();
+        {
+            let mut link = &mut self.head;
+            loop {
+                let kept = match *link {
+                    Some(ref mut node) => keep(&mut node.elem)
+                  , None => break
+                };
+                if kept {
+                    link = &mut link.as_mut().unwrap().next;
+                } else {
+                    let mut node = link.take().unwrap();
+                    *link = node.next.take();
+                    self.len -= 1;
+                    removed.cons(node);
+                }
+            }
+        }
+        while removed.pop().is_some() {}
+        self.free = removed.free.take();
+    }
+
+    /// Call `f` on each element in order, until it returns `Break`.
+    ///
+    /// This walks the nodes directly rather than through an iterator, so `f`
+    /// can stop the walk early without any iterator state to thread around.
+    ///
+    /// # Returns
+    /// - `ControlFlow::Break(B)` with the value `f` broke with
+    /// - `ControlFlow::Continue(())` if `f` visited every element
+    ///
+    /// # Time complexity
+    /// O(number of elements visited)
+    pub fn walk<'a, B, F>(&'a self, mut f: F) -> ControlFlow<B>
+    where F: FnMut(&'a T) -> ControlFlow<B> {
+        let mut next = self.head.as_deref();
+        while let Some(node) = next {
+            f(&node.elem)?;
+            next = node.next.as_deref();
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Call `f` on each element mutably in order, until it returns `Break`.
+    ///
+    /// Like [`walk`](#method.walk), but `f` can modify the elements it
+    /// visits, and can break with a mutable reference to one of them.
+    ///
+    /// # Time complexity
+    /// O(number of elements visited)
+    pub fn walk_mut<'a, B, F>(&'a mut self, mut f: F) -> ControlFlow<B>
+    where F: FnMut(&'a mut T) -> ControlFlow<B> {
+        let mut next = self.head.as_deref_mut();
+        while let Some(node) = next {
+            f(&mut node.elem)?;
+            next = node.next.as_deref_mut();
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Consume the list, applying `f` to each element in order, and stop at
+    /// the first error.
+    ///
+    /// # Returns
+    /// - `Ok(List<U>)` with the results in the same order, if `f` succeeded
+    ///   for every element
+    /// - `Err(E)` with the first error `f` returned. The elements `f` wasn't
+    ///   called on, and the results so far, are dropped.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<List<U>, E>
+    where F: FnMut(T) -> Result<U, E> {
+        let mut mapped = List::new();
+        let mut link = &mut mapped.head;
+        for elem in self {
+            link = &mut link.insert(Box::new(Node::new(f(elem)?))).next;
+            mapped.len += 1;
+        }
+        count!( mapped
+              , pushes += mapped.len as u64
+              , allocations += mapped.len as u64 );
+        Ok(mapped)
+    }
+
+    /// Divide the list into `n` contiguous parts, in order, whose lengths
+    /// differ by at most one, by relinking its nodes.
+    ///
+    /// The longer parts come first, and if the list has fewer than `n`
+    /// elements, the parts at the end are empty. The first part keeps the
+    /// list's freelist. This is the first step in handing a list's
+    /// elements out to `n` workers.
+    ///
+    /// # Panics
+    /// If `n` is 0.
+    ///
+    /// # Time complexity
+    /// O(`n` + the number of elements)
+    pub fn split_into(mut self, n: usize) -> Vec<List<T>> {
+        assert!(n > 0, "can't split a list into 0 parts");
+        let (size, longer) = (self.len / n, self.len % n);
+        let mut parts = Vec::with_capacity(n);
+        for i in 1..n {
+            let len = if i <= longer { size + 1 } else { size };
+            let rest = self.split_off(len);
+            parts.push(mem::replace(&mut self, rest));
+        }
+        parts.push(self);
+        parts
+    }
+
+    /// Remove the least element of the list and return it.
+    ///
+    /// If several elements are equally least, the first is removed, as
+    /// `Iterator::min` would return. Its node goes onto the freelist.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list wasn't empty
+    /// - `None` otherwise
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn remove_min(&mut self) -> Option<T>
+    where T: Ord {
+        self.remove_best(|elem, least| least.is_none_or(|least| elem < least))
+    }
+
+    /// Remove the greatest element of the list and return it.
+    ///
+    /// If several elements are equally greatest, the last is removed, as
+    /// `Iterator::max` would return. Its node goes onto the freelist.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list wasn't empty
+    /// - `None` otherwise
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn remove_max(&mut self) -> Option<T>
+    where T: Ord {
+        self.remove_best(|elem, most| most.is_none_or(|most| elem >= most))
+    }
+
+    /// Remove the element of the list for which `f` returns the least key,
+    /// like [`remove_min`](#method.remove_min).
+    ///
+    /// `f` is called once for each element.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn remove_min_by_key<K, F>(&mut self, mut f: F) -> Option<T>
+    where K: Ord
+        , F: FnMut(&T) -> K {
+        let mut least = None;
+        self.remove_best(|elem, _| {
+            let key = f(elem);
+            let better = least.as_ref().is_none_or(|least| key < *least);
+            if better { least = Some(key) }
+            better
+        })
+    }
+
+    /// Remove the element of the list for which `f` returns the greatest
+    /// key, like [`remove_max`](#method.remove_max).
+    ///
+    /// `f` is called once for each element.
+    ///
+    /// # Time complexity
+    /// O(n)
+    pub fn remove_max_by_key<K, F>(&mut self, mut f: F) -> Option<T>
+    where K: Ord
+        , F: FnMut(&T) -> K {
+        let mut most = None;
+        self.remove_best(|elem, _| {
+            let key = f(elem);
+            let better = most.as_ref().is_none_or(|most| key >= *most);
+            if better { most = Some(key) }
+            better
+        })
+    }
+
+    /// Remove the element `better` picks out and return it. Its node goes
+    /// onto the freelist.
+    ///
+    /// `better` is called on each element in turn, along with the best
+    /// element so far, if there is one, and returns whether the element
+    /// should take its place.
+    ///
+    /// # Time complexity
+    /// O(n)
+    fn remove_best<F>(&mut self, mut better: F) -> Option<T>
+    where F: FnMut(&T, Option<&T>) -> bool {
+        let mut best = None;
+        for (index, elem) in self.iter().enumerate() {
+            if better(elem, best.map(|(_, best)| best)) {
+                best = Some((index, elem));
+            }
+        }
+        let (index, _) = best?;
+        self.changed();
+        self.remove_nth(index)
+    }
+
+    /// Move the element at `index` to the front of the list, by relinking its
+    /// node, as when an entry in a least-recently-used cache is used.
+    ///
+    /// # Returns
+    /// - `true` if the element was moved
+    /// - `false` if `index` is past the end of the list
+    ///
+    /// # Time complexity
+    /// O(`index`)
+    pub fn touch(&mut self, index: usize) -> bool {
+        if index >= self.len { return false }
+        self.changed();
+        let mut node = {
+            let link = self.link_at(index);
+            let mut node = link.take().expect("index out of bounds");
+            *link = node.next.take();
+            node
+        };
+        node.next = self.head.take();
+        self.head = Some(node);
+        true
+    }
+
+    /// Move the first element which `pred` returns `true` for to the front
+    /// of the list, by relinking its node.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index the element was at before it was moved
+    /// - `None` if there was no such element, in which case the list isn't
+    ///   reordered
+    ///
+    /// # Time complexity
+    /// O(index of the element found)
+    pub fn touch_where<F>(&mut self, mut pred: F) -> Option<usize>
+    where F: FnMut(&T) -> bool {
+        let mut index = 0;
+        let mut node = {
+            let mut link = &mut self.head;
+            loop {
+                let found = match *link {
+                    Some(ref node) => pred(&node.elem)
+                  , None => return None
+                };
+                if found { break }
+                link = &mut link.as_mut().unwrap().next;
+                index += 1;
+            }
+            let mut node = link.take().unwrap();
+            *link = node.next.take();
+            node
+        };
+        self.changed();
+        node.next = self.head.take();
+        self.head = Some(node);
+        Some(index)
+    }
+
+    /// Remove the element at the back of the list, which is the least
+    /// recently used if elements are pushed and [`touch`]ed at the front.
+    ///
+    /// The node goes onto the freelist, so evicting an entry and pushing its
+    /// replacement doesn't allocate.
+    ///
+    /// # Time complexity
+    /// O(n), since the list has to be walked to find its back.
+    ///
+    /// [`touch`]: #method.touch
+    pub fn pop_back_lru(&mut self) -> Option<T> {
+        let last = self.len.checked_sub(1)?;
+        self.remove_nth(last)
+    }
+
+    /// Resolve `range` to a start and end index, checking it's in bounds.
+    fn try_bounds<R>(&self, range: R) -> Result<(usize, usize), RangeError>
+    where R: RangeBounds<usize> {
+        // bounds too large to add one to are out of bounds anyway, so they
+        // saturate rather than overflowing.
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n
+          , Bound::Excluded(&n) => n.saturating_add(1)
+          , Bound::Unbounded => 0
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.saturating_add(1)
+          , Bound::Excluded(&n) => n
+          , Bound::Unbounded => self.len
+        };
+        RangeError::check( error::signed(start), error::signed(end)
+                         , 0, error::signed(self.len) )?;
+        Ok((start, end))
+    }
 }
 
 pub struct Iter<'a, T: 'a>{ next: Option<&'a Node<T>>
@@ -27,6 +478,9 @@ where T: 'a {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // an iterator split off from the front of another one ends before
+        // the end of the list, so stop when we've run out of length.
+        if self.len == 0 { return None; }
         self.next.map(|node| {
             self.next = node.next.as_ref()
                          .map(|next| &**next);
@@ -39,13 +493,52 @@ where T: 'a {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.len, Some(self.len))
     }
+
+    #[inline] fn count(self) -> usize { self.len }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            self.next = None;
+            return None;
+        }
+        for _ in 0..n {
+            self.next = self.next.and_then(|node| node.next.as_deref());
+        }
+        self.len -= n;
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let last = self.len.checked_sub(1)?;
+        self.nth(last)
+    }
 }
 
 
+impl<'a, T> Iter<'a, T> {
+    /// Split this iterator in two, returning an iterator over the next `n`
+    /// elements and an iterator over the elements after them.
+    pub(crate) fn split_at(self, n: usize) -> (Self, Self) {
+        let n = n.min(self.len);
+        let mut rest = self.next;
+        for _ in 0..n {
+            rest = rest.and_then(|node| node.next.as_deref());
+        }
+        ( Iter { next: self.next, len: n }
+        , Iter { next: rest, len: self.len - n }
+        )
+    }
+}
+
 impl<'a, T> iter::ExactSizeIterator for Iter<'a, T> {
     #[inline] fn len(&self) -> usize { self.len }
 }
 
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self { Iter { next: self.next, len: self.len } }
+}
+
 pub struct IterMut<'a, T: 'a>{ next: Option<&'a mut Node<T>>
                                  , len: usize }
 
@@ -54,6 +547,8 @@ where T: 'a {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // as with `Iter`, a range ends before the end of the list.
+        if self.len == 0 { return None; }
         self.next.take().map(|node| {
             self.next = node.next.as_mut()
                          .map(|next| &mut **next);
@@ -66,6 +561,29 @@ where T: 'a {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.len, Some(self.len))
     }
+
+    #[inline] fn count(self) -> usize { self.len }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            self.next = None;
+            return None;
+        }
+        // skip over the nodes without borrowing their elements.
+        let mut next = self.next.take();
+        for _ in 0..n {
+            next = next.and_then(|node| node.next.as_deref_mut());
+        }
+        self.next = next;
+        self.len -= n;
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let last = self.len.checked_sub(1)?;
+        self.nth(last)
+    }
 }
 
 impl<'a, T> iter::ExactSizeIterator for IterMut<'a, T> {
@@ -82,8 +600,60 @@ impl<T> Iterator for IntoIter<T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.0.len, Some(self.0.len))
     }
+
+    #[inline] fn count(self) -> usize { self.0.len }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // unlink the skipped elements all at once, and drop them together.
+        let rest = self.0.split_off(n);
+        drop(mem::replace(&mut self.0, rest));
+        self.0.pop()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let last = self.0.len.checked_sub(1)?;
+        self.0.remove_nth(last)
+    }
 }
 
 impl<T> iter::ExactSizeIterator for IntoIter<T> {
     #[inline] fn len(&self) -> usize { self.0.len }
 }
+
+/// An iterator which removes a range of elements from a `List`.
+///
+/// This is created by [`List::drain`](../struct.List.html#method.drain).
+pub struct Drain<'a, T: 'a> { list: &'a mut List<T>
+                            , drained: List<T>
+                            }
+
+impl<'a, T> Drain<'a, T> {
+    /// Returns an iterator over `drained`, which gives its nodes to `list`'s
+    /// freelist when it's dropped.
+    pub(crate) fn new(list: &'a mut List<T>, drained: List<T>) -> Self {
+        Drain { list, drained }
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+    #[inline] fn next(&mut self) -> Option<Self::Item> { self.drained.pop() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.drained.len, Some(self.drained.len))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for Drain<'a, T> {
+    #[inline] fn len(&self) -> usize { self.drained.len }
+}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // dropping the rest one at a time puts their nodes on the freelist
+        // too, and then the whole freelist is handed back to the list.
+        while self.drained.pop().is_some() {}
+        self.drained.give_free(self.list);
+    }
+}
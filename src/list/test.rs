@@ -1,4 +1,4 @@
-use ::{List, Stack};
+use ::{List, Stack, ThinList};
 use quickcheck::{Arbitrary, Gen};
 
 impl<T> Arbitrary for List<T>
@@ -101,3 +101,621 @@ quickcheck! {
             .all(|(a, b)| &a == b)
     }
 }
+
+quickcheck! {
+    fn push_pop_cycles_preserve_contents(list: List<usize>, items: Vec<usize>)
+                                         -> bool {
+        let mut list = list;
+        let before = list.iter().cloned().collect::<Vec<_>>();
+
+        for item in items.clone() {
+            list.push(item);
+        }
+        for _ in 0..items.len() {
+            list.pop();
+        }
+        for item in items.clone() {
+            list.push(item);
+        }
+
+        list.len() == before.len() + items.len() &&
+        list.iter().skip(items.len()).cloned().collect::<Vec<_>>() == before
+    }
+}
+
+#[test]
+fn pop_then_push_reuses_node() {
+    let mut list = List::new();
+    list.push(1usize);
+    let addr = list.peek().unwrap() as *const usize;
+    list.pop();
+    list.push(2);
+    assert_eq!(list.peek().unwrap() as *const usize, addr);
+}
+
+#[test]
+fn freelist_drops_elements_once() {
+    use std::rc::Rc;
+    let elem = Rc::new(());
+    {
+        let mut list = List::new();
+        for _ in 0..10 { list.push(elem.clone()); }
+        for _ in 0..5 { list.pop(); }
+        assert_eq!(Rc::strong_count(&elem), 6);
+        list.shrink_to_fit();
+        list.push(elem.clone());
+        assert_eq!(Rc::strong_count(&elem), 7);
+    }
+    assert_eq!(Rc::strong_count(&elem), 1);
+}
+
+#[test]
+fn heap_size_counts_free_nodes() {
+    use std::mem::size_of;
+    let node = size_of::<usize>() + size_of::<usize>();
+    let mut list = List::new();
+    list.extend(0..4usize);
+    assert_eq!(list.heap_size_of_children(), 4 * node);
+    list.pop();
+    assert_eq!(list.heap_size_of_children(), 4 * node);
+    list.shrink_to_fit();
+    assert_eq!(list.heap_size_of_children(), 3 * node);
+}
+
+#[test]
+fn heap_size_recurses_into_elements() {
+    use size::MemSize;
+    let mut list = List::new();
+    list.push(String::with_capacity(10));
+    list.push(String::with_capacity(20));
+    assert_eq!(list.heap_size(), list.heap_size_of_children() + 30);
+}
+
+quickcheck! {
+    fn extend_same_as_pushes(list: List<usize>, items: Vec<usize>) -> bool {
+        let mut pushed = list.clone();
+        let mut extended = list;
+        for item in items.clone() {
+            pushed.push(item);
+        }
+        extended.extend(items);
+        extended.len() == pushed.len() && extended.iter().eq(pushed.iter())
+    }
+}
+
+#[test]
+fn extend_reuses_free_nodes() {
+    let mut list = (0..100usize).collect::<List<_>>();
+    let size = list.heap_size_of_children();
+    for _ in 0..50 { list.pop(); }
+    list.extend(0..50);
+    assert_eq!(list.heap_size_of_children(), size);
+}
+
+#[test]
+fn extend_reserves_nodes_for_exact_size_input() {
+    use std::panic;
+    let mut list = list![0usize];
+    let node = list.heap_size_of_children();
+    // every node is allocated before the first element is pushed, so they're
+    // all there even though the iterator stops part way
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        list.extend((1..11).map(|i| if i < 5 { i } else { panic!() }));
+    }));
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.heap_size_of_children(), 11 * node);
+    list.extend(5..11);
+    assert_eq!(list.heap_size_of_children(), 11 * node);
+}
+
+#[test]
+fn extend_keeps_pushed_items_on_panic() {
+    use std::panic;
+    let mut list = List::new();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        list.extend((0..10usize).map(|i| if i < 5 { i } else { panic!() }));
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.len(), 5);
+    assert!(list.iter().cloned().eq((0..5).rev()));
+}
+
+#[test]
+fn list_macro_in_declaration_order() {
+    let list = list![1, 2, 3];
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let empty: List<usize> = list![];
+    assert!(empty.is_empty());
+}
+
+quickcheck! {
+    fn add_concatenates(a: List<usize>, b: List<usize>) -> bool {
+        let expected = a.iter().chain(b.iter()).cloned().collect::<Vec<_>>();
+        let sum = a + b;
+        sum.len() == expected.len() && sum.iter().cloned().eq(expected)
+    }
+
+    fn add_assign_concatenates(a: List<usize>, b: List<usize>) -> bool {
+        let expected = a.iter().chain(b.iter()).cloned().collect::<Vec<_>>();
+        let mut a = a;
+        a += b;
+        a.len() == expected.len() && a.iter().cloned().eq(expected)
+    }
+}
+
+#[test]
+fn alternate_debug_one_element_per_line() {
+    assert_eq!(format!("{:#?}", list![1, 2]), "[\n    1,\n    2,\n]");
+    assert_eq!(format!("{:#?}", List::<usize>::new()), "[]");
+}
+
+#[test]
+fn to_dot_draws_nodes_in_order() {
+    let list = list!["a", "b c"];
+    assert_eq!(list.to_dot(), "digraph list {
+    rankdir=LR;
+    node [shape=record];
+    list [label=\"List|len: 2\"];
+    n0 [label=\"<elem> \\\"a\\\"|<next>\"];
+    n1 [label=\"<elem> \\\"b\\ c\\\"|<next>\"];
+    n0:next -> n1:elem;
+    list -> n0:elem;
+}
+");
+}
+
+#[test]
+fn leak_outlives_scope() {
+    let leaked: &'static mut List<&str> = {
+        let mut list = list!["debug", "verbose"];
+        list.push("quiet");
+        list.pop();
+        list.leak()
+    };
+    // the popped node's allocation was released rather than leaked
+    assert_eq!( leaked.heap_size_of_children()
+              , list!["debug", "verbose"].heap_size_of_children() );
+    leaked.push("trace");
+    assert_eq!( leaked.iter().collect::<Vec<_>>()
+              , vec![&"trace", &"debug", &"verbose"] );
+}
+
+#[test]
+fn try_reserve_nodes_fills_freelist() {
+    let mut list = list![1];
+    let node = list.heap_size_of_children();
+    list.try_reserve_nodes(3).unwrap();
+    assert_eq!(list.heap_size_of_children(), 4 * node);
+    // already reserved
+    list.try_reserve_nodes(2).unwrap();
+    assert_eq!(list.heap_size_of_children(), 4 * node);
+    for i in 2..5 { list.try_push(i).unwrap(); }
+    assert_eq!(list.heap_size_of_children(), 4 * node);
+    assert_eq!(list.iter().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+    list.try_push(5).unwrap();
+    assert_eq!(list.heap_size_of_children(), 5 * node);
+}
+
+quickcheck! {
+    fn thin_list_matches_list(ops: Vec<Option<usize>>) -> bool {
+        let mut list = List::new();
+        let mut thin = ThinList::new();
+        for op in ops {
+            let same = match op {
+                Some(elem) => {
+                    list.push(elem);
+                    thin.push(elem);
+                    true
+                }
+              , None => list.pop() == thin.pop()
+            };
+            if !same || list.peek() != thin.peek() { return false }
+        }
+        thin.len() == list.len() && thin.iter().eq(list.iter())
+    }
+
+    fn thin_list_conversions_keep_order(list: List<usize>) -> bool {
+        let elems = list.iter().cloned().collect::<Vec<_>>();
+        let thin = ThinList::from(list);
+        let thin_elems = thin.iter().cloned().collect::<Vec<_>>();
+        let list = List::from(thin.clone());
+        thin_elems == elems &&
+        list.len() == elems.len() &&
+        list.into_iter().eq(elems.into_iter())
+    }
+}
+
+#[test]
+fn thin_list_is_one_word() {
+    use std::mem::size_of;
+    assert_eq!(size_of::<ThinList<u64>>(), size_of::<usize>());
+}
+
+quickcheck! {
+    fn display_round_trips(list: List<i64>) -> bool {
+        list.to_string().parse::<List<i64>>()
+            .map(|parsed| parsed.iter().eq(list.iter()))
+            .unwrap_or(false)
+    }
+}
+
+#[test]
+fn from_str_errors() {
+    use std::num::ParseIntError;
+    use error::ParseListError;
+    let parsed = " [ 1,2 , 3 ] ".parse::<List<u8>>().unwrap();
+    assert_eq!(parsed.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert!("[]".parse::<List<u8>>().unwrap().is_empty());
+    assert_eq!( "1, 2".parse::<List<u8>>().unwrap_err()
+              , ParseListError::Brackets );
+    assert_eq!("[".parse::<List<u8>>().unwrap_err(), ParseListError::Brackets);
+    let err: ParseListError<ParseIntError> =
+        "[1, x]".parse::<List<u8>>().unwrap_err();
+    match err {
+        ParseListError::Elem { index: 1, .. } => {}
+      , err => panic!("unexpected error {:?}", err)
+    }
+    assert!(err.to_string().starts_with("can't parse list element 1: "));
+}
+
+#[test]
+fn reserve_preallocates_pushes() {
+    let mut list = List::new();
+    list.push(0u64);
+    list.reserve(4);
+    let size = list.heap_size_of_children();
+    for i in 1..5 { list.push(i); }
+    assert_eq!(list.heap_size_of_children(), size);
+    // nodes already on the freelist count towards the reservation
+    list.pop();
+    list.reserve(1);
+    assert_eq!(list.heap_size_of_children(), size);
+}
+
+quickcheck! {
+    fn range_matches_skip_take(list: List<u8>, a: usize, b: usize) -> bool {
+        let (a, b) = (a % (list.len() + 1), b % (list.len() + 1));
+        let (start, end) = (a.min(b), a.max(b));
+        let expected = list.iter().skip(start).take(end - start);
+        let range = list.range(start..end);
+        range.len() == end - start && range.eq(expected)
+    }
+}
+
+#[test]
+fn range_mut_edits_only_range() {
+    let mut list = (0..6).rev().collect::<List<_>>();
+    assert_eq!(list.range_mut(2..=3).len(), 2);
+    for elem in list.range_mut(2..=3) { *elem *= 10; }
+    assert_eq!( list.iter().cloned().collect::<Vec<_>>()
+              , vec![0, 1, 20, 30, 4, 5] );
+    assert_eq!(list.range(4..).cloned().collect::<Vec<_>>(), vec![4, 5]);
+    assert_eq!(list.range(..).len(), 6);
+}
+
+#[test]
+#[should_panic]
+fn range_past_end_panics() {
+    let list = (0..3).collect::<List<_>>();
+    list.range(1..4);
+}
+
+quickcheck! {
+    fn splice_matches_vec( list: List<u8>, a: usize, b: usize
+                         , replace_with: Vec<u8>) -> bool {
+        let (a, b) = (a % (list.len() + 1), b % (list.len() + 1));
+        let (start, end) = (a.min(b), a.max(b));
+        let mut vec = list.iter().cloned().collect::<Vec<_>>();
+        let expected = vec.splice(start..end, replace_with.clone())
+                          .collect::<Vec<_>>();
+        let mut list = list;
+        let removed = list.splice(start..end, replace_with);
+        removed.iter().eq(expected.iter()) && list.len() == vec.len() &&
+        list.iter().eq(vec.iter())
+    }
+}
+
+#[test]
+fn splice_reuses_free_nodes() {
+    let mut list = (0..4).rev().collect::<List<u64>>();
+    list.pop();
+    list.pop();
+    let size = list.heap_size_of_children();
+    let removed = list.splice(1..1, vec![7, 8]);
+    assert!(removed.is_empty());
+    assert_eq!(list.heap_size_of_children(), size);
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![2, 7, 8, 3]);
+}
+
+quickcheck! {
+    fn drain_matches_vec(list: List<u8>, a: usize, b: usize, n: usize)
+                         -> bool {
+        let (a, b) = (a % (list.len() + 1), b % (list.len() + 1));
+        let (start, end) = (a.min(b), a.max(b));
+        let mut vec = list.iter().cloned().collect::<Vec<_>>();
+        let expected = vec.drain(start..end).take(n).collect::<Vec<_>>();
+        let mut list = list;
+        let drained = list.drain(start..end).take(n).collect::<Vec<_>>();
+        drained == expected && list.len() == vec.len() &&
+        list.iter().eq(vec.iter())
+    }
+}
+
+#[test]
+fn drain_returns_nodes_to_freelist() {
+    let mut list = (0..6).rev().collect::<List<u64>>();
+    let size = list.heap_size_of_children();
+    {
+        let mut drain = list.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(1));
+    }
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![0, 4, 5]);
+    assert_eq!(list.heap_size_of_children(), size);
+    list.extend(vec![7, 8, 9]);
+    assert_eq!(list.heap_size_of_children(), size);
+}
+
+quickcheck! {
+    fn retain_mut_matches_vec(list: List<u8>) -> bool {
+        let mut vec = list.iter().cloned().collect::<Vec<_>>();
+        let mut list = list;
+        let mut visited = Vec::new();
+        list.retain_mut(|x| { visited.push(*x); *x /= 2; *x % 3 != 0 });
+        let expected = vec.clone();
+        vec.retain_mut(|x| { *x /= 2; *x % 3 != 0 });
+        visited == expected && list.len() == vec.len() &&
+        list.iter().eq(vec.iter())
+    }
+}
+
+#[test]
+fn retain_mut_recycles_nodes() {
+    let mut list = (0..6).rev().collect::<List<u64>>();
+    let size = list.heap_size_of_children();
+    list.retain_mut(|x| *x % 2 == 0);
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![0, 2, 4]);
+    assert_eq!(list.heap_size_of_children(), size);
+}
+
+quickcheck! {
+    fn touch_matches_vec(list: List<u8>, index: usize) -> bool {
+        let mut vec = list.iter().cloned().collect::<Vec<_>>();
+        let index = index % (vec.len() + 1);
+        let mut list = list;
+        let moved = list.touch(index);
+        if index < vec.len() {
+            let elem = vec.remove(index);
+            vec.insert(0, elem);
+        }
+        moved == (index < list.len()) && list.iter().eq(vec.iter())
+    }
+}
+
+#[test]
+fn touch_where_and_evict() {
+    let mut cache = List::new();
+    for key in 0..4 { cache.push(key); }
+    assert_eq!(cache.touch_where(|&key| key == 1), Some(2));
+    assert_eq!(cache.touch_where(|&key| key == 9), None);
+    assert_eq!(cache.iter().cloned().collect::<Vec<_>>(), vec![1, 3, 2, 0]);
+    let size = cache.heap_size_of_children();
+    assert_eq!(cache.pop_back_lru(), Some(0));
+    cache.push(4);
+    assert_eq!(cache.heap_size_of_children(), size);
+    assert_eq!(cache.iter().cloned().collect::<Vec<_>>(), vec![4, 1, 3, 2]);
+    assert_eq!(List::<u8>::new().pop_back_lru(), None);
+}
+
+quickcheck! {
+    fn cursors_merge_pairs(list: List<u8>) -> bool {
+        // add each element to the one mirroring it from the other end
+        let vec = list.iter().cloned().collect::<Vec<_>>();
+        let mut list = list;
+        list.with_cursors(|mut token| {
+            let (mut a, mut b) = match (token.front(), token.back()) {
+                (Some(a), Some(b)) => (a, b)
+              , _ => return
+            };
+            while a < b {
+                let (x, y) = token.get_pair_mut(a, b).unwrap();
+                *x = x.wrapping_add(*y);
+                a = token.next(a).unwrap();
+                b = token.prev(b).unwrap();
+            }
+            assert!(token.get_pair_mut(a, a).is_none());
+        });
+        let n = vec.len();
+        list.iter().enumerate().all(|(i, &x)| {
+            x == if i < n - 1 - i { vec[i].wrapping_add(vec[n - 1 - i]) }
+                 else { vec[i] }
+        })
+    }
+}
+
+quickcheck! {
+    fn clone_from_matches_clone(list: List<String>, source: List<String>)
+                                -> bool {
+        let mut list = list;
+        list.clone_from(&source);
+        list.len() == source.len() && list.iter().eq(source.iter())
+    }
+}
+
+#[test]
+fn clone_from_reuses_nodes() {
+    let mut list = (0..8).collect::<List<u64>>();
+    let size = list.heap_size_of_children();
+    list.clone_from(&(0..3).collect());
+    assert_eq!(list.heap_size_of_children(), size);
+    list.clone_from(&(10..16).collect());
+    assert_eq!(list.heap_size_of_children(), size);
+    assert!(list.iter().cloned().eq((10..16).rev()));
+}
+
+quickcheck! {
+    fn iterator_overrides_match_vec(list: List<usize>, n: usize, m: usize)
+                                    -> bool {
+        let mut list = list;
+        let elems = list.iter().cloned().collect::<Vec<_>>();
+        let (n, m) = (n % (elems.len() + 2), m % 3);
+        let expected = {
+            let mut iter = elems.iter().cloned();
+            (iter.nth(n), iter.nth(m), iter.len())
+        };
+        let by_ref = {
+            let mut iter = list.iter().cloned();
+            (iter.nth(n), iter.nth(m), iter.len())
+        };
+        let by_mut = {
+            let mut iter = list.iter_mut().map(|elem| *elem);
+            (iter.nth(n), iter.nth(m), iter.len())
+        };
+        let mut owned = list.clone().into_iter();
+        let owned = (owned.nth(n), owned.nth(m), owned.len());
+        by_ref == expected && by_mut == expected && owned == expected &&
+        list.iter().last() == elems.last() &&
+        list.iter_mut().last().cloned() == elems.last().cloned() &&
+        list.clone().into_iter().last() == elems.last().cloned() &&
+        list.iter().count() == elems.len() &&
+        list.iter().skip(1).count() == elems.len().saturating_sub(1)
+    }
+}
+
+#[test]
+fn range_iterators_stop_at_the_range() {
+    let list = (0..10).rev().collect::<List<_>>();
+    assert_eq!(list.range(2..5).last(), Some(&4));
+    assert_eq!(list.range(2..5).nth(3), None);
+    assert_eq!(list.range(2..5).count(), 3);
+}
+
+#[test]
+fn walk_stops_at_break() {
+    use std::ops::ControlFlow;
+    let mut list = (0..6).rev().collect::<List<_>>();
+    let mut seen = Vec::new();
+    let found = list.walk(|&elem| {
+        seen.push(elem);
+        if elem == 3 { ControlFlow::Break(elem * 10) }
+        else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(found, ControlFlow::Break(30));
+    assert_eq!(seen, vec![0, 1, 2, 3]);
+    let first_odd = list.walk_mut(|elem| {
+        if *elem % 2 == 1 { ControlFlow::Break(elem) }
+        else { *elem += 10; ControlFlow::Continue(()) }
+    });
+    if let ControlFlow::Break(elem) = first_odd { *elem = 100; }
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(),
+               vec![10, 100, 2, 3, 4, 5]);
+    assert_eq!(list.walk(|_| ControlFlow::<()>::Continue(())),
+               ControlFlow::Continue(()));
+}
+
+#[test]
+fn try_range_methods_report_bad_ranges() {
+    use error::RangeError;
+    let mut list = (0..5).rev().collect::<List<_>>();
+    let err = |start, end| RangeError { start, end, lower: 0, upper: 5 };
+    assert_eq!(list.try_range(2..6).err(), Some(err(2, 6)));
+    let (start, end) = (4, 3);
+    assert_eq!(list.try_range_mut(start..end).err(), Some(err(4, 3)));
+    assert_eq!(list.try_drain(..=usize::MAX).err(),
+               Some(err(0, isize::MAX)));
+    let mut consumed = false;
+    let replacement = (0..2).inspect(|_| consumed = true);
+    assert_eq!(list.try_splice(3..9, replacement).err(), Some(err(3, 9)));
+    assert!(!consumed);
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(list.try_range(1..3).unwrap().collect::<Vec<_>>(), vec![&1, &2]);
+    let removed = list.try_splice(3.., vec![7]).unwrap();
+    assert_eq!(removed.len(), 2);
+    assert_eq!(list.try_drain(..1).unwrap().collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn from_fn_calls_in_index_order() {
+    let mut calls = Vec::new();
+    let list = List::from_fn(5, |i| { calls.push(i); i * 10 });
+    assert_eq!(calls, vec![0, 1, 2, 3, 4]);
+    assert_eq!(list.len(), 5);
+    assert!(list.iter().cloned().eq(vec![0, 10, 20, 30, 40]));
+    assert!(List::from_fn(0, |_| -> u8 { unreachable!() }).is_empty());
+}
+
+#[test]
+fn from_elem_moves_value_into_last_node() {
+    use std::rc::Rc;
+    let token = Rc::new(());
+    let list = List::from_elem(3, token.clone());
+    assert_eq!(Rc::strong_count(&token), 4);
+    assert!(list.iter().all(|elem| Rc::ptr_eq(elem, &token)));
+    drop(List::from_elem(0, token.clone()));
+    assert_eq!(Rc::strong_count(&token), 4);
+    drop(list);
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[test]
+fn try_map_stops_at_first_error() {
+    let list = list!["1", "2", "x", "y"];
+    let mut calls = 0;
+    let result = list.try_map(|s| { calls += 1; s.parse::<u8>() });
+    assert!(result.is_err());
+    assert_eq!(calls, 3);
+    let list = list!["1", "2", "3"];
+    let mapped = list.try_map(|s| s.parse::<u8>()).unwrap();
+    assert_eq!(mapped.len(), 3);
+    assert!(mapped.iter().cloned().eq(vec![1, 2, 3]));
+}
+
+quickcheck! {
+    fn split_into_near_equal_parts(list: List<usize>, n: usize) -> bool {
+        let n = n % 8 + 1;
+        let elems = list.iter().cloned().collect::<Vec<_>>();
+        let parts = list.split_into(n);
+        let lens = parts.iter().map(List::len).collect::<Vec<_>>();
+        parts.len() == n &&
+        lens.windows(2).all(|w| w[0] >= w[1] && w[0] - w[1] <= 1) &&
+        parts.into_iter().flat_map(List::into_iter).eq(elems)
+    }
+}
+
+#[test]
+#[should_panic(expected = "0 parts")]
+fn split_into_zero_parts_panics() {
+    list![1].split_into(0);
+}
+
+quickcheck! {
+    fn remove_min_sorts_like_vec(list: List<i8>) -> bool {
+        let mut list = list;
+        let mut elems = list.iter().cloned().collect::<Vec<_>>();
+        elems.sort();
+        let mut removed = Vec::new();
+        while let Some(min) = list.remove_min() { removed.push(min) }
+        removed == elems
+    }
+
+    fn remove_by_key_matches_iterator(list: List<(i8, u8)>) -> bool {
+        let mut list = list;
+        let max = list.iter().max_by_key(|pair| pair.0).cloned();
+        let removed = list.remove_max_by_key(|pair| pair.0);
+        let min = list.iter().min_by_key(|pair| pair.0).cloned();
+        removed == max && list.remove_min_by_key(|pair| pair.0) == min
+    }
+}
+
+#[test]
+fn remove_extremes_reuse_nodes() {
+    let mut list = list![3, 1, 4, 1, 5];
+    assert_eq!(list.remove_max(), Some(5));
+    assert_eq!(list.remove_min(), Some(1));
+    assert!(list.iter().cloned().eq(vec![3, 4, 1]));
+    let size = list.heap_size_of_children();
+    list.push(9);
+    assert_eq!(list.heap_size_of_children(), size);
+    assert_eq!(List::<u8>::new().remove_min(), None);
+}
@@ -0,0 +1,75 @@
+/// Create a [`List`](struct.List.html) containing the arguments, in order.
+///
+/// The first argument ends up at the top of the list.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate an_zipper;
+/// # fn main() {
+/// let list = list![1, 2, 3];
+/// assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! list {
+    ($($elem:expr),* $(,)*) => {
+        // collecting pushes each element in turn, leaving the last one at
+        // the top, so the elements are collected from the last.
+        ::std::iter::IntoIterator::into_iter([$($elem),*]).rev()
+            .collect::<$crate::List<_>>()
+    };
+}
+
+/// Create a [`ZipList`](struct.ZipList.html) containing the arguments, in
+/// order, with the cursor at the `;`.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate an_zipper;
+/// # fn main() {
+/// let zipper = zipper![1, 2 ; 3, 4];
+/// assert_eq!(zipper.peek_left(), Some(&2));
+/// assert_eq!(zipper.peek_right(), Some(&3));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! zipper {
+    () => { $crate::ZipList::new() };
+    ($($left:expr),* ; $($right:expr),* $(,)*) => {{
+        #[allow(unused_mut)]
+        let mut zipper = $crate::ZipList::new();
+        $( zipper.push_left($left); )*
+        #[allow(unused_mut)]
+        let mut right = 0;
+        $( zipper.push_left($right); right += 1; )*
+        zipper.seek_left(right);
+        zipper
+    }};
+}
+
+/// Add to a list's operation counts, if the `stats` feature is enabled.
+///
+/// The amounts aren't evaluated at all when it isn't.
+macro_rules! count {
+    ($list:expr, $($field:ident += $n:expr),+) => {
+        #[cfg(feature = "stats")]
+        { $( $list.stats.$field += $n; )+ }
+    };
+}
+
+/// Emit a trace-level `tracing` event, if the `tracing` feature is enabled.
+macro_rules! trace_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!($($arg)+);
+    };
+}
+
+/// Enter a trace-level `tracing` span until the end of the enclosing block,
+/// if the `tracing` feature is enabled.
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::trace_span!($($arg)+).entered();
+    };
+}
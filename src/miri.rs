@@ -0,0 +1,173 @@
+//! Tests for the crate's unsafe code, small enough to run under [Miri].
+//!
+//! `List` and `ZipList` own their nodes through `Box`es, so the only unsafe
+//! code in them is the freelist: nodes are reinterpreted as holding
+//! uninitialized elements when they're freed, and back again when they're
+//! reused. With the `zeroize` feature, nodes dropped along with a list are
+//! reinterpreted the same way, to be wiped. These tests drive every path through that code (and through the
+//! lock-free stack and the inline arrays of `ArrayStack` and
+//! `ArrayZipList`) with elements that count their drops, so that Miri
+//! catches any use of a freed element, any element dropped twice, and any
+//! element or node that's leaked.
+//!
+//! Run them with `cargo +nightly miri test --features crossbeam-epoch
+//! miri::`. `--all-features` won't do, since it turns on the old `clippy`
+//! plugin, which no longer builds. The quickcheck tests elsewhere cover the
+//! same code, but take too long to interpret.
+//!
+//! [Miri]: https://github.com/rust-lang/miri
+use std::rc::Rc;
+
+use ::{ArrayStack, ArrayZipList, List, Stack, ThinList, ZipList};
+
+/// Returns `n` elements which each hold a reference to `token`, so that
+/// `Rc::strong_count(token)` counts how many haven't been dropped.
+fn tracked(token: &Rc<()>, n: usize) -> Vec<Rc<()>> {
+    (0..n).map(|_| token.clone()).collect()
+}
+
+fn live(token: &Rc<()>) -> usize { Rc::strong_count(token) - 1 }
+
+#[test]
+fn popped_nodes_are_reused() {
+    let token = Rc::new(());
+    {
+        let mut list = tracked(&token, 4).into_iter().collect::<List<_>>();
+        drop(list.pop());
+        drop(list.pop());
+        assert_eq!(live(&token), 2);
+        list.push(token.clone()).push(token.clone()).push(token.clone());
+        assert_eq!(live(&token), 5);
+        assert!(list.peek().is_some());
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn reserved_nodes_are_written_before_reading() {
+    let token = Rc::new(());
+    {
+        let mut list = List::new();
+        list.try_reserve_nodes(3).unwrap();
+        list.reserve(5);
+        list.extend(tracked(&token, 4));
+        assert_eq!(list.len(), 4);
+        // one reserved node is still free, and holds no element to drop
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn shrinking_frees_nodes_without_elements() {
+    let token = Rc::new(());
+    let mut list = tracked(&token, 3).into_iter().collect::<List<_>>();
+    while list.pop().is_some() {}
+    list.shrink_to_fit();
+    assert_eq!(list.heap_size_of_children(), 0);
+    list.push(token.clone());
+    assert_eq!(live(&token), 1);
+    drop(list);
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn bulk_removals_recycle_once() {
+    let token = Rc::new(());
+    {
+        let mut list = tracked(&token, 10).into_iter().collect::<List<_>>();
+        drop(list.splice(1..3, tracked(&token, 1)));
+        assert_eq!(live(&token), 9);
+        drop(list.drain(2..4).next());
+        assert_eq!(live(&token), 7);
+        let mut keep = false;
+        list.retain_mut(|_| { keep = !keep; keep });
+        assert_eq!(live(&token), 4);
+        assert!(list.touch(3));
+        drop(list.pop_back_lru());
+        assert_eq!(live(&token), 3);
+        list.extend(tracked(&token, 3));
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn clones_and_conversions_own_their_elements() {
+    let token = Rc::new(());
+    {
+        let mut list = tracked(&token, 3).into_iter().collect::<List<_>>();
+        drop(list.pop());
+        let clone = list.clone();
+        assert_eq!(live(&token), 4);
+        let thin = ThinList::from(list);
+        let back = List::from(thin);
+        assert_eq!(back.len() + clone.len(), live(&token));
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn zipper_moves_share_nodes_between_sides() {
+    let token = Rc::new(());
+    {
+        let mut zipper = ZipList::from_iter_with_cursor(tracked(&token, 6), 3);
+        drop(zipper.pop_left());
+        drop(zipper.pop_right());
+        zipper.push_left(token.clone()).push_right(token.clone());
+        zipper.seek_left(2);
+        zipper.seek_right(4);
+        zipper.truncate(3);
+        zipper.resize(5, token.clone());
+        assert_eq!(live(&token), 5);
+        let frozen = zipper.freeze();
+        let zipper = frozen.clone().thaw();
+        assert_eq!(live(&token), 10);
+        drop(frozen);
+        assert_eq!(live(&token), zipper.len());
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[test]
+fn inline_arrays_move_elements_across_the_gap() {
+    let token = Rc::new(());
+    {
+        let mut stack = ArrayStack::<_, 3>::new();
+        for elem in tracked(&token, 3) { stack.push(elem); }
+        drop(stack.pop());
+        let _clone = stack.clone();
+
+        let mut zipper = ArrayZipList::<_, 4>::new();
+        for elem in tracked(&token, 4) { zipper.push_right(elem); }
+        zipper.seek_right(3);
+        zipper.seek_left(2);
+        drop(zipper.pop_left());
+        zipper.push_right(token.clone());
+        assert!(zipper.peek_right_mut().is_some());
+        let _clone = zipper.clone();
+        assert_eq!(live(&token), 12);
+    }
+    assert_eq!(live(&token), 0);
+}
+
+#[cfg(feature = "crossbeam-epoch")]
+#[test]
+fn lock_free_stack_moves_elements_out_once() {
+    use std::sync::Arc;
+    use std::thread;
+    use sync::Stack as AtomicStack;
+
+    let stack = Arc::new(AtomicStack::new());
+    let pushers = (0..2).map(|t| {
+        let stack = stack.clone();
+        thread::spawn(move || {
+            for i in 0..4 { stack.push(Box::new(t * 4 + i)); }
+        })
+    }).collect::<Vec<_>>();
+    for pusher in pushers { pusher.join().unwrap(); }
+    let mut popped = Vec::new();
+    while let Some(elem) = stack.pop() { popped.push(*elem); }
+    popped.sort();
+    assert_eq!(popped, (0..8).collect::<Vec<_>>());
+    stack.push(Box::new(8));
+    // the last element is dropped along with the stack
+}
@@ -0,0 +1,435 @@
+//! A reference model of `ZipList`, for differential testing.
+//!
+//! This module is only built with the `test-support` feature. A [`Model`]
+//! is a `VecDeque` and a cursor index, which is slow but obviously correct.
+//! [`check`] applies a sequence of [`Op`]s to both a `ZipList` and a
+//! `Model`, and compares what each operation returned and the contents and
+//! cursor position of both after every step, returning the first
+//! [`Mismatch`] if there is one.
+//!
+//! Operation sequences can come from anywhere, such as a property testing
+//! library or a fuzzer; [`random_ops`] generates them from a seed.
+//!
+//! ```
+//! # use an_zipper::model::{check, random_ops};
+//! for seed in 0..16 {
+//!     check(random_ops(seed, 100)).unwrap();
+//! }
+//! ```
+//!
+//! [`Model`]: struct.Model.html
+//! [`check`]: fn.check.html
+//! [`Op`]: enum.Op.html
+//! [`Mismatch`]: struct.Mismatch.html
+//! [`random_ops`]: fn.random_ops.html
+use std::collections::VecDeque;
+use std::fmt;
+
+use super::ZipList;
+
+#[cfg(test)] mod test;
+
+/// An operation on a zipper.
+///
+/// Each variant calls the `ZipList` method of the same name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op<T> {
+    /// `push_left(elem)`
+    PushLeft(T)
+  , /// `push_right(elem)`
+    PushRight(T)
+  , /// `pop_left()`
+    PopLeft
+  , /// `pop_right()`
+    PopRight
+  , /// `peek_left()`
+    PeekLeft
+  , /// `peek_right()`
+    PeekRight
+  , /// `move_left()`
+    MoveLeft
+  , /// `move_right()`
+    MoveRight
+  , /// `seek_left(n)`
+    SeekLeft(usize)
+  , /// `seek_right(n)`
+    SeekRight(usize)
+  , /// `try_seek_left(n)`, with the outcome `Moved(true)` if it succeeds
+    TrySeekLeft(usize)
+  , /// `try_seek_right(n)`, with the outcome `Moved(true)` if it succeeds
+    TrySeekRight(usize)
+  , /// `absorb(other)`, where `other` holds `elems` in order, with its
+    /// cursor before the element at index `cursor`, or at its right end
+    Absorb(Vec<T>, usize)
+  , /// `paste(list)`, where `list` holds `elems` in order
+    Paste(Vec<T>)
+  , /// `cut_left(n)`, with the elements cut as the outcome
+    CutLeft(usize)
+  , /// `cut_right(n)`, with the elements cut as the outcome
+    CutRight(usize)
+  , /// `take_left(n)`, with the zipper taken as the outcome
+    TakeLeft(usize)
+  , /// `take_right(n)`, with the zipper taken as the outcome
+    TakeRight(usize)
+  , /// `retain_mut(keep)`, where `keep` keeps the `i`th element it's called
+    /// on if bit `i % 64` of the mask is set
+    Retain(u64)
+  , /// `commit_left()`
+    CommitLeft
+}
+
+/// What an operation returned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// The operation doesn't return anything.
+    Nothing
+  , /// An element that was popped or peeked, if there was one.
+    Elem(Option<T>)
+  , /// Whether the cursor could be moved.
+    Moved(bool)
+  , /// How far the cursor was moved.
+    Seeked(usize)
+  , /// The elements that were removed, in order.
+    Elems(Vec<T>)
+  , /// The elements, in order, and cursor position of a zipper that was
+    /// split off.
+    Zipper(Vec<T>, usize)
+  , /// How many elements were dropped.
+    Dropped(usize)
+}
+
+/// A `VecDeque` with a cursor, which behaves the way a `ZipList` should.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Model<T> { elems: VecDeque<T>
+                    , cursor: usize
+                    }
+
+impl<T> Default for Model<T> {
+    fn default() -> Self { Model::new() }
+}
+
+impl<T> Model<T> {
+    /// Create an empty model.
+    pub fn new() -> Self {
+        Model { elems: VecDeque::new(), cursor: 0 }
+    }
+}
+
+impl<T> Model<T>
+where T: Clone {
+    /// Create a model with the same elements and cursor as `zipper`.
+    pub fn from_zipper(zipper: &ZipList<T>) -> Self {
+        let (elems, cursor) = state(zipper);
+        Model { elems: elems.into(), cursor }
+    }
+
+    /// Returns the model's elements, from left to right, and the number of
+    /// elements to the left of its cursor.
+    pub fn state(&self) -> (Vec<T>, usize) {
+        (self.elems.iter().cloned().collect(), self.cursor)
+    }
+
+    /// Apply `op` to the model, returning what it returned.
+    pub fn apply(&mut self, op: &Op<T>) -> Outcome<T> {
+        let len = self.elems.len();
+        match *op {
+            Op::PushLeft(ref elem) => {
+                self.elems.insert(self.cursor, elem.clone());
+                self.cursor += 1;
+                Outcome::Nothing
+            }
+          , Op::PushRight(ref elem) => {
+                self.elems.insert(self.cursor, elem.clone());
+                Outcome::Nothing
+            }
+          , Op::PopLeft if self.cursor == 0 => Outcome::Elem(None)
+          , Op::PopLeft => {
+                self.cursor -= 1;
+                Outcome::Elem(self.elems.remove(self.cursor))
+            }
+          , Op::PopRight => Outcome::Elem(self.elems.remove(self.cursor))
+          , Op::PeekLeft => Outcome::Elem(
+                self.cursor.checked_sub(1)
+                    .and_then(|i| self.elems.get(i))
+                    .cloned())
+          , Op::PeekRight => Outcome::Elem(self.elems.get(self.cursor).cloned())
+          , Op::MoveLeft => Outcome::Moved(self.seek_by(-1) == 1)
+          , Op::MoveRight => Outcome::Moved(self.seek_by(1) == 1)
+          , Op::SeekLeft(n) => Outcome::Seeked(self.seek_by(-(n as isize)))
+          , Op::SeekRight(n) => Outcome::Seeked(self.seek_by(n as isize))
+          , Op::TrySeekLeft(n) => Outcome::Moved(
+                n <= self.cursor && self.seek_by(-(n as isize)) == n)
+          , Op::TrySeekRight(n) => Outcome::Moved(
+                n <= len - self.cursor && self.seek_by(n as isize) == n)
+          , Op::Absorb(ref elems, cursor) => {
+                for (i, elem) in elems.iter().enumerate() {
+                    self.elems.insert(self.cursor + i, elem.clone());
+                }
+                self.cursor += cursor.min(elems.len());
+                Outcome::Nothing
+            }
+          , Op::Paste(ref elems) => {
+                for elem in elems {
+                    self.elems.insert(self.cursor, elem.clone());
+                    self.cursor += 1;
+                }
+                Outcome::Nothing
+            }
+          , Op::CutLeft(n) => Outcome::Elems(self.remove_left(n))
+          , Op::CutRight(n) => Outcome::Elems(self.remove_right(n))
+          , Op::TakeLeft(n) => {
+                let taken = self.remove_left(n);
+                let cursor = taken.len();
+                Outcome::Zipper(taken, cursor)
+            }
+          , Op::TakeRight(n) => Outcome::Zipper(self.remove_right(n), 0)
+          , Op::Retain(mask) => {
+                self.cursor = (0..self.cursor).filter(|&i| kept(mask, i))
+                                              .count();
+                let mut i = 0;
+                self.elems.retain(|_| { i += 1; kept(mask, i - 1) });
+                Outcome::Nothing
+            }
+          , Op::CommitLeft => {
+                let dropped = self.cursor;
+                self.elems.drain(..dropped);
+                self.cursor = 0;
+                Outcome::Dropped(dropped)
+            }
+        }
+    }
+
+    /// Remove up to `n` elements to the left of the cursor, returning them
+    /// in order.
+    fn remove_left(&mut self, n: usize) -> Vec<T> {
+        let start = self.cursor - n.min(self.cursor);
+        let removed = self.elems.drain(start..self.cursor).collect();
+        self.cursor = start;
+        removed
+    }
+
+    /// Remove up to `n` elements to the right of the cursor, returning them
+    /// in order.
+    fn remove_right(&mut self, n: usize) -> Vec<T> {
+        let end = self.cursor + n.min(self.elems.len() - self.cursor);
+        self.elems.drain(self.cursor..end).collect()
+    }
+
+    /// Move the cursor by `n`, clamped to the ends, returning how far it
+    /// moved.
+    fn seek_by(&mut self, n: isize) -> usize {
+        let target = (self.cursor as isize + n)
+                         .max(0)
+                         .min(self.elems.len() as isize) as usize;
+        let moved = self.cursor.max(target) - self.cursor.min(target);
+        self.cursor = target;
+        moved
+    }
+}
+
+/// Returns whether `Op::Retain(mask)` keeps the `i`th element it's asked
+/// about.
+fn kept(mask: u64, i: usize) -> bool { (mask >> (i % 64)) & 1 == 1 }
+
+/// Apply `op` to `zipper`, returning what it returned.
+pub fn apply<T>(zipper: &mut ZipList<T>, op: &Op<T>) -> Outcome<T>
+where T: Clone {
+    match *op {
+        Op::PushLeft(ref elem) => {
+            zipper.push_left(elem.clone());
+            Outcome::Nothing
+        }
+      , Op::PushRight(ref elem) => {
+            zipper.push_right(elem.clone());
+            Outcome::Nothing
+        }
+      , Op::PopLeft => Outcome::Elem(zipper.pop_left())
+      , Op::PopRight => Outcome::Elem(zipper.pop_right())
+      , Op::PeekLeft => Outcome::Elem(zipper.peek_left().cloned())
+      , Op::PeekRight => Outcome::Elem(zipper.peek_right().cloned())
+      , Op::MoveLeft => Outcome::Moved(zipper.move_left())
+      , Op::MoveRight => Outcome::Moved(zipper.move_right())
+      , Op::SeekLeft(n) => Outcome::Seeked(zipper.seek_left(n))
+      , Op::SeekRight(n) => Outcome::Seeked(zipper.seek_right(n))
+      , Op::TrySeekLeft(n) => Outcome::Moved(zipper.try_seek_left(n).is_ok())
+      , Op::TrySeekRight(n) =>
+            Outcome::Moved(zipper.try_seek_right(n).is_ok())
+      , Op::Absorb(ref elems, cursor) => {
+            let elems = elems.iter().cloned();
+            zipper.absorb(ZipList::from_iter_with_cursor(elems, cursor));
+            Outcome::Nothing
+        }
+      , Op::Paste(ref elems) => {
+            // collecting a list pushes each element in turn, so the last one
+            // has to go first to end up at the back.
+            zipper.paste(elems.iter().rev().cloned().collect());
+            Outcome::Nothing
+        }
+      , Op::CutLeft(n) =>
+            Outcome::Elems(zipper.cut_left(n).into_iter().collect())
+      , Op::CutRight(n) =>
+            Outcome::Elems(zipper.cut_right(n).into_iter().collect())
+      , Op::TakeLeft(n) => {
+            let (elems, cursor) = state(&zipper.take_left(n));
+            Outcome::Zipper(elems, cursor)
+        }
+      , Op::TakeRight(n) => {
+            let (elems, cursor) = state(&zipper.take_right(n));
+            Outcome::Zipper(elems, cursor)
+        }
+      , Op::Retain(mask) => {
+            let mut i = 0;
+            zipper.retain_mut(|_| { i += 1; kept(mask, i - 1) });
+            Outcome::Nothing
+        }
+      , Op::CommitLeft => Outcome::Dropped(zipper.commit_left())
+    }
+}
+
+/// Returns the elements of `zipper`, from left to right, and the number of
+/// elements to the left of its cursor.
+pub fn state<T>(zipper: &ZipList<T>) -> (Vec<T>, usize)
+where T: Clone {
+    let mut elems = zipper.left_iter().cloned().collect::<Vec<_>>();
+    elems.reverse();
+    let cursor = elems.len();
+    elems.extend(zipper.right_iter().cloned());
+    (elems, cursor)
+}
+
+/// The first step at which a zipper and its model disagreed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch<T> { /// The index of the operation.
+                         pub step: usize
+                       , /// The operation.
+                         pub op: Op<T>
+                       , /// What the model returned.
+                         pub expected: Outcome<T>
+                       , /// What the zipper returned.
+                         pub actual: Outcome<T>
+                       , /// The model's elements and cursor afterwards.
+                         pub expected_state: (Vec<T>, usize)
+                       , /// The zipper's elements and cursor afterwards.
+                         pub actual_state: (Vec<T>, usize)
+                       }
+
+impl<T> fmt::Display for Mismatch<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!( f, "step {} ({:?}): expected {:?} leaving {:?}, \
+                    got {:?} leaving {:?}"
+              , self.step, self.op
+              , self.expected, self.expected_state
+              , self.actual, self.actual_state )
+    }
+}
+
+/// Apply `ops` to an empty `ZipList` and an empty `Model`, comparing them
+/// after every step.
+pub fn check<T, I>(ops: I) -> Result<(), Box<Mismatch<T>>>
+where T: Clone + PartialEq
+    , I: IntoIterator<Item=Op<T>> {
+    check_from(ZipList::new(), ops)
+}
+
+/// Apply `ops` to `zipper` and a `Model` of it, comparing them after every
+/// step.
+pub fn check_from<T, I>(mut zipper: ZipList<T>, ops: I)
+                        -> Result<(), Box<Mismatch<T>>>
+where T: Clone + PartialEq
+    , I: IntoIterator<Item=Op<T>> {
+    let mut model = Model::from_zipper(&zipper);
+    for (step, op) in ops.into_iter().enumerate() {
+        let expected = model.apply(&op);
+        let actual = apply(&mut zipper, &op);
+        let expected_state = model.state();
+        let actual_state = state(&zipper);
+        if expected != actual || expected_state != actual_state
+            || zipper.len() != expected_state.0.len() {
+            return Err(Box::new(Mismatch { step, op, expected, actual
+                                         , expected_state, actual_state }))
+        }
+    }
+    Ok(())
+}
+
+/// Generate `len` random operations from `seed`.
+///
+/// The same seed always generates the same operations, so a failing
+/// sequence can be reproduced from its seed. Pushes and splices in are
+/// weighted so that the zipper tends to grow, and seeks and splices out are
+/// kept short enough to usually stay in bounds.
+pub fn random_ops(seed: u64, len: usize) -> Vec<Op<u32>> {
+    // xorshift64*, which is plenty for picking operations
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    let mut next = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    };
+    (0..len).map(|_| {
+        let n = next();
+        let arg = (n >> 32) as u32;
+        let dist = (arg % 8) as usize;
+        let run = || (0..arg % 5).map(|i| arg.wrapping_add(i)).collect();
+        match n % 22 {
+            0 | 1 => Op::PushLeft(arg)
+          , 2 | 3 => Op::PushRight(arg)
+          , 4 => Op::PopLeft
+          , 5 => Op::PopRight
+          , 6 => Op::PeekLeft
+          , 7 => Op::PeekRight
+          , 8 => Op::MoveLeft
+          , 9 => Op::MoveRight
+          , 10 => Op::SeekLeft(dist)
+          , 11 => Op::SeekRight(dist)
+          , 12 => Op::TrySeekLeft(dist)
+          , 13 => Op::TrySeekRight(dist)
+          , 14 => Op::Absorb(run(), dist)
+          , 15 => Op::Paste(run())
+          , 16 => Op::CutLeft(dist)
+          , 17 => Op::CutRight(dist)
+          , 18 => Op::TakeLeft(dist)
+          , 19 => Op::TakeRight(dist)
+          , 20 => Op::Retain(next())
+          , _ => Op::CommitLeft
+        }
+    }).collect()
+}
+
+/// Decode a tape of operations from `bytes`, such as a fuzzer's input.
+///
+/// Each operation is read from two bytes: one picking the operation, and
+/// one giving the element to push, the distance to seek or splice, or the
+/// elements to splice in, which are a run counting up from it. A trailing
+/// odd byte is ignored. Every byte string decodes to some sequence, so
+/// fuzzers never waste inputs on ones that fail to decode.
+pub fn decode_ops(bytes: &[u8]) -> Vec<Op<u8>> {
+    bytes.chunks_exact(2).map(|op| {
+        let (arg, dist) = (op[1], usize::from(op[1] % 16));
+        let run = || (0..arg % 5).map(|i| arg.wrapping_add(i)).collect();
+        match op[0] % 20 {
+            0 => Op::PushLeft(arg)
+          , 1 => Op::PushRight(arg)
+          , 2 => Op::PopLeft
+          , 3 => Op::PopRight
+          , 4 => Op::PeekLeft
+          , 5 => Op::PeekRight
+          , 6 => Op::MoveLeft
+          , 7 => Op::MoveRight
+          , 8 => Op::SeekLeft(dist)
+          , 9 => Op::SeekRight(dist)
+          , 10 => Op::TrySeekLeft(dist)
+          , 11 => Op::TrySeekRight(dist)
+          , 12 => Op::Absorb(run(), usize::from(arg >> 6))
+          , 13 => Op::Paste(run())
+          , 14 => Op::CutLeft(dist)
+          , 15 => Op::CutRight(dist)
+          , 16 => Op::TakeLeft(dist)
+          , 17 => Op::TakeRight(dist)
+          , 18 => Op::Retain(u64::from(arg) * 0x0101_0101_0101_0101)
+          , _ => Op::CommitLeft
+        }
+    }).collect()
+}
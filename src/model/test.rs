@@ -0,0 +1,92 @@
+use quickcheck::{Arbitrary, Gen};
+
+use ::ZipList;
+use super::*;
+
+impl<T> Arbitrary for Op<T>
+where T: Arbitrary {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let dist = usize::arbitrary(g) % 8;
+        let mut run = Vec::<T>::arbitrary(g);
+        run.truncate(dist);
+        match g.gen_range(0, 20) {
+            0 => Op::PushLeft(T::arbitrary(g))
+          , 1 => Op::PushRight(T::arbitrary(g))
+          , 2 => Op::PopLeft
+          , 3 => Op::PopRight
+          , 4 => Op::PeekLeft
+          , 5 => Op::PeekRight
+          , 6 => Op::MoveLeft
+          , 7 => Op::MoveRight
+          , 8 => Op::SeekLeft(dist)
+          , 9 => Op::SeekRight(dist)
+          , 10 => Op::TrySeekLeft(dist)
+          , 11 => Op::TrySeekRight(dist)
+          , 12 => Op::Absorb(run, usize::arbitrary(g) % 8)
+          , 13 => Op::Paste(run)
+          , 14 => Op::CutLeft(dist)
+          , 15 => Op::CutRight(dist)
+          , 16 => Op::TakeLeft(dist)
+          , 17 => Op::TakeRight(dist)
+          , 18 => Op::Retain(u64::arbitrary(g))
+          , _ => Op::CommitLeft
+        }
+    }
+}
+
+quickcheck! {
+    fn zipper_matches_model(zipper: ZipList<u8>, ops: Vec<Op<u8>>) -> bool {
+        check_from(zipper, ops).is_ok()
+    }
+}
+
+#[test]
+fn random_ops_match_model() {
+    for seed in 0..64 {
+        if let Err(mismatch) = check(random_ops(seed, 500)) {
+            panic!("seed {}: {}", seed, mismatch);
+        }
+    }
+}
+
+#[test]
+fn random_ops_are_reproducible() {
+    assert_eq!(random_ops(7, 50), random_ops(7, 50));
+    assert!(random_ops(7, 50) != random_ops(8, 50));
+}
+
+#[test]
+fn model_clamps_seeks() {
+    let mut model = Model::new();
+    model.apply(&Op::PushLeft(1));
+    model.apply(&Op::PushRight(2));
+    assert_eq!(model.apply(&Op::SeekLeft(5)), Outcome::Seeked(1));
+    assert_eq!(model.apply(&Op::TrySeekRight(3)), Outcome::Moved(false));
+    assert_eq!(model.apply(&Op::PopRight), Outcome::Elem(Some(1)));
+    assert_eq!(model.state(), (vec![2], 0));
+}
+
+#[test]
+fn model_splices() {
+    let mut model = Model::new();
+    model.apply(&Op::Absorb(vec![1, 2, 3], 2));
+    model.apply(&Op::Paste(vec![4, 5]));
+    assert_eq!(model.state(), (vec![1, 2, 4, 5, 3], 4));
+    assert_eq!(model.apply(&Op::CutLeft(3)), Outcome::Elems(vec![2, 4, 5]));
+    assert_eq!(model.apply(&Op::TakeRight(5)), Outcome::Zipper(vec![3], 0));
+    model.apply(&Op::Paste(vec![6, 7, 8]));
+    model.apply(&Op::Retain(0b1011));
+    assert_eq!(model.state(), (vec![1, 6, 8], 3));
+    assert_eq!(model.apply(&Op::TakeLeft(1)), Outcome::Zipper(vec![8], 1));
+    assert_eq!(model.apply(&Op::CommitLeft), Outcome::Dropped(2));
+    assert_eq!(model.state(), (vec![], 0));
+}
+
+#[test]
+fn decode_ops_reads_pairs() {
+    assert_eq!( decode_ops(&[0, 7, 21, 20, 11, 3, 9])
+              , vec![Op::PushLeft(7), Op::PushRight(20), Op::TrySeekRight(3)] );
+    assert_eq!( decode_ops(&[12, 66, 19, 0])
+              , vec![Op::Absorb(vec![66], 1), Op::CommitLeft] );
+    assert!(decode_ops(&[]).is_empty());
+}
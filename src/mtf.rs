@@ -0,0 +1,175 @@
+use std::fmt;
+use std::iter;
+
+use super::{List, Stack, list};
+
+/// A self-organizing list, which moves each element it finds to the front,
+/// so that frequently used elements stay quick to find.
+///
+/// This is the classic move-to-front heuristic, as used by move-to-front
+/// coding and small caches of hot items. Found nodes are relinked at the
+/// front rather than moved, so a lookup never allocates.
+///
+/// ```
+/// # use an_zipper::MoveToFrontList;
+/// let mut symbols = "abc".chars().rev().collect::<MoveToFrontList<_>>();
+/// let codes = "bbca".chars()
+///     .map(|c| symbols.position(&c).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(codes, vec![1, 0, 2, 2]);
+/// assert_eq!(symbols.iter().collect::<String>(), "acb");
+/// ```
+#[derive(Clone)]
+pub struct MoveToFrontList<T> { list: List<T> }
+
+impl<T> MoveToFrontList<T> {
+    /// Create a new empty `MoveToFrontList`.
+    pub const fn new() -> Self { MoveToFrontList { list: List::new() } }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn len(&self) -> usize { self.list.len() }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.list.is_empty() }
+
+    /// Push `elem` to the front of the list.
+    pub fn push(&mut self, elem: T) -> &mut Self {
+        self.list.push(elem);
+        self
+    }
+
+    /// Remove the element at the front of the list, if there is one.
+    pub fn pop(&mut self) -> Option<T> { self.list.pop() }
+
+    /// Borrow the element at the front of the list, which is the one that
+    /// was found or pushed most recently.
+    pub fn peek(&self) -> Option<&T> { self.list.peek() }
+
+    /// Returns an iterator over the elements, from the front, without
+    /// reordering them.
+    pub fn iter(&self) -> list::Iter<'_, T> { self.list.iter() }
+
+    /// Find the first element which `pred` returns `true` for, and move it
+    /// to the front of the list.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` with the element that was found
+    /// - `None` if there was no such element, in which case the list isn't
+    ///   reordered
+    ///
+    /// # Time complexity
+    /// O(index of the element found)
+    pub fn find_by<F>(&mut self, pred: F) -> Option<&mut T>
+    where F: FnMut(&T) -> bool {
+        self.list.touch_where(pred)?;
+        self.list.peek_mut()
+    }
+
+    /// Find the first element equal to `elem`, and move it to the front of
+    /// the list.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` with the element that was found
+    /// - `None` if there was no such element, in which case the list isn't
+    ///   reordered
+    ///
+    /// # Time complexity
+    /// O(index of the element found)
+    pub fn find(&mut self, elem: &T) -> Option<&mut T>
+    where T: PartialEq {
+        self.find_by(|x| x == elem)
+    }
+
+    /// Find the first element equal to `elem`, and move it to the front of
+    /// the list, returning the index it was found at.
+    ///
+    /// This is the encoding step of move-to-front coding.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the element's index before it was moved
+    /// - `None` if there was no such element, in which case the list isn't
+    ///   reordered
+    ///
+    /// # Time complexity
+    /// O(index of the element found)
+    pub fn position(&mut self, elem: &T) -> Option<usize>
+    where T: PartialEq {
+        self.list.touch_where(|x| x == elem)
+    }
+
+    /// Move the element at `index` to the front of the list, and borrow it.
+    ///
+    /// This is the decoding step of move-to-front coding.
+    ///
+    /// # Time complexity
+    /// O(`index`)
+    pub fn get(&mut self, index: usize) -> Option<&mut T> {
+        if !self.list.touch(index) { return None }
+        self.list.peek_mut()
+    }
+
+    /// Remove the first element equal to `elem` from the list.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the element that was removed
+    /// - `None` if there was no such element
+    ///
+    /// # Time complexity
+    /// O(index of the element removed)
+    pub fn remove(&mut self, elem: &T) -> Option<T>
+    where T: PartialEq {
+        self.list.touch_where(|x| x == elem)?;
+        self.list.pop()
+    }
+
+    /// Returns the list of elements, in their current order.
+    #[inline] pub fn as_list(&self) -> &List<T> { &self.list }
+
+    /// Consume the `MoveToFrontList`, returning its elements as a `List`, in
+    /// their current order.
+    #[inline] pub fn into_list(self) -> List<T> { self.list }
+}
+
+impl<T> From<List<T>> for MoveToFrontList<T> {
+    /// Use `list` as a `MoveToFrontList`, keeping its order.
+    fn from(list: List<T>) -> Self { MoveToFrontList { list } }
+}
+
+impl<T> Default for MoveToFrontList<T> {
+    fn default() -> Self { MoveToFrontList::new() }
+}
+
+impl<'a, T> IntoIterator for &'a MoveToFrontList<T> {
+    type IntoIter = list::Iter<'a, T>;
+    type Item = &'a T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T> IntoIterator for MoveToFrontList<T> {
+    type IntoIter = list::IntoIter<T>;
+    type Item = T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.list.into_iter() }
+}
+
+impl<T> iter::FromIterator<T> for MoveToFrontList<T> {
+    /// Push each element of `iter` in turn, so that the last one is at the
+    /// front, as with `List`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MoveToFrontList { list: iter.into_iter().collect() }
+    }
+}
+
+impl<T> iter::Extend<T> for MoveToFrontList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<T> fmt::Debug for MoveToFrontList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
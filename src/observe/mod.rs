@@ -0,0 +1,282 @@
+//! Hooks for watching a zipper change.
+//!
+//! An [`Observed`] zipper wraps a `ZipList` together with an [`Observer`],
+//! and tells the observer about every element inserted or removed, whether
+//! one at a time or spliced in or out as a run, and every cursor movement,
+//! with the positions involved. This makes it possible to
+//! keep derived data, like an index over the elements, up to date without
+//! wrapping every call site that edits the zipper.
+//!
+//! Positions are indices into the zipper's elements from left to right, so
+//! the cursor's position is the number of elements to its left.
+//!
+//! [`Observed`]: struct.Observed.html
+//! [`Observer`]: trait.Observer.html
+use std::ops::Deref;
+
+use super::{List, ZipList};
+use error::SeekError;
+
+#[cfg(test)] mod test;
+
+/// Receives notifications about changes to an [`Observed`] zipper.
+///
+/// Every method does nothing by default, so implementations only need to
+/// provide the ones they're interested in. Each is called after the change
+/// it describes has been made.
+///
+/// Inserting or removing the element to the left of the cursor changes the
+/// cursor's position, so `moved` is called after `inserted` or `removed` in
+/// that case, as well as after seeks.
+///
+/// A run of elements spliced in or out at once is reported one element at a
+/// time, in order from the left: each is `inserted` at the position after
+/// the last, or `removed` from the position the last was removed from.
+///
+/// [`Observed`]: struct.Observed.html
+pub trait Observer<T> {
+    /// Called when `elem` is inserted at `index`.
+    fn inserted(&mut self, _index: usize, _elem: &T) {}
+
+    /// Called when `elem` is removed from `index`.
+    fn removed(&mut self, _index: usize, _elem: &T) {}
+
+    /// Called when the cursor's position changes from `from` to `to`.
+    fn moved(&mut self, _from: usize, _to: usize) {}
+}
+
+impl<T> Observer<T> for () {}
+
+/// A `ZipList` which reports changes to an [`Observer`].
+///
+/// This dereferences to the underlying `ZipList` for reading. Changes have to
+/// be made through the methods on `Observed`, so that none of them are missed
+/// by the observer.
+///
+/// [`Observer`]: trait.Observer.html
+#[derive(Clone, Debug)]
+pub struct Observed<T, O> { zipper: ZipList<T>
+                          , observer: O
+                          }
+
+impl<T, O> Observed<T, O>
+where O: Observer<T> {
+    /// Start observing changes to `zipper`.
+    pub fn new(zipper: ZipList<T>, observer: O) -> Self {
+        Observed { zipper, observer }
+    }
+
+    /// Borrow the observer.
+    #[inline] pub fn observer(&self) -> &O { &self.observer }
+
+    /// Mutably borrow the observer.
+    #[inline] pub fn observer_mut(&mut self) -> &mut O { &mut self.observer }
+
+    /// Stop observing, returning the zipper and the observer.
+    pub fn into_inner(self) -> (ZipList<T>, O) { (self.zipper, self.observer) }
+
+    /// Returns the cursor's position.
+    #[inline] fn cursor(&self) -> usize { self.zipper.left.len() }
+
+    /// Pop the item to the left of the zipper and return it.
+    ///
+    /// # Returns
+    /// - `Some(T)` if there is an item to the left of the zipper
+    /// - `None` if there are no items to the left of the zipper
+    pub fn pop_left(&mut self) -> Option<T> {
+        let elem = self.zipper.pop_left()?;
+        let index = self.cursor();
+        self.observer.removed(index, &elem);
+        self.observer.moved(index + 1, index);
+        Some(elem)
+    }
+
+    /// Pop the item to the right of the zipper and return it.
+    ///
+    /// # Returns
+    /// - `Some(T)` if there is an item to the right of the zipper
+    /// - `None` if there are no items to the right of the zipper
+    pub fn pop_right(&mut self) -> Option<T> {
+        let elem = self.zipper.pop_right()?;
+        let index = self.cursor();
+        self.observer.removed(index, &elem);
+        Some(elem)
+    }
+
+    /// Push `elem` to the left of the zipper.
+    pub fn push_left(&mut self, elem: T) -> &mut Self {
+        let index = self.cursor();
+        self.zipper.push_left(elem);
+        if let Some(elem) = self.zipper.peek_left() {
+            self.observer.inserted(index, elem)
+        }
+        self.observer.moved(index, index + 1);
+        self
+    }
+
+    /// Push `elem` to the right of the zipper.
+    pub fn push_right(&mut self, elem: T) -> &mut Self {
+        let index = self.cursor();
+        self.zipper.push_right(elem);
+        if let Some(elem) = self.zipper.peek_right() {
+            self.observer.inserted(index, elem)
+        }
+        self
+    }
+
+    /// Move the zipper one position to the left.
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved to the left
+    /// - `false` if the zipper is already at the left
+    pub fn move_left(&mut self) -> bool {
+        self.seek_left(1) == 1
+    }
+
+    /// Move the zipper one position to the right
+    ///
+    /// # Returns
+    /// - `true` if the zipper was moved to the right
+    /// - `false` if the zipper is already at the right
+    pub fn move_right(&mut self) -> bool {
+        self.seek_right(1) == 1
+    }
+
+    /// Move the zipper `n` positions to the left
+    ///
+    /// The observer isn't told about a seek that doesn't move the zipper.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   zipper reached the end of the list before it finished moving.
+    pub fn seek_left(&mut self, n: usize) -> usize {
+        let from = self.cursor();
+        let moved = self.zipper.seek_left(n);
+        if moved > 0 { self.observer.moved(from, from - moved) }
+        moved
+    }
+
+    /// Move the zipper `n` positions to the right
+    ///
+    /// The observer isn't told about a seek that doesn't move the zipper.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   zipper reached the end of the list before it finished moving.
+    pub fn seek_right(&mut self, n: usize) -> usize {
+        let from = self.cursor();
+        let moved = self.zipper.seek_right(n);
+        if moved > 0 { self.observer.moved(from, from + moved) }
+        moved
+    }
+
+    /// Move the zipper exactly `n` positions to the left.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the zipper was moved
+    /// - `Err(SeekError)` if there are fewer than `n` items to the left of
+    ///   the zipper, in which case it isn't moved at all
+    pub fn try_seek_left(&mut self, n: usize) -> Result<(), SeekError> {
+        let from = self.cursor();
+        self.zipper.try_seek_left(n)?;
+        if n > 0 { self.observer.moved(from, from - n) }
+        Ok(())
+    }
+
+    /// Move the zipper exactly `n` positions to the right.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the zipper was moved
+    /// - `Err(SeekError)` if there are fewer than `n` items to the right of
+    ///   the zipper, in which case it isn't moved at all
+    pub fn try_seek_right(&mut self, n: usize) -> Result<(), SeekError> {
+        let from = self.cursor();
+        self.zipper.try_seek_right(n)?;
+        if n > 0 { self.observer.moved(from, from + n) }
+        Ok(())
+    }
+
+    /// Insert all of `other`'s elements at the cursor, in order, as
+    /// [`ZipList::absorb`](../struct.ZipList.html#method.absorb) does.
+    pub fn absorb(&mut self, other: ZipList<T>) {
+        let from = self.cursor();
+        let (left, right) = (other.left.len(), other.right.len());
+        self.zipper.absorb(other);
+        self.inserted(left, right);
+        if left > 0 { self.observer.moved(from, from + left) }
+    }
+
+    /// Insert the elements of `list` at the cursor, in order, leaving the
+    /// cursor after them, as
+    /// [`ZipList::paste`](../struct.ZipList.html#method.paste) does.
+    pub fn paste(&mut self, list: List<T>) {
+        let (from, n) = (self.cursor(), list.len());
+        self.zipper.paste(list);
+        self.inserted(n, 0);
+        if n > 0 { self.observer.moved(from, from + n) }
+    }
+
+    /// Remove up to `n` elements immediately to the left of the zipper,
+    /// returning them as a list, in order.
+    pub fn cut_left(&mut self, n: usize) -> List<T> {
+        let from = self.cursor();
+        let cut = self.zipper.cut_left(n);
+        self.removed(cut.iter());
+        if !cut.is_empty() { self.observer.moved(from, from - cut.len()) }
+        cut
+    }
+
+    /// Remove up to `n` elements immediately to the right of the zipper,
+    /// returning them as a list, in order.
+    pub fn cut_right(&mut self, n: usize) -> List<T> {
+        let cut = self.zipper.cut_right(n);
+        self.removed(cut.iter());
+        cut
+    }
+
+    /// Detach up to `n` elements immediately to the left of the zipper,
+    /// returning them as a new `ZipList`, with its zipper at the right end.
+    pub fn take_left(&mut self, n: usize) -> ZipList<T> {
+        let from = self.cursor();
+        let taken = self.zipper.take_left(n);
+        let mut elems = taken.left_iter().collect::<Vec<_>>();
+        elems.reverse();
+        self.removed(elems.into_iter());
+        if !taken.is_empty() { self.observer.moved(from, from - taken.len()) }
+        taken
+    }
+
+    /// Detach up to `n` elements immediately to the right of the zipper,
+    /// returning them as a new `ZipList`, with its zipper at the left end.
+    pub fn take_right(&mut self, n: usize) -> ZipList<T> {
+        let taken = self.zipper.take_right(n);
+        self.removed(taken.right_iter());
+        taken
+    }
+
+    /// Tell the observer about the `left` elements nearest the cursor on its
+    /// left and the `right` nearest on its right, which were just inserted.
+    fn inserted(&mut self, left: usize, right: usize) {
+        let index = self.cursor() - left;
+        let mut elems = self.zipper.left_iter().take(left).collect::<Vec<_>>();
+        elems.reverse();
+        elems.extend(self.zipper.right_iter().take(right));
+        for (i, elem) in elems.into_iter().enumerate() {
+            self.observer.inserted(index + i, elem)
+        }
+    }
+
+    /// Tell the observer about `elems`, in order, which were just removed
+    /// from where the cursor is now.
+    fn removed<'a, I>(&mut self, elems: I)
+    where I: Iterator<Item = &'a T>
+        , T: 'a {
+        let index = self.cursor();
+        for elem in elems { self.observer.removed(index, elem) }
+    }
+}
+
+impl<T, O> Deref for Observed<T, O> {
+    type Target = ZipList<T>;
+    #[inline] fn deref(&self) -> &ZipList<T> { &self.zipper }
+}
@@ -0,0 +1,82 @@
+use super::{Observed, Observer};
+use ::ZipList;
+use test::contents;
+
+/// An observer which keeps a copy of the zipper's elements up to date, using
+/// only the positions it's told about.
+#[derive(Default)]
+struct Mirror { elems: Vec<usize>
+              , moves: Vec<(usize, usize)>
+              }
+
+impl Observer<usize> for Mirror {
+    fn inserted(&mut self, index: usize, elem: &usize) {
+        self.elems.insert(index, *elem);
+    }
+    fn removed(&mut self, index: usize, elem: &usize) {
+        assert_eq!(self.elems.remove(index), *elem);
+    }
+    fn moved(&mut self, from: usize, to: usize) { self.moves.push((from, to)) }
+}
+
+quickcheck! {
+    fn observer_sees_every_edit(ops: Vec<(u8, usize)>) -> bool {
+        let mut zipper = Observed::new(ZipList::new(), Mirror::default());
+        for (op, n) in ops {
+            let cursor = zipper.left_iter().len();
+            match op % 14 {
+                0 => { zipper.push_left(n); }
+              , 1 => { zipper.push_right(n); }
+              , 2 => { zipper.pop_left(); }
+              , 3 => { zipper.pop_right(); }
+              , 4 => { zipper.seek_left(n % 4); }
+              , 5 => { zipper.seek_right(n % 4); }
+              , 6 => { let _ = zipper.try_seek_left(n % 4); }
+              , 7 => { zipper.cut_left(n % 4); }
+              , 8 => { zipper.cut_right(n % 4); }
+              , 9 => { zipper.take_left(n % 4); }
+              , 10 => { zipper.take_right(n % 4); }
+              , 11 => { zipper.paste((n..n + n % 4).collect()); }
+              , 12 => { zipper.absorb(zipper![n, n + 1 ; n + 2]); }
+              , _ => { zipper.move_right(); }
+            }
+            let now = zipper.left_iter().len();
+            if now != cursor &&
+               zipper.observer().moves.last() != Some(&(cursor, now)) {
+                return false
+            }
+        }
+        let (zipper, mirror) = zipper.into_inner();
+        mirror.elems == contents(&zipper).0
+    }
+}
+
+#[test]
+fn positions_are_indices_from_the_left() {
+    let mut zipper = Observed::new(zipper![1, 2 ; 3], Mirror::default());
+    zipper.observer_mut().elems = vec![1, 2, 3];
+    zipper.push_left(4).push_right(5);
+    assert_eq!(zipper.observer().elems, vec![1, 2, 4, 5, 3]);
+    assert_eq!(zipper.pop_left(), Some(4));
+    assert_eq!(zipper.pop_right(), Some(5));
+    assert_eq!(zipper.seek_left(5), 2);
+    assert!(!zipper.move_left());
+    assert!(zipper.try_seek_right(4).is_err());
+    assert_eq!(zipper.observer().moves, vec![(2, 3), (3, 2), (2, 0)]);
+    assert_eq!(zipper.observer().elems, vec![1, 2, 3]);
+}
+
+#[test]
+fn splices_are_reported_element_by_element() {
+    let mut zipper = Observed::new(zipper![1, 2 ; 3, 4], Mirror::default());
+    zipper.observer_mut().elems = vec![1, 2, 3, 4];
+    let cut = zipper.cut_left(1);
+    zipper.paste(cut);
+    zipper.absorb(zipper![5 ; 6]);
+    assert_eq!(zipper.observer().elems, vec![1, 2, 5, 6, 3, 4]);
+    assert_eq!(contents(&zipper.take_right(2)).0, vec![6, 3]);
+    assert_eq!(contents(&zipper.take_left(2)).0, vec![2, 5]);
+    assert_eq!(zipper.cut_right(5).len(), 1);
+    assert_eq!(zipper.observer().elems, vec![1]);
+    assert_eq!(zipper.observer().moves, vec![(2, 1), (1, 2), (2, 3), (3, 1)]);
+}
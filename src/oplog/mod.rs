@@ -0,0 +1,148 @@
+//! Recording and replaying edits to a zipper.
+//!
+//! An [`OpLog`] is an [`Observer`] which records every change made to an
+//! [`Observed`] zipper as an [`Operation`]. The operations can be serialized
+//! with `serde`, sent elsewhere, and replayed on a copy of the zipper with
+//! [`ZipList::replay`], to make the same changes to it.
+//!
+//! This module requires the `oplog` feature.
+//!
+//! [`OpLog`]: struct.OpLog.html
+//! [`Observer`]: ../observe/trait.Observer.html
+//! [`Observed`]: ../observe/struct.Observed.html
+//! [`Operation`]: enum.Operation.html
+//! [`ZipList::replay`]: ../struct.ZipList.html#method.replay
+use std::mem;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Stack, ZipList};
+use error::ReplayError;
+use observe::Observer;
+
+#[cfg(test)] mod test;
+
+/// A single change to a zipper.
+///
+/// Positions are indices into the zipper's elements from left to right, as
+/// in [`Observer`](../observe/trait.Observer.html).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Operation<T> {
+    /// Insert an element at `index`.
+    Insert { index: usize, elem: T }
+  , /// Remove the element at `index`.
+    Remove { index: usize }
+  , /// Move the cursor to position `to`.
+    Move { to: usize }
+}
+
+/// An [`Observer`] which records every change it's told about.
+///
+/// Consecutive cursor movements are recorded as a single [`Move`], so
+/// seeking around a zipper without editing it doesn't grow the log.
+///
+/// [`Observer`]: ../observe/trait.Observer.html
+/// [`Move`]: enum.Operation.html#variant.Move
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpLog<T> { ops: Vec<Operation<T>> }
+
+impl<T> OpLog<T> {
+    /// Create a new empty `OpLog`.
+    pub fn new() -> Self { OpLog { ops: Vec::new() } }
+
+    /// Returns the operations recorded so far, oldest first.
+    #[inline] pub fn operations(&self) -> &[Operation<T>] { &self.ops }
+
+    /// Returns the number of operations recorded so far.
+    #[inline] pub fn len(&self) -> usize { self.ops.len() }
+
+    /// Returns true if no operations have been recorded.
+    #[inline] pub fn is_empty(&self) -> bool { self.ops.is_empty() }
+
+    /// Remove and return the operations recorded so far, leaving the log
+    /// empty, e.g. to send them to a replica.
+    pub fn take(&mut self) -> Vec<Operation<T>> {
+        mem::take(&mut self.ops)
+    }
+}
+
+impl<T> Default for OpLog<T> {
+    fn default() -> Self { OpLog::new() }
+}
+
+impl<T> Observer<T> for OpLog<T>
+where T: Clone {
+    fn inserted(&mut self, index: usize, elem: &T) {
+        self.ops.push(Operation::Insert { index, elem: elem.clone() })
+    }
+
+    fn removed(&mut self, index: usize, _elem: &T) {
+        self.ops.push(Operation::Remove { index })
+    }
+
+    fn moved(&mut self, _from: usize, to: usize) {
+        if let Some(&mut Operation::Move { to: ref mut last }) =
+            self.ops.last_mut() {
+            *last = to;
+            return
+        }
+        self.ops.push(Operation::Move { to })
+    }
+}
+
+impl<T> ZipList<T> {
+    /// Apply each of `ops` to this zipper, in order.
+    ///
+    /// Replaying the operations recorded by an [`OpLog`] on a copy of the
+    /// zipper it observed, as it was when recording started, leaves the
+    /// copy with the same elements and cursor position as the original.
+    ///
+    /// # Returns
+    /// - `Ok(())` if all of the operations were applied
+    /// - `Err(ReplayError)` if an operation refers to a position past the
+    ///   end of the zipper. The operations before it have been applied,
+    ///   and the cursor is where they left it.
+    ///
+    /// # Time complexity
+    /// O(the total distance between the positions the operations refer to)
+    ///
+    /// [`OpLog`]: oplog/struct.OpLog.html
+    pub fn replay<I>(&mut self, ops: I) -> Result<(), ReplayError>
+    where I: IntoIterator<Item = Operation<T>> {
+        // edits are made by seeking to them, so keep track of where the
+        // cursor should be separately, and only move it there at the end
+        let mut cursor = self.left.len();
+        let mut result = Ok(());
+        for (applied, op) in ops.into_iter().enumerate() {
+            let len = self.len();
+            match op {
+                Operation::Insert { index, elem } if index <= len => {
+                    self.seek_to(index);
+                    self.right.push(elem);
+                    if index < cursor { cursor += 1 }
+                }
+              , Operation::Remove { index } if index < len => {
+                    self.seek_to(index);
+                    self.right.pop();
+                    if index < cursor { cursor -= 1 }
+                }
+              , Operation::Move { to } if to <= len => cursor = to
+              , Operation::Insert { index, .. } | Operation::Remove { index }
+              | Operation::Move { to: index } => {
+                    result = Err(ReplayError { applied, index, len });
+                    break
+                }
+            }
+        }
+        self.seek_to(cursor);
+        result
+    }
+
+    /// Move the cursor to position `index`, which must be at most the
+    /// zipper's length.
+    fn seek_to(&mut self, index: usize) {
+        let cursor = self.left.len();
+        if index < cursor { self.seek_left(cursor - index); }
+        else { self.seek_right(index - cursor); }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{OpLog, Operation};
+use ::{List, ZipList};
+use error::ReplayError;
+use observe::Observed;
+use test::contents;
+
+quickcheck! {
+    fn replay_reproduces_edits(start: ZipList<usize>, ops: Vec<(u8, usize)>)
+                               -> bool {
+        let mut zipper = Observed::new(start.clone(), OpLog::new());
+        for (op, n) in ops {
+            match op % 12 {
+                0 => { zipper.push_left(n); }
+              , 1 => { zipper.push_right(n); }
+              , 2 => { zipper.pop_left(); }
+              , 3 => { zipper.pop_right(); }
+              , 4 => { zipper.seek_left(n % 4); }
+              , 5 => { zipper.seek_right(n % 4); }
+              , 6 => {
+                    let mut other = ZipList::new();
+                    for elem in n..n + n % 5 { other.push_left(elem); }
+                    other.seek_left(n % 3);
+                    zipper.absorb(other);
+                }
+              , 7 => { zipper.paste((n..n + n % 4).collect::<List<_>>()); }
+              , 8 => { zipper.cut_left(n % 4); }
+              , 9 => { zipper.cut_right(n % 4); }
+              , 10 => { zipper.take_left(n % 4); }
+              , _ => { zipper.take_right(n % 4); }
+            }
+        }
+        let (zipper, mut log) = zipper.into_inner();
+        let mut replica = start;
+        replica.replay(log.take()).is_ok() &&
+        contents(&replica) == contents(&zipper) &&
+        log.is_empty()
+    }
+}
+
+#[test]
+fn replay_keeps_cursor_after_edits_right_of_it() {
+    let mut zipper = Observed::new(zipper![1 ; 9], OpLog::new());
+    zipper.absorb(zipper![ ; 2, 3]);
+    let (zipper, mut log) = zipper.into_inner();
+    assert_eq!(contents(&zipper), (vec![1, 2, 3, 9], 1));
+    let mut replica = zipper![1 ; 9];
+    replica.replay(log.take()).unwrap();
+    assert_eq!(contents(&replica), (vec![1, 2, 3, 9], 1));
+}
+
+#[test]
+fn consecutive_moves_are_merged() {
+    let mut zipper = Observed::new(zipper![1, 2, 3 ; 4], OpLog::new());
+    zipper.seek_left(2);
+    zipper.move_right();
+    zipper.pop_right();
+    zipper.seek_left(1);
+    zipper.seek_right(1);
+    assert_eq!( zipper.observer().operations()
+              , &[ Operation::Move { to: 2 }
+                 , Operation::Remove { index: 2 }
+                 , Operation::Move { to: 2 }
+                 ] );
+}
+
+#[test]
+fn replay_stops_at_bad_operation() {
+    let mut zipper = zipper![1 ; 2];
+    let err = zipper.replay(vec![ Operation::Insert { index: 2, elem: 3 }
+                                , Operation::Remove { index: 3 }
+                                , Operation::Move { to: 0 }
+                                ]);
+    assert_eq!(err, Err(ReplayError { applied: 1, index: 3, len: 3 }));
+    assert_eq!(contents(&zipper), (vec![1, 2, 3], 1));
+}
@@ -0,0 +1,157 @@
+//! Parallel iterators over lists and zippers, using [rayon].
+//!
+//! A linked list can't be split in half in constant time, so these iterators
+//! walk the list once up front to break it into contiguous chunks, and then
+//! hand those chunks out to the thread pool.
+//!
+//! [rayon]: https://docs.rs/rayon
+use std::cmp;
+use std::mem;
+
+use rayon;
+use rayon::iter::{Either, IntoParallelIterator, ParallelIterator};
+use rayon::iter::plumbing::UnindexedConsumer;
+
+use super::{List, ZipList, list};
+
+#[cfg(test)] mod test;
+
+/// How many chunks to split a list into for each thread in the pool, so that
+/// uneven per-element work can still be balanced between threads.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Returns the number of elements to put in each chunk when splitting `len`
+/// elements across the current thread pool.
+fn chunk_len(len: usize) -> usize {
+    let chunks = rayon::current_num_threads() * CHUNKS_PER_THREAD;
+    cmp::max(1, len.div_ceil(chunks))
+}
+
+/// Break `list` into chunks of `size` elements, appending them to `chunks`
+/// in order.
+fn split_list<T>(mut list: List<T>, size: usize, chunks: &mut Vec<List<T>>) {
+    while !list.is_empty() {
+        let rest = list.split_off(size);
+        chunks.push(mem::replace(&mut list, rest));
+    }
+}
+
+/// Break `iter` into iterators over chunks of `size` elements, in order.
+fn split_iter<T>(mut iter: list::Iter<T>, size: usize) -> Vec<list::Iter<T>> {
+    let mut chunks = Vec::new();
+    while iter.len() > 0 {
+        let (chunk, rest) = iter.split_at(size);
+        chunks.push(chunk);
+        iter = rest;
+    }
+    chunks
+}
+
+/// A parallel iterator over the elements of a `List` or `ZipList`.
+///
+/// This is created by calling `into_par_iter()` on a `List` or `ZipList`.
+pub struct IntoIter<T> { chunks: Vec<List<T>> }
+
+impl<T> ParallelIterator for IntoIter<T>
+where T: Send {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where C: UnindexedConsumer<Self::Item> {
+        self.chunks.into_par_iter()
+            .flat_map_iter(List::into_iter)
+            .drive_unindexed(consumer)
+    }
+}
+
+/// A parallel iterator over references to the elements of a `List` or
+/// `ZipList`.
+///
+/// This is created by calling `par_iter()` on a `List` or `ZipList`.
+pub struct Iter<'a, T: 'a> { chunks: Vec<Chunk<'a, T>> }
+
+/// A chunk of a borrowed list, which is either already in order, or must be
+/// yielded back-to-front (as chunks from the left side of a zipper are).
+enum Chunk<'a, T: 'a> { Forward(list::Iter<'a, T>)
+                      , Backward(list::Iter<'a, T>)
+                      }
+
+impl<'a, T> ParallelIterator for Iter<'a, T>
+where T: Sync + 'a {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where C: UnindexedConsumer<Self::Item> {
+        self.chunks.into_par_iter()
+            .flat_map_iter(|chunk| match chunk {
+                Chunk::Forward(iter) => Either::Left(iter)
+              , Chunk::Backward(iter) =>
+                    Either::Right(iter.collect::<Vec<_>>().into_iter().rev())
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+impl<T> IntoParallelIterator for List<T>
+where T: Send {
+    type Iter = IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let size = chunk_len(self.len());
+        let mut chunks = Vec::new();
+        split_list(self, size, &mut chunks);
+        IntoIter { chunks }
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a List<T>
+where T: Sync + 'a {
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let size = chunk_len(self.len());
+        let chunks = split_iter(self.iter(), size).into_iter()
+                        .map(Chunk::Forward)
+                        .collect();
+        Iter { chunks }
+    }
+}
+
+impl<T> IntoParallelIterator for ZipList<T>
+where T: Send {
+    type Iter = IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let ZipList { mut left, right, .. } = self;
+        // the left side is stored nearest-first, so it has to be flipped
+        // around to come out in order.
+        left.reverse();
+        let size = chunk_len(left.len() + right.len());
+        let mut chunks = Vec::new();
+        split_list(left, size, &mut chunks);
+        split_list(right, size, &mut chunks);
+        IntoIter { chunks }
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a ZipList<T>
+where T: Sync + 'a {
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let size = chunk_len(self.len());
+        // the left side is stored nearest-first, so its chunks are taken in
+        // reverse order, and each is yielded back-to-front.
+        let mut chunks = split_iter(self.left.iter(), size).into_iter()
+                            .rev()
+                            .map(Chunk::Backward)
+                            .collect::<Vec<_>>();
+        chunks.extend(split_iter(self.right.iter(), size).into_iter()
+                        .map(Chunk::Forward));
+        Iter { chunks }
+    }
+}
@@ -0,0 +1,34 @@
+use ::{List, ZipList};
+use rayon::prelude::*;
+
+fn zip_list(items: &[usize], cursor: usize) -> ZipList<usize> {
+    let mut zipper = ZipList::new();
+    for item in items {
+        zipper.push_left(*item);
+    }
+    zipper.seek_left(items.len() - cursor.min(items.len()));
+    zipper
+}
+
+quickcheck! {
+    fn list_par_iter_in_order(list: List<usize>) -> bool {
+        let expected = list.iter().collect::<Vec<_>>();
+        list.par_iter().collect::<Vec<_>>() == expected
+    }
+
+    fn list_into_par_iter_in_order(list: List<usize>) -> bool {
+        let expected = list.iter().cloned().collect::<Vec<_>>();
+        list.into_par_iter().collect::<Vec<_>>() == expected
+    }
+
+    fn zip_list_par_iter_in_order(items: Vec<usize>, cursor: usize) -> bool {
+        let zipper = zip_list(&items, cursor);
+        zipper.par_iter().cloned().collect::<Vec<_>>() == items
+    }
+
+    fn zip_list_into_par_iter_in_order(items: Vec<usize>, cursor: usize)
+                                       -> bool {
+        let zipper = zip_list(&items, cursor);
+        zipper.into_par_iter().collect::<Vec<_>>() == items
+    }
+}
@@ -0,0 +1,70 @@
+//! Shuffling lists and picking random positions in zippers, using [rand].
+//!
+//! [rand]: https://docs.rs/rand
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::{List, ZipList};
+
+#[cfg(test)] mod test;
+
+impl<T> List<T> {
+    /// Shuffle the list in place, so that every order of its elements is
+    /// equally likely.
+    ///
+    /// The nodes are unlinked into a vector, shuffled with Fisher-Yates, and
+    /// relinked in their new order, so the elements themselves aren't moved.
+    ///
+    /// # Time complexity
+    /// O(n), and O(n) extra space for the vector of nodes.
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where R: Rng + ?Sized {
+        self.changed();
+        let mut nodes = Vec::with_capacity(self.len);
+        while let Some(node) = self.uncons() {
+            nodes.push(node);
+        }
+        nodes.shuffle(rng);
+        for node in nodes {
+            self.cons(node);
+        }
+    }
+}
+
+impl<T> ZipList<T> {
+    /// Move the cursor to a position chosen uniformly at random, from the
+    /// left end to the right end inclusive.
+    ///
+    /// # Returns
+    /// - the new position of the cursor
+    ///
+    /// # Time complexity
+    /// O(distance moved)
+    pub fn seek_random<R>(&mut self, rng: &mut R) -> usize
+    where R: Rng + ?Sized {
+        let cursor = self.left.len();
+        let target = rng.gen_range(0..=self.len());
+        if target < cursor {
+            self.seek_left(cursor - target);
+        } else {
+            self.seek_right(target - cursor);
+        }
+        target
+    }
+
+    /// Borrow an element chosen uniformly at random, without moving the
+    /// cursor.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the zipper isn't empty
+    /// - `None` if it is
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to the chosen element)
+    pub fn choose<R>(&self, rng: &mut R) -> Option<&T>
+    where R: Rng + ?Sized {
+        if self.is_empty() { return None }
+        let index = rng.gen_range(0..self.len());
+        self.as_view().get(index)
+    }
+}
@@ -0,0 +1,47 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use ::{List, ZipList};
+use test::contents;
+
+quickcheck! {
+    fn shuffle_is_a_permutation(list: List<u8>, seed: u64) -> bool {
+        let mut expected = list.iter().cloned().collect::<Vec<_>>();
+        let mut list = list;
+        list.shuffle(&mut StdRng::seed_from_u64(seed));
+        let mut elems = list.iter().cloned().collect::<Vec<_>>();
+        expected.sort();
+        elems.sort();
+        list.len() == expected.len() && elems == expected
+    }
+}
+
+#[test]
+fn shuffle_reorders_and_keeps_nodes() {
+    let mut list = (0..64).rev().collect::<List<u64>>();
+    let size = list.heap_size_of_children();
+    list.shuffle(&mut StdRng::seed_from_u64(7));
+    assert_eq!(list.heap_size_of_children(), size);
+    assert!(!list.iter().cloned().eq(0..64));
+}
+
+quickcheck! {
+    fn seek_random_stays_in_bounds(zipper: ZipList<u8>, seed: u64) -> bool {
+        let expected = contents(&zipper).0;
+        let mut zipper = zipper;
+        let cursor = zipper.seek_random(&mut StdRng::seed_from_u64(seed));
+        cursor <= expected.len() && contents(&zipper).0 == expected &&
+        zipper.as_view().cursor() == cursor
+    }
+}
+
+#[test]
+fn choose_picks_an_element() {
+    let mut rng = StdRng::seed_from_u64(3);
+    assert_eq!(ZipList::<u8>::new().choose(&mut rng), None);
+    let zipper = ZipList::from_iter_with_cursor(0..10, 4);
+    for _ in 0..20 {
+        let &elem = zipper.choose(&mut rng).unwrap();
+        assert!(elem < 10);
+    }
+}
@@ -0,0 +1,102 @@
+use std::iter;
+
+use super::{Stack, ZipList};
+
+impl<T> ZipList<T> {
+    /// Move the zipper one position to the right, returning the element it
+    /// moved past.
+    ///
+    /// This drives the zipper like a read head: the element stays in the
+    /// zipper, to the left of the cursor, so it can be backtracked over.
+    ///
+    /// # Returns
+    /// - `Some(&T)` with the element now to the left of the zipper
+    /// - `None` if the zipper was already at the right end
+    pub fn next_right(&mut self) -> Option<&T> {
+        if self.move_right() { self.left.peek() } else { None }
+    }
+
+    /// Move the zipper one position to the left, returning the element it
+    /// moved past.
+    ///
+    /// # Returns
+    /// - `Some(&T)` with the element now to the right of the zipper
+    /// - `None` if the zipper was already at the left end
+    pub fn next_left(&mut self) -> Option<&T> {
+        if self.move_left() { self.right.peek() } else { None }
+    }
+
+    /// Returns an iterator which moves the zipper right as it's advanced,
+    /// yielding a copy of each element it moves past.
+    ///
+    /// The elements are copied because moving the zipper relinks the nodes
+    /// they're in, so a reference to one can't be held across the next
+    /// move. Use [`next_right`](#method.next_right) to borrow each element
+    /// in turn instead.
+    ///
+    /// Stopping the iterator early, such as with `take_while`, leaves the
+    /// zipper after the last element yielded.
+    pub fn read_right(&mut self) -> ReadRight<'_, T>
+    where T: Clone {
+        ReadRight { zipper: self }
+    }
+
+    /// Returns an iterator which moves the zipper left as it's advanced,
+    /// yielding a copy of each element it moves past.
+    ///
+    /// See [`read_right`](#method.read_right) for details.
+    pub fn read_left(&mut self) -> ReadLeft<'_, T>
+    where T: Clone {
+        ReadLeft { zipper: self }
+    }
+}
+
+/// An iterator which moves a zipper right as it's advanced.
+///
+/// This is created by [`ZipList::read_right`].
+///
+/// [`ZipList::read_right`]: struct.ZipList.html#method.read_right
+pub struct ReadRight<'a, T: 'a> { zipper: &'a mut ZipList<T> }
+
+impl<'a, T> Iterator for ReadRight<'a, T>
+where T: Clone {
+    type Item = T;
+
+    #[inline] fn next(&mut self) -> Option<T> {
+        self.zipper.next_right().cloned()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.zipper.right.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for ReadRight<'a, T>
+where T: Clone {}
+
+/// An iterator which moves a zipper left as it's advanced.
+///
+/// This is created by [`ZipList::read_left`].
+///
+/// [`ZipList::read_left`]: struct.ZipList.html#method.read_left
+pub struct ReadLeft<'a, T: 'a> { zipper: &'a mut ZipList<T> }
+
+impl<'a, T> Iterator for ReadLeft<'a, T>
+where T: Clone {
+    type Item = T;
+
+    #[inline] fn next(&mut self) -> Option<T> {
+        self.zipper.next_left().cloned()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.zipper.left.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for ReadLeft<'a, T>
+where T: Clone {}
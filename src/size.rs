@@ -0,0 +1,73 @@
+//! Measuring the memory used by lists and their elements.
+use std::mem;
+
+use super::{List, SkipList, ZipList};
+
+/// Trait for values which can report how much heap memory they own.
+pub trait MemSize {
+    /// Returns the number of bytes of heap memory owned by `self`, not
+    /// counting `size_of::<Self>()` for `self` itself.
+    fn heap_size(&self) -> usize;
+}
+
+macro_rules! no_heap {
+    ($($t:ty),*) => {
+        $( impl MemSize for $t {
+            #[inline] fn heap_size(&self) -> usize { 0 }
+        } )*
+    }
+}
+
+no_heap!{ (), bool, char, f32, f64
+        , u8, u16, u32, u64, u128, usize
+        , i8, i16, i32, i64, i128, isize
+        }
+
+impl MemSize for String {
+    #[inline] fn heap_size(&self) -> usize { self.capacity() }
+}
+
+impl<T> MemSize for Box<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        mem::size_of::<T>() + (**self).heap_size()
+    }
+}
+
+impl<T> MemSize for Option<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map(MemSize::heap_size).unwrap_or(0)
+    }
+}
+
+impl<T> MemSize for Vec<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
+            + self.iter().map(MemSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T> MemSize for List<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        self.heap_size_of_children()
+            + self.iter().map(MemSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T> MemSize for ZipList<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        self.left.heap_size() + self.right.heap_size()
+    }
+}
+
+impl<T> MemSize for SkipList<T>
+where T: MemSize {
+    fn heap_size(&self) -> usize {
+        self.heap_size_of_children()
+            + self.iter().map(MemSize::heap_size).sum::<usize>()
+    }
+}
@@ -0,0 +1,436 @@
+//! An ordered set of linked nodes.
+//!
+//! A [`SkipList`] keeps its elements sorted in a linked list, with extra
+//! levels of links above it. Each level links roughly half of the nodes in
+//! the level below, so a search can skip over most of the list, and finding,
+//! inserting or removing an element takes O(log n) steps on average.
+//!
+//! A node is linked from as many levels as it's tall, so rather than owning
+//! each other, the nodes are kept in a vector and linked by index. The slots
+//! of removed nodes are kept on a freelist and reused by later inserts, as
+//! a `List` does with its nodes.
+//!
+//! [`SkipList`]: struct.SkipList.html
+use std::cmp::{self, Ordering};
+use std::fmt;
+use std::iter;
+use std::mem;
+
+#[cfg(test)] mod test;
+
+/// The most levels of links a skip list can have. With each level half as
+/// likely as the one below, this is plenty for any list that fits in memory.
+const MAX_LEVEL: usize = 32;
+
+/// The seed for the generator which chooses how tall new nodes are.
+const SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// An ordered set with O(log n) insertion, removal and lookup, which can be
+/// iterated over in order.
+///
+/// # Examples
+/// ```
+/// use an_zipper::SkipList;
+///
+/// let mut set = [5, 1, 4, 1, 3].iter().cloned().collect::<SkipList<_>>();
+/// assert!(set.insert(2));
+/// assert!(!set.insert(4));
+/// assert_eq!(set.remove(&1), Some(1));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&2, &3, &4, &5]);
+/// ```
+#[derive(Clone)]
+pub struct SkipList<T> { slots: Vec<Slot<T>>
+                       // the first node at each level, from the bottom up
+                       , heads: Vec<Option<usize>>
+                       , len: usize
+                       // the first free slot, which links to the next one
+                       , free: Option<usize>
+                       // state of the generator which chooses node heights
+                       , rng: u64
+                       }
+
+#[derive(Clone)]
+enum Slot<T> { Used(SkipNode<T>)
+             , Free(Option<usize>)
+             }
+
+#[derive(Clone)]
+struct SkipNode<T> { elem: T
+                   // the next node at each level this node is on
+                   , next: Vec<Option<usize>>
+                   }
+
+/// An iterator over the elements of a `SkipList`, in order.
+pub struct Iter<'a, T: 'a> { list: &'a SkipList<T>
+                           , next: Option<usize>
+                           , len: usize
+                           }
+
+/// An iterator over the elements of a `SkipList`, in order, which consumes
+/// it.
+pub struct IntoIter<T>(SkipList<T>);
+
+impl<T> SkipList<T> {
+    /// Create a new empty `SkipList`.
+    pub const fn new() -> Self {
+        SkipList { slots: Vec::new()
+                 , heads: Vec::new()
+                 , len: 0
+                 , free: None
+                 , rng: SEED
+                 }
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn len(&self) -> usize { self.len }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns an iterator over the elements, from least to greatest.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self
+             , next: self.heads.first().cloned().unwrap_or(None)
+             , len: self.len
+             }
+    }
+
+    /// Borrow the least element, if there is one.
+    ///
+    /// # Time complexity
+    /// O(1)
+    pub fn first(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Borrow the greatest element, if there is one.
+    ///
+    /// # Time complexity
+    /// O(log n) on average
+    pub fn last(&self) -> Option<&T> {
+        let mut at = None;
+        for level in (0..self.heads.len()).rev() {
+            while let Some(next) = self.next(at, level) {
+                at = Some(next);
+            }
+        }
+        at.map(|i| &self.node(i).elem)
+    }
+
+    /// Remove the least element, if there is one, and return it.
+    ///
+    /// # Time complexity
+    /// O(1) on average
+    pub fn pop_first(&mut self) -> Option<T> {
+        let first = self.heads.first().cloned().unwrap_or(None)?;
+        let node = self.release(first);
+        // the first node is first on every level it's on, so it's linked
+        // straight from the heads.
+        for (level, next) in node.next.into_iter().enumerate() {
+            self.heads[level] = next;
+        }
+        self.trim_levels();
+        self.len -= 1;
+        Some(node.elem)
+    }
+
+    /// Remove all the elements, keeping the slots they were in for reuse.
+    pub fn clear(&mut self) {
+        while self.pop_first().is_some() {}
+    }
+
+    /// Returns the number of bytes of heap memory held by the list's slots
+    /// and links, including free slots.
+    ///
+    /// This doesn't include any heap memory owned by the elements
+    /// themselves; use [`MemSize::heap_size`] for that.
+    ///
+    /// # Time complexity
+    /// O(number of slots)
+    ///
+    /// [`MemSize::heap_size`]: ../size/trait.MemSize.html#tymethod.heap_size
+    pub fn heap_size_of_children(&self) -> usize {
+        let links = self.slots.iter()
+            .map(|slot| match *slot {
+                Slot::Used(ref node) => node.next.capacity()
+              , Slot::Free(_) => 0
+            })
+            .sum::<usize>() + self.heads.capacity();
+        self.slots.capacity() * mem::size_of::<Slot<T>>()
+            + links * mem::size_of::<Option<usize>>()
+    }
+
+    fn node(&self, i: usize) -> &SkipNode<T> {
+        match self.slots[i] {
+            Slot::Used(ref node) => node
+          , Slot::Free(_) => unreachable!("linked to a free slot")
+        }
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut SkipNode<T> {
+        match self.slots[i] {
+            Slot::Used(ref mut node) => node
+          , Slot::Free(_) => unreachable!("linked to a free slot")
+        }
+    }
+
+    /// Returns the node after `at` on `level`, where `None` is the head.
+    #[inline]
+    fn next(&self, at: Option<usize>, level: usize) -> Option<usize> {
+        match at {
+            Some(i) => self.node(i).next[level]
+          , None => self.heads[level]
+        }
+    }
+
+    /// Returns the link to the node after `at` on `level`, where `None` is
+    /// the head.
+    #[inline]
+    fn link_mut(&mut self, at: Option<usize>, level: usize)
+                -> &mut Option<usize> {
+        match at {
+            Some(i) => &mut self.node_mut(i).next[level]
+          , None => &mut self.heads[level]
+        }
+    }
+
+    /// Put `node` in a slot, reusing a free one if possible, and return the
+    /// slot's index.
+    fn alloc(&mut self, node: SkipNode<T>) -> usize {
+        match self.free {
+            Some(i) => {
+                let slot = mem::replace(&mut self.slots[i], Slot::Used(node));
+                self.free = match slot {
+                    Slot::Free(next) => next
+                  , Slot::Used(_) => unreachable!("freelist links to a node")
+                };
+                i
+            }
+          , None => {
+                self.slots.push(Slot::Used(node));
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    /// Move the node out of slot `i`, which must already be unlinked (or
+    /// about to be), and put the slot on the freelist.
+    fn release(&mut self, i: usize) -> SkipNode<T> {
+        let slot = mem::replace(&mut self.slots[i], Slot::Free(self.free));
+        self.free = Some(i);
+        match slot {
+            Slot::Used(node) => node
+          , Slot::Free(_) => unreachable!("released a free slot")
+        }
+    }
+
+    /// Drop any levels at the top which no longer link to any nodes.
+    fn trim_levels(&mut self) {
+        while let Some(&None) = self.heads.last() {
+            self.heads.pop();
+        }
+    }
+
+    /// Choose how many levels a new node is on: one, and then each further
+    /// level with probability 1/2.
+    fn random_level(&mut self) -> usize {
+        // xorshift64
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng = x;
+        cmp::min(x.trailing_ones() as usize + 1, MAX_LEVEL)
+    }
+}
+
+impl<T> SkipList<T>
+where T: Ord {
+    /// Returns `true` if the list contains `elem`.
+    ///
+    /// # Time complexity
+    /// O(log n) on average
+    #[inline] pub fn contains(&self, elem: &T) -> bool {
+        self.find(elem).is_some()
+    }
+
+    /// Borrow the element in the list which is equal to `elem`, if there is
+    /// one.
+    ///
+    /// # Time complexity
+    /// O(log n) on average
+    pub fn get(&self, elem: &T) -> Option<&T> {
+        self.find(elem).map(|i| &self.node(i).elem)
+    }
+
+    /// Add `elem` to the list, unless there's already an element equal to
+    /// it.
+    ///
+    /// # Returns
+    /// - `true` if `elem` was inserted
+    /// - `false` if the list already contained an equal element, in which
+    ///   case the list isn't changed and `elem` is dropped
+    ///
+    /// # Time complexity
+    /// O(log n) on average
+    pub fn insert(&mut self, elem: T) -> bool {
+        let mut preds = self.predecessors(&elem);
+        if let Some(&pred) = preds.first() {
+            if let Some(next) = self.next(pred, 0) {
+                if self.node(next).elem == elem { return false }
+            }
+        }
+        let height = self.random_level();
+        // levels that don't exist yet are linked straight from the head
+        if height > self.heads.len() {
+            self.heads.resize(height, None);
+            preds.resize(height, None);
+        }
+        let next = preds.iter().take(height).enumerate()
+                        .map(|(level, &pred)| self.next(pred, level))
+                        .collect();
+        let index = self.alloc(SkipNode { elem, next });
+        for (level, &pred) in preds.iter().take(height).enumerate() {
+            *self.link_mut(pred, level) = Some(index);
+        }
+        self.len += 1;
+        true
+    }
+
+    /// Remove the element equal to `elem` from the list, if there is one.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the element that was removed
+    /// - `None` if the list didn't contain an equal element
+    ///
+    /// # Time complexity
+    /// O(log n) on average
+    pub fn remove(&mut self, elem: &T) -> Option<T> {
+        let preds = self.predecessors(elem);
+        let index = self.next(*preds.first()?, 0)?;
+        if self.node(index).elem != *elem { return None }
+        let node = self.release(index);
+        for (level, next) in node.next.into_iter().enumerate() {
+            *self.link_mut(preds[level], level) = next;
+        }
+        self.trim_levels();
+        self.len -= 1;
+        Some(node.elem)
+    }
+
+    /// Returns the slot of the node equal to `elem`, if there is one.
+    fn find(&self, elem: &T) -> Option<usize> {
+        let mut at = None;
+        for level in (0..self.heads.len()).rev() {
+            while let Some(next) = self.next(at, level) {
+                match self.node(next).elem.cmp(elem) {
+                    Ordering::Less => at = Some(next)
+                  , Ordering::Equal => return Some(next)
+                  , Ordering::Greater => break
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the last node before `elem` on each level, from the bottom
+    /// up, where `None` is the head.
+    fn predecessors(&self, elem: &T) -> Vec<Option<usize>> {
+        let mut preds = vec![None; self.heads.len()];
+        let mut at = None;
+        for level in (0..self.heads.len()).rev() {
+            while let Some(next) = self.next(at, level) {
+                if self.node(next).elem >= *elem { break }
+                at = Some(next);
+            }
+            preds[level] = at;
+        }
+        preds
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|i| {
+            let node = self.list.node(i);
+            self.next = node.next[0];
+            self.len -= 1;
+            &node.elem
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for Iter<'a, T> {
+    #[inline] fn len(&self) -> usize { self.len }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    #[inline] fn next(&mut self) -> Option<Self::Item> { self.0.pop_first() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> iter::ExactSizeIterator for IntoIter<T> {
+    #[inline] fn len(&self) -> usize { self.0.len }
+}
+
+impl<'a, T> IntoIterator for &'a SkipList<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T> IntoIterator for SkipList<T> {
+    type IntoIter = IntoIter<T>;
+    type Item = T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { IntoIter(self) }
+}
+
+impl<T> iter::FromIterator<T> for SkipList<T>
+where T: Ord {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SkipList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> iter::Extend<T> for SkipList<T>
+where T: Ord {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter { self.insert(elem); }
+    }
+}
+
+impl<T> Default for SkipList<T> {
+    fn default() -> Self { SkipList::new() }
+}
+
+impl<T> PartialEq for SkipList<T>
+where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for SkipList<T> where T: Eq {}
+
+impl<T> fmt::Debug for SkipList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
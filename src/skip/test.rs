@@ -0,0 +1,58 @@
+use std::collections::BTreeSet;
+
+use ::SkipList;
+
+quickcheck! {
+    fn matches_btree_set(ops: Vec<(bool, u8)>) -> bool {
+        let mut list = SkipList::new();
+        let mut set = BTreeSet::new();
+        for (insert, elem) in ops {
+            let same = if insert {
+                list.insert(elem) == set.insert(elem)
+            } else {
+                list.remove(&elem) == set.take(&elem)
+            };
+            if !same || list.contains(&elem) != set.contains(&elem) {
+                return false
+            }
+        }
+        list.len() == set.len() && list.iter().eq(set.iter()) &&
+        list.first() == set.iter().next() &&
+        list.last() == set.iter().next_back()
+    }
+}
+
+quickcheck! {
+    fn collects_in_order(elems: Vec<i16>) -> bool {
+        let set = elems.iter().cloned().collect::<BTreeSet<_>>();
+        let list = elems.into_iter().collect::<SkipList<_>>();
+        list.clone().into_iter().eq(set.into_iter()) && list.clone() == list
+    }
+}
+
+#[test]
+fn reuses_free_slots() {
+    let mut list = (0..100).collect::<SkipList<u32>>();
+    let slots = list.slots.len();
+    for i in (0..100).filter(|i| i % 3 == 0) {
+        assert_eq!(list.remove(&i), Some(i));
+    }
+    assert_eq!(list.remove(&3), None);
+    for i in (0..100).filter(|i| i % 3 == 0) {
+        assert!(list.insert(i));
+    }
+    assert_eq!(list.slots.len(), slots);
+    assert!(list.iter().cloned().eq(0..100));
+}
+
+#[test]
+fn pop_first_and_clear() {
+    let mut list = vec![3, 1, 2].into_iter().collect::<SkipList<_>>();
+    assert_eq!(list.pop_first(), Some(1));
+    assert_eq!(list.first(), Some(&2));
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.pop_first(), None);
+    assert_eq!(list.last(), None);
+    assert_eq!(format!("{:?}", list), "{}");
+}
@@ -0,0 +1,269 @@
+//! Saving and restoring zippers in a compact binary format.
+//!
+//! [`ZipList::write_snapshot`] writes a zipper's elements and cursor
+//! position, and [`ZipList::read_snapshot`] reads them back. Elements are
+//! written with the [`Snapshot`] trait, which is implemented for primitive
+//! types, strings, and a few containers, and can be implemented for others.
+//!
+//! A snapshot is laid out as:
+//!
+//! - the number of elements to the left of the cursor, as a little-endian
+//!   `u64`,
+//! - the number of elements to the right of the cursor, in the same way,
+//! - then every element, from left to right.
+//!
+//! Snapshots are written and read with one call to the `Write` or `Read`
+//! per primitive value, so they should usually be given a `BufWriter` or
+//! `BufReader`.
+//!
+//! Lists can also be streamed with [`List::write_to`] and
+//! [`List::read_from`], which take the element encoding as a closure rather
+//! than a `Snapshot` impl, and never buffer the list's elements.
+//!
+//! [`ZipList::write_snapshot`]: ../struct.ZipList.html#method.write_snapshot
+//! [`ZipList::read_snapshot`]: ../struct.ZipList.html#method.read_snapshot
+//! [`List::write_to`]: ../struct.List.html#method.write_to
+//! [`List::read_from`]: ../struct.List.html#method.read_from
+//! [`Snapshot`]: trait.Snapshot.html
+use std::io::{self, Read, Write};
+
+use super::{List, Stack, ZipList, ZipListBuilder};
+
+#[cfg(test)] mod test;
+
+/// Trait for values which can be written to and read from a snapshot.
+pub trait Snapshot: Sized {
+    /// Write `self` to `w`.
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+
+    /// Read a value written by `encode` from `r`.
+    ///
+    /// This should fail with `io::ErrorKind::InvalidData` if the data can't
+    /// have been written by `encode`.
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self>;
+}
+
+/// Returns an `InvalidData` error with the message `msg`.
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Read a length written as a `u64`.
+fn decode_len<R: Read>(r: &mut R) -> io::Result<usize> {
+    let len = u64::decode(r)?;
+    if len > usize::MAX as u64 { return Err(invalid("length too large")) }
+    Ok(len as usize)
+}
+
+macro_rules! le_bytes {
+    ($($t:ty),*) => {
+        $( impl Snapshot for $t {
+            #[inline] fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                w.write_all(&self.to_le_bytes())
+            }
+            #[inline] fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+                let mut bytes = [0; ::std::mem::size_of::<$t>()];
+                r.read_exact(&mut bytes)?;
+                Ok(<$t>::from_le_bytes(bytes))
+            }
+        } )*
+    }
+}
+
+le_bytes!{ u8, u16, u32, u64, u128
+         , i8, i16, i32, i64, i128
+         , f32, f64
+         }
+
+impl Snapshot for usize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u64).encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> { decode_len(r) }
+}
+
+impl Snapshot for isize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as i64).encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let n = i64::decode(r)?;
+        if n < isize::MIN as i64 || n > isize::MAX as i64 {
+            return Err(invalid("isize out of range"))
+        }
+        Ok(n as isize)
+    }
+}
+
+impl Snapshot for bool {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u8).encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        match u8::decode(r)? {
+            0 => Ok(false)
+          , 1 => Ok(true)
+          , _ => Err(invalid("invalid bool"))
+        }
+    }
+}
+
+impl Snapshot for char {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u32).encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        ::std::char::from_u32(u32::decode(r)?)
+            .ok_or_else(|| invalid("invalid char"))
+    }
+}
+
+impl Snapshot for String {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.len().encode(w)?;
+        w.write_all(self.as_bytes())
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        // don't trust the length enough to allocate it all up front
+        let mut bytes = Vec::new();
+        r.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < len {
+            return Err(io::Error::new( io::ErrorKind::UnexpectedEof
+                                     , "string shorter than its length" ))
+        }
+        String::from_utf8(bytes).map_err(|_| invalid("invalid UTF-8"))
+    }
+}
+
+impl<T> Snapshot for Option<T>
+where T: Snapshot {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        match *self {
+            Some(ref value) => { 1u8.encode(w)?; value.encode(w) }
+          , None => 0u8.encode(w)
+        }
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        if bool::decode(r)? { T::decode(r).map(Some) } else { Ok(None) }
+    }
+}
+
+impl<T> Snapshot for Box<T>
+where T: Snapshot {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (**self).encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        T::decode(r).map(Box::new)
+    }
+}
+
+impl<T> Snapshot for Vec<T>
+where T: Snapshot {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.len().encode(w)?;
+        self.iter().try_for_each(|elem| elem.encode(w))
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        (0..len).map(|_| T::decode(r)).collect()
+    }
+}
+
+impl<A, B> Snapshot for (A, B)
+where A: Snapshot
+    , B: Snapshot {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.0.encode(w)?;
+        self.1.encode(w)
+    }
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        Ok((A::decode(r)?, B::decode(r)?))
+    }
+}
+
+impl<T> List<T> {
+    /// Stream this list's elements to `w`, in order, writing each one with
+    /// `encode`.
+    ///
+    /// The list's length is written first, as a little-endian `u64`, and is
+    /// the only thing written other than what `encode` writes. Nothing is
+    /// buffered, so this works for lists too large to copy into a `Vec`.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`), with O(1) extra memory
+    pub fn write_to<W, F>(&self, mut w: W, mut encode: F) -> io::Result<()>
+    where W: Write
+        , F: FnMut(&T, &mut dyn Write) -> io::Result<()> {
+        self.len().encode(&mut w)?;
+        for elem in self {
+            encode(elem, &mut w)?;
+        }
+        w.flush()
+    }
+
+    /// Read a list written by [`write_to`](#method.write_to) from `r`,
+    /// reading each element with `decode`.
+    ///
+    /// Elements are pushed onto the list as they're read, without an
+    /// intermediate buffer, so the only memory used is the list's own.
+    ///
+    /// # Returns
+    /// - `Ok(List)` with the elements that were written, in order
+    /// - `Err(io::Error)` if `r` or `decode` fails, in which case the
+    ///   elements read so far are dropped
+    ///
+    /// # Time complexity
+    /// O(the number of elements)
+    pub fn read_from<R, F>(mut r: R, mut decode: F) -> io::Result<Self>
+    where R: Read
+        , F: FnMut(&mut dyn Read) -> io::Result<T> {
+        let len = decode_len(&mut r)?;
+        let mut list = List::new();
+        for _ in 0..len {
+            list.push(decode(&mut r)?);
+        }
+        // pushing stacks the elements up last-first
+        list.reverse();
+        Ok(list)
+    }
+}
+
+impl<T> ZipList<T>
+where T: Snapshot {
+    /// Write this zipper's elements and cursor position to `w`.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`), plus O(elements to the left of the cursor) extra
+    /// memory to write them in order.
+    pub fn write_snapshot<W: Write>(&self, mut w: W) -> io::Result<()> {
+        self.left.len().encode(&mut w)?;
+        self.right.len().encode(&mut w)?;
+        let left = self.left.iter().collect::<Vec<_>>();
+        for elem in left.into_iter().rev().chain(self.right.iter()) {
+            elem.encode(&mut w)?;
+        }
+        w.flush()
+    }
+
+    /// Read a zipper written by [`write_snapshot`](#method.write_snapshot)
+    /// from `r`.
+    ///
+    /// # Returns
+    /// - `Ok(ZipList)` with the same elements and cursor position as the
+    ///   zipper that was written
+    /// - `Err(io::Error)` if `r` fails, or doesn't contain a snapshot
+    ///
+    /// # Time complexity
+    /// O(the number of elements)
+    pub fn read_snapshot<R: Read>(mut r: R) -> io::Result<Self> {
+        let left = decode_len(&mut r)?;
+        let right = decode_len(&mut r)?;
+        let mut builder = ZipListBuilder::new();
+        for _ in 0..left { builder.push(T::decode(&mut r)?); }
+        builder.mark_cursor();
+        for _ in 0..right { builder.push(T::decode(&mut r)?); }
+        Ok(builder.build())
+    }
+}
@@ -0,0 +1,74 @@
+use std::io::{self, Read};
+
+use ::{List, ZipList};
+use super::Snapshot;
+
+fn round_trip<T>(zipper: &ZipList<T>) -> ZipList<T>
+where T: Snapshot {
+    let mut bytes = Vec::new();
+    zipper.write_snapshot(&mut bytes).unwrap();
+    ZipList::read_snapshot(&bytes[..]).unwrap()
+}
+
+quickcheck! {
+    fn snapshot_round_trips(zipper: ZipList<usize>) -> bool {
+        let read = round_trip(&zipper);
+        read.left_iter().eq(zipper.left_iter()) &&
+        read.right_iter().eq(zipper.right_iter())
+    }
+
+    fn snapshot_round_trips_strings(zipper: ZipList<(String, Option<i32>)>)
+                                    -> bool {
+        let read = round_trip(&zipper);
+        read.left_iter().eq(zipper.left_iter()) &&
+        read.right_iter().eq(zipper.right_iter())
+    }
+}
+
+#[test]
+fn snapshot_layout() {
+    let mut bytes = Vec::new();
+    zipper![1u8 ; 2u8, 3u8].write_snapshot(&mut bytes).unwrap();
+    assert_eq!(bytes, vec![ 1, 0, 0, 0, 0, 0, 0, 0
+                          , 2, 0, 0, 0, 0, 0, 0, 0
+                          , 1, 2, 3
+                          ]);
+}
+
+#[test]
+fn truncated_snapshot_fails() {
+    let mut bytes = Vec::new();
+    zipper![String::from("abc") ; String::from("def")]
+        .write_snapshot(&mut bytes).unwrap();
+    for len in 0..bytes.len() {
+        let err = ZipList::<String>::read_snapshot(&bytes[..len]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
+
+#[test]
+fn invalid_elements_fail() {
+    let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+    let err = ZipList::<bool>::read_snapshot(&bytes[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn list_streams_in_order() {
+    let list = (0..5u16).rev().collect::<List<_>>();
+    let mut bytes = Vec::new();
+    list.write_to(&mut bytes, |elem, w| w.write_all(&elem.to_le_bytes()))
+        .unwrap();
+    assert_eq!(bytes.len(), 8 + 5 * 2);
+    assert_eq!(&bytes[8..12], &[0, 0, 1, 0]);
+    let decode = |r: &mut dyn Read| {
+        let mut elem = [0; 2];
+        r.read_exact(&mut elem)?;
+        Ok(u16::from_le_bytes(elem))
+    };
+    let read = List::read_from(&bytes[..], decode).unwrap();
+    assert_eq!(read.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+    let err = List::read_from(&bytes[..bytes.len() - 1], decode).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
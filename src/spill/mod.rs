@@ -0,0 +1,397 @@
+//! Zippers which page elements far from the cursor out to storage.
+//!
+//! A [`SpillZipList`] keeps only the elements near its cursor in memory.
+//! When either side of the cursor grows past twice the zipper's page length,
+//! the elements furthest from the cursor are encoded with [`Snapshot`] and
+//! written to a [`Storage`] as a page, and when the cursor runs out of
+//! elements in memory on one side, the nearest page on that side is read
+//! back in. Pages on each side form a stack, so they are always read back in
+//! the reverse of the order they were written in.
+//!
+//! Every operation which might page elements in or out can fail with the
+//! storage's I/O error. When it does, no elements are lost, and the zipper
+//! is left as it was before the failed page was written or read.
+//!
+//! [`FileStorage`] keeps pages in a file, such as a temporary file; other
+//! backends can be plugged in by implementing `Storage`.
+//!
+//! [`SpillZipList`]: struct.SpillZipList.html
+//! [`Snapshot`]: ../snapshot/trait.Snapshot.html
+//! [`Storage`]: trait.Storage.html
+//! [`FileStorage`]: struct.FileStorage.html
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{List, Stack, ZipList};
+use snapshot::Snapshot;
+
+#[cfg(test)] mod test;
+
+/// Somewhere to keep pages of elements paged out of a [`SpillZipList`].
+///
+/// [`SpillZipList`]: struct.SpillZipList.html
+pub trait Storage {
+    /// Store `page`, returning a key to load it with.
+    fn store(&mut self, page: &[u8]) -> io::Result<u64>;
+
+    /// Load the page stored with `key`, leaving it in storage.
+    ///
+    /// A page may be loaded again if decoding it failed, until it's
+    /// released.
+    fn load(&mut self, key: u64) -> io::Result<Vec<u8>>;
+
+    /// Release the page stored with `key`, once its elements have been read
+    /// back into memory. It won't be loaded again, so the storage may reuse
+    /// its space.
+    ///
+    /// If this fails, the page should still be there to load.
+    fn release(&mut self, key: u64) -> io::Result<()>;
+}
+
+/// Page storage in a file.
+///
+/// Pages are appended to the file, each preceded by its length. The file is
+/// truncated whenever every page written to it has been released, which
+/// happens whenever the cursor returns to a region with nothing paged out.
+#[derive(Debug)]
+pub struct FileStorage { file: File
+                       , end: u64
+                       , live: usize
+                       , /// The file to remove on drop, if it's temporary.
+                         path: Option<PathBuf>
+                       }
+
+impl FileStorage {
+    /// Store pages in `file`, which must be open for reading and writing.
+    ///
+    /// Anything already in `file` is overwritten.
+    pub fn new(file: File) -> Self {
+        FileStorage { file, end: 0, live: 0, path: None }
+    }
+
+    /// Store pages in a new file in the system's temporary directory, which
+    /// is removed when the `FileStorage` is dropped.
+    pub fn temp() -> io::Result<Self> {
+        static FILES: AtomicUsize = AtomicUsize::new(0);
+        let name = format!( "an-zipper-{}-{}.spill"
+                          , process::id()
+                          , FILES.fetch_add(1, Ordering::Relaxed) );
+        let path = env::temp_dir().join(name);
+        let file = OpenOptions::new().read(true)
+                                     .write(true)
+                                     .create_new(true)
+                                     .open(&path)?;
+        let mut storage = FileStorage::new(file);
+        storage.path = Some(path);
+        Ok(storage)
+    }
+}
+
+impl Storage for FileStorage {
+    fn store(&mut self, page: &[u8]) -> io::Result<u64> {
+        let key = self.end;
+        self.file.seek(SeekFrom::Start(key))?;
+        self.file.write_all(&(page.len() as u64).to_le_bytes())?;
+        self.file.write_all(page)?;
+        self.end += 8 + page.len() as u64;
+        self.live += 1;
+        Ok(key)
+    }
+
+    fn load(&mut self, key: u64) -> io::Result<Vec<u8>> {
+        let mut len = [0; 8];
+        self.file.seek(SeekFrom::Start(key))?;
+        self.file.read_exact(&mut len)?;
+        let mut page = vec![0; u64::from_le_bytes(len) as usize];
+        self.file.read_exact(&mut page)?;
+        Ok(page)
+    }
+
+    fn release(&mut self, _key: u64) -> io::Result<()> {
+        if self.live == 1 {
+            self.file.set_len(0)?;
+            self.end = 0;
+        }
+        self.live -= 1;
+        Ok(())
+    }
+}
+
+impl Drop for FileStorage {
+    fn drop(&mut self) {
+        if let Some(ref path) = self.path {
+            // there's nothing to be done if this fails
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// A page of elements in storage.
+#[derive(Debug)]
+struct Page { key: u64
+            , len: usize
+            }
+
+/// A zipper which pages elements far from its cursor out to a [`Storage`].
+///
+/// See the [module documentation](index.html) for details.
+///
+/// [`Storage`]: trait.Storage.html
+#[derive(Debug)]
+pub struct SpillZipList<T, S> { zipper: ZipList<T>
+                              , storage: S
+                              , page_len: usize
+                              , left_pages: Vec<Page>
+                              , right_pages: Vec<Page>
+                              , spilled: usize
+                              }
+
+/// Page out everything in `list` past the first `page_len` elements, if it
+/// has more than twice that many.
+///
+/// # Returns
+/// - the number of elements paged out
+fn spill<T, S>( list: &mut List<T>, pages: &mut Vec<Page>, storage: &mut S
+              , page_len: usize) -> io::Result<usize>
+where T: Snapshot
+    , S: Storage {
+    if list.len() <= 2 * page_len { return Ok(0) }
+    let page = list.split_off(page_len);
+    let mut bytes = Vec::new();
+    let stored = page.iter()
+                     .try_for_each(|elem| elem.encode(&mut bytes))
+                     .and_then(|_| storage.store(&bytes));
+    match stored {
+        Ok(key) => {
+            pages.push(Page { key, len: page.len() });
+            Ok(page.len())
+        }
+      , Err(err) => {
+            list.append(page);
+            Err(err)
+        }
+    }
+}
+
+/// Page the nearest page in `pages` back into `list`, if `list` is empty.
+///
+/// # Returns
+/// - the number of elements paged in
+fn fill<T, S>(list: &mut List<T>, pages: &mut Vec<Page>, storage: &mut S)
+              -> io::Result<usize>
+where T: Snapshot
+    , S: Storage {
+    if !list.is_empty() { return Ok(0) }
+    let page = match pages.last() {
+        Some(page) => page
+      , None => return Ok(0)
+    };
+    let bytes = storage.load(page.key)?;
+    let mut r = &bytes[..];
+    // decode the whole page before touching `list` or releasing the page, so
+    // a page that fails part way through is left in storage to try again.
+    let mut elems = List::new();
+    for _ in 0..page.len {
+        elems.push(T::decode(&mut r)?);
+    }
+    storage.release(page.key)?;
+    elems.reverse();
+    list.prepend(elems);
+    pages.pop();
+    Ok(list.len())
+}
+
+impl<T, S> SpillZipList<T, S>
+where T: Snapshot
+    , S: Storage {
+    /// Create a new empty zipper which keeps its pages in `storage`, paging
+    /// out `page_len` or more elements at a time.
+    ///
+    /// At most `4 * page_len` elements are kept in memory.
+    ///
+    /// # Panics
+    /// If `page_len` is zero.
+    pub fn new(storage: S, page_len: usize) -> Self {
+        assert!(page_len > 0, "page length must be non-zero");
+        SpillZipList { zipper: ZipList::new()
+                     , storage
+                     , page_len
+                     , left_pages: Vec::new()
+                     , right_pages: Vec::new()
+                     , spilled: 0
+                     }
+    }
+
+    /// Create a zipper from the elements and cursor position of `zipper`,
+    /// paging out everything far from the cursor to `storage` straight away.
+    ///
+    /// # Panics
+    /// If `page_len` is zero.
+    pub fn from_zipper(zipper: ZipList<T>, storage: S, page_len: usize)
+                       -> io::Result<Self> {
+        let mut spilling = SpillZipList::new(storage, page_len);
+        spilling.zipper = zipper;
+        spilling.spill_left()?;
+        spilling.spill_right()?;
+        Ok(spilling)
+    }
+
+    /// Returns the number of elements in the zipper, in memory or not.
+    #[inline] pub fn len(&self) -> usize { self.zipper.len() + self.spilled }
+
+    /// Returns true if there are no elements in the zipper.
+    #[inline] pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements currently in memory.
+    #[inline] pub fn resident(&self) -> usize { self.zipper.len() }
+
+    /// Borrow the storage pages are kept in.
+    #[inline] pub fn storage(&self) -> &S { &self.storage }
+
+    fn spill_left(&mut self) -> io::Result<()> {
+        let ZipList { ref mut left, .. } = self.zipper;
+        self.spilled += spill( left, &mut self.left_pages, &mut self.storage
+                             , self.page_len )?;
+        Ok(())
+    }
+
+    fn spill_right(&mut self) -> io::Result<()> {
+        let ZipList { ref mut right, .. } = self.zipper;
+        self.spilled += spill( right, &mut self.right_pages, &mut self.storage
+                             , self.page_len )?;
+        Ok(())
+    }
+
+    fn fill_left(&mut self) -> io::Result<()> {
+        let ZipList { ref mut left, .. } = self.zipper;
+        self.spilled -= fill(left, &mut self.left_pages, &mut self.storage)?;
+        Ok(())
+    }
+
+    fn fill_right(&mut self) -> io::Result<()> {
+        let ZipList { ref mut right, .. } = self.zipper;
+        self.spilled -= fill(right, &mut self.right_pages, &mut self.storage)?;
+        Ok(())
+    }
+
+    /// Borrow the element to the left of the cursor, paging it in if need be.
+    pub fn peek_left(&mut self) -> io::Result<Option<&T>> {
+        self.fill_left()?;
+        Ok(self.zipper.peek_left())
+    }
+
+    /// Borrow the element to the right of the cursor, paging it in if need
+    /// be.
+    pub fn peek_right(&mut self) -> io::Result<Option<&T>> {
+        self.fill_right()?;
+        Ok(self.zipper.peek_right())
+    }
+
+    /// Remove the element to the left of the cursor and return it.
+    pub fn pop_left(&mut self) -> io::Result<Option<T>> {
+        self.fill_left()?;
+        Ok(self.zipper.pop_left())
+    }
+
+    /// Remove the element to the right of the cursor and return it.
+    pub fn pop_right(&mut self) -> io::Result<Option<T>> {
+        self.fill_right()?;
+        Ok(self.zipper.pop_right())
+    }
+
+    /// Insert `elem` to the left of the cursor.
+    ///
+    /// `elem` is inserted even if paging out other elements fails.
+    pub fn push_left(&mut self, elem: T) -> io::Result<()> {
+        self.zipper.push_left(elem);
+        self.spill_left()
+    }
+
+    /// Insert `elem` to the right of the cursor.
+    ///
+    /// `elem` is inserted even if paging out other elements fails.
+    pub fn push_right(&mut self, elem: T) -> io::Result<()> {
+        self.zipper.push_right(elem);
+        self.spill_right()
+    }
+
+    /// Move the cursor one position to the left.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the cursor was moved
+    /// - `Ok(false)` if it was already at the left end
+    /// - `Err(io::Error)` if paging failed, in which case the cursor may
+    ///   have been moved
+    pub fn move_left(&mut self) -> io::Result<bool> {
+        self.seek_left(1).map(|moved| moved == 1)
+    }
+
+    /// Move the cursor one position to the right.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the cursor was moved
+    /// - `Ok(false)` if it was already at the right end
+    /// - `Err(io::Error)` if paging failed, in which case the cursor may
+    ///   have been moved
+    pub fn move_right(&mut self) -> io::Result<bool> {
+        self.seek_right(1).map(|moved| moved == 1)
+    }
+
+    /// Move the cursor up to `n` positions to the left, paging elements in
+    /// and out as it goes.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of positions moved
+    /// - `Err(io::Error)` if paging failed, in which case the cursor may
+    ///   have been moved part of the way
+    pub fn seek_left(&mut self, n: usize) -> io::Result<usize> {
+        let mut moved = 0;
+        while moved < n {
+            self.fill_left()?;
+            let step = self.zipper.seek_left(n - moved);
+            if step == 0 { break }
+            moved += step;
+            self.spill_right()?;
+        }
+        Ok(moved)
+    }
+
+    /// Move the cursor up to `n` positions to the right, paging elements in
+    /// and out as it goes.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the number of positions moved
+    /// - `Err(io::Error)` if paging failed, in which case the cursor may
+    ///   have been moved part of the way
+    pub fn seek_right(&mut self, n: usize) -> io::Result<usize> {
+        let mut moved = 0;
+        while moved < n {
+            self.fill_right()?;
+            let step = self.zipper.seek_right(n - moved);
+            if step == 0 { break }
+            moved += step;
+            self.spill_left()?;
+        }
+        Ok(moved)
+    }
+
+    /// Page every element back in, returning them as an ordinary zipper with
+    /// the same cursor position.
+    pub fn into_zipper(mut self) -> io::Result<ZipList<T>> {
+        while !self.left_pages.is_empty() {
+            let mut page = List::new();
+            fill(&mut page, &mut self.left_pages, &mut self.storage)?;
+            self.zipper.left.append(page);
+        }
+        while !self.right_pages.is_empty() {
+            let mut page = List::new();
+            fill(&mut page, &mut self.right_pages, &mut self.storage)?;
+            self.zipper.right.append(page);
+        }
+        Ok(self.zipper)
+    }
+}
@@ -0,0 +1,117 @@
+use std::cell::Cell;
+use std::io;
+
+use ::ZipList;
+use super::*;
+
+/// Storage which keeps pages in memory, and counts how many it holds.
+#[derive(Default)]
+struct Pages(Vec<Option<Vec<u8>>>);
+
+impl Pages {
+    fn live(&self) -> usize { self.0.iter().filter(|p| p.is_some()).count() }
+}
+
+impl Storage for Pages {
+    fn store(&mut self, page: &[u8]) -> io::Result<u64> {
+        self.0.push(Some(page.to_vec()));
+        Ok(self.0.len() as u64 - 1)
+    }
+
+    fn load(&mut self, key: u64) -> io::Result<Vec<u8>> {
+        Ok(self.0[key as usize].clone().expect("page loaded after release"))
+    }
+
+    fn release(&mut self, key: u64) -> io::Result<()> {
+        self.0[key as usize].take().expect("page released twice");
+        Ok(())
+    }
+}
+
+thread_local!(static FAIL_DECODE: Cell<bool> = const { Cell::new(false) });
+
+/// An element which can't be decoded while `FAIL_DECODE` is set.
+#[derive(Clone, Debug, PartialEq)]
+struct Flaky(u32);
+
+impl Snapshot for Flaky {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.0.encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        if FAIL_DECODE.with(Cell::get) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "flaky"))
+        }
+        u32::decode(r).map(Flaky)
+    }
+}
+
+fn contents<S: Storage>(zipper: SpillZipList<u32, S>) -> (Vec<u32>, usize) {
+    ::test::contents(&zipper.into_zipper().unwrap())
+}
+
+#[test]
+fn pages_out_far_elements() {
+    let mut zipper = SpillZipList::new(Pages::default(), 4);
+    for i in 0..100 { zipper.push_left(i).unwrap(); }
+    assert_eq!(zipper.len(), 100);
+    assert!(zipper.resident() <= 8);
+    assert_eq!(zipper.seek_left(60).unwrap(), 60);
+    assert!(zipper.resident() <= 16);
+    assert_eq!(zipper.peek_left().unwrap(), Some(&39));
+    assert_eq!(zipper.pop_right().unwrap(), Some(40));
+    assert_eq!(zipper.seek_left(100).unwrap(), 40);
+    assert!(!zipper.move_left().unwrap());
+    assert!(zipper.move_right().unwrap());
+    assert_eq!(zipper.len(), 99);
+    let expected = (0..100).filter(|&i| i != 40).collect::<Vec<_>>();
+    assert_eq!(contents(zipper), (expected, 1));
+}
+
+#[test]
+fn from_zipper_round_trips() {
+    let zipper = ZipList::from_iter_with_cursor(0..50, 20);
+    let spilling = SpillZipList::from_zipper(zipper, Pages::default(), 3)
+        .unwrap();
+    assert!(spilling.resident() <= 6);
+    assert!(spilling.storage().live() > 0);
+    assert_eq!(contents(spilling), ((0..50).collect(), 20));
+}
+
+#[test]
+fn file_storage_is_reused_and_removed() {
+    let mut storage = FileStorage::temp().unwrap();
+    let path = storage.path.clone().unwrap();
+    let a = storage.store(b"hello").unwrap();
+    let b = storage.store(b"").unwrap();
+    assert_eq!(storage.load(b).unwrap(), b"");
+    storage.release(b).unwrap();
+    assert_eq!(storage.load(a).unwrap(), b"hello");
+    assert_eq!(storage.load(a).unwrap(), b"hello");
+    storage.release(a).unwrap();
+    assert_eq!(storage.store(b"again").unwrap(), 0);
+
+    let mut zipper = SpillZipList::new(storage, 2);
+    for i in 0..20 { zipper.push_right(i).unwrap(); }
+    zipper.seek_right(15).unwrap();
+    assert_eq!(contents(zipper), ((0..20).rev().collect(), 15));
+    assert!(!path.exists());
+}
+
+#[test]
+fn failed_decode_keeps_page() {
+    let zipper = ZipList::from_iter_with_cursor((0..20).map(Flaky), 20);
+    let storage = FileStorage::temp().unwrap();
+    let mut spilling = SpillZipList::from_zipper(zipper, storage, 2).unwrap();
+    assert_eq!(spilling.resident(), 2);
+    FAIL_DECODE.with(|fail| fail.set(true));
+    let err = spilling.seek_left(5).unwrap_err();
+    FAIL_DECODE.with(|fail| fail.set(false));
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(spilling.len(), 20);
+    assert_eq!(spilling.peek_left().unwrap(), Some(&Flaky(17)));
+    let zipper = spilling.into_zipper().unwrap();
+    assert_eq!(zipper.left_iter().len(), 18);
+    assert!(zipper.iter().cloned().eq((0..20).map(Flaky)));
+}
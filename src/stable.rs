@@ -0,0 +1,261 @@
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use error::AnchoredError;
+use super::{List, Stack, ZipList};
+
+/// A zipper list whose elements can be held onto with [`Anchor`]s, which
+/// stay valid however the cursor moves and whatever is pushed around them.
+///
+/// A plain reference into a `ZipList` borrows the whole zipper, so the
+/// cursor can't move while it's held. An anchor shares ownership of one
+/// element instead, so it doesn't borrow the zipper at all. While an element
+/// has anchors, the zipper won't remove it: popping it returns an
+/// [`AnchoredError`], and it can't be borrowed mutably.
+///
+/// ```
+/// # use an_zipper::{StableZipList, ZipList};
+/// let mut zipper = StableZipList::from(ZipList::from_iter_with_cursor(
+///     vec!["a", "b", "c"], 1));
+/// let b = zipper.anchor_right().unwrap();
+/// zipper.seek_right(2);
+/// zipper.push_left("d");
+/// assert_eq!(*b, "b");
+/// zipper.seek_left(2);
+/// assert!(zipper.pop_left().is_err());
+/// drop(b);
+/// assert_eq!(zipper.pop_left(), Ok(Some("b")));
+/// ```
+///
+/// [`Anchor`]: struct.Anchor.html
+/// [`AnchoredError`]: error/struct.AnchoredError.html
+pub struct StableZipList<T> { zipper: ZipList<Rc<T>> }
+
+/// A handle to an element of a [`StableZipList`], which keeps it from being
+/// removed.
+///
+/// This is created by [`anchor_left`] or [`anchor_right`], and derefs to the
+/// element.
+///
+/// [`StableZipList`]: struct.StableZipList.html
+/// [`anchor_left`]: struct.StableZipList.html#method.anchor_left
+/// [`anchor_right`]: struct.StableZipList.html#method.anchor_right
+pub struct Anchor<T>(Rc<T>);
+
+impl<T> StableZipList<T> {
+    /// Create a new empty `StableZipList`.
+    pub const fn new() -> Self { StableZipList { zipper: ZipList::new() } }
+
+    /// Returns the number of elements in the zipper.
+    #[inline] pub fn len(&self) -> usize { self.zipper.len() }
+
+    #[inline] pub fn is_empty(&self) -> bool { self.zipper.is_empty() }
+
+    /// Returns the number of elements to the left of the cursor.
+    #[inline] pub fn cursor(&self) -> usize { self.zipper.left.len() }
+
+    /// Borrow the element to the left of the cursor, if there is one.
+    pub fn peek_left(&self) -> Option<&T> {
+        self.zipper.peek_left().map(|elem| &**elem)
+    }
+
+    /// Borrow the element to the right of the cursor, if there is one.
+    pub fn peek_right(&self) -> Option<&T> {
+        self.zipper.peek_right().map(|elem| &**elem)
+    }
+
+    /// Mutably borrow the element to the left of the cursor, if there is one
+    /// and it has no anchors.
+    pub fn peek_left_mut(&mut self) -> Option<&mut T> {
+        self.zipper.peek_left_mut().and_then(Rc::get_mut)
+    }
+
+    /// Mutably borrow the element to the right of the cursor, if there is
+    /// one and it has no anchors.
+    pub fn peek_right_mut(&mut self) -> Option<&mut T> {
+        self.zipper.peek_right_mut().and_then(Rc::get_mut)
+    }
+
+    /// Returns an anchor to the element to the left of the cursor, if there
+    /// is one.
+    pub fn anchor_left(&self) -> Option<Anchor<T>> {
+        self.zipper.peek_left().map(|elem| Anchor(elem.clone()))
+    }
+
+    /// Returns an anchor to the element to the right of the cursor, if there
+    /// is one.
+    pub fn anchor_right(&self) -> Option<Anchor<T>> {
+        self.zipper.peek_right().map(|elem| Anchor(elem.clone()))
+    }
+
+    /// Push `elem` to the left of the cursor.
+    pub fn push_left(&mut self, elem: T) -> &mut Self {
+        self.zipper.push_left(Rc::new(elem));
+        self
+    }
+
+    /// Push `elem` to the right of the cursor.
+    pub fn push_right(&mut self, elem: T) -> &mut Self {
+        self.zipper.push_right(Rc::new(elem));
+        self
+    }
+
+    /// Remove the element to the left of the cursor, if there is one.
+    ///
+    /// # Returns
+    /// - `Ok(Some(T))` if an element was removed
+    /// - `Ok(None)` if the cursor is at the left end
+    /// - `Err(AnchoredError)` if the element has anchors, in which case it
+    ///   isn't removed
+    pub fn pop_left(&mut self) -> Result<Option<T>, AnchoredError> {
+        take(&mut self.zipper.left)
+    }
+
+    /// Remove the element to the right of the cursor, if there is one.
+    ///
+    /// # Returns
+    /// - `Ok(Some(T))` if an element was removed
+    /// - `Ok(None)` if the cursor is at the right end
+    /// - `Err(AnchoredError)` if the element has anchors, in which case it
+    ///   isn't removed
+    pub fn pop_right(&mut self) -> Result<Option<T>, AnchoredError> {
+        take(&mut self.zipper.right)
+    }
+
+    /// Move the cursor one position to the left.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved
+    /// - `false` if it's already at the left end
+    #[inline] pub fn move_left(&mut self) -> bool { self.zipper.move_left() }
+
+    /// Move the cursor one position to the right.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved
+    /// - `false` if it's already at the right end
+    #[inline] pub fn move_right(&mut self) -> bool {
+        self.zipper.move_right()
+    }
+
+    /// Move the cursor up to `n` positions to the left, returning how far
+    /// it moved.
+    #[inline] pub fn seek_left(&mut self, n: usize) -> usize {
+        self.zipper.seek_left(n)
+    }
+
+    /// Move the cursor up to `n` positions to the right, returning how far
+    /// it moved.
+    #[inline] pub fn seek_right(&mut self, n: usize) -> usize {
+        self.zipper.seek_right(n)
+    }
+
+    /// Returns an iterator over the elements to the left of the cursor,
+    /// nearest first.
+    pub fn left_iter(&self) -> impl Iterator<Item = &T> {
+        self.zipper.left_iter().map(|elem| &**elem)
+    }
+
+    /// Returns an iterator over the elements to the right of the cursor,
+    /// nearest first.
+    pub fn right_iter(&self) -> impl Iterator<Item = &T> {
+        self.zipper.right_iter().map(|elem| &**elem)
+    }
+
+    /// Consume the `StableZipList`, returning a `ZipList` of its elements
+    /// with the cursor in the same place.
+    ///
+    /// Elements that still have anchors are cloned, and the anchors keep
+    /// the originals.
+    pub fn into_zipper(self) -> ZipList<T>
+    where T: Clone {
+        let ZipList { left, right, committed, generation, consumed } =
+            self.zipper;
+        ZipList { left: unwrap_all(left)
+                , right: unwrap_all(right)
+                , committed
+                , generation
+                , consumed
+                }
+    }
+}
+
+/// Pop the top of `list`, unless it has anchors.
+fn take<T>(list: &mut List<Rc<T>>) -> Result<Option<T>, AnchoredError> {
+    let anchors = match list.peek() {
+        None => return Ok(None)
+      , Some(elem) => Rc::strong_count(elem) - 1
+    };
+    if anchors > 0 { return Err(AnchoredError { anchors }) }
+    let elem = list.pop().expect("peeked an element");
+    match Rc::try_unwrap(elem) {
+        Ok(elem) => Ok(Some(elem))
+      , Err(_) => unreachable!("element has no anchors")
+    }
+}
+
+/// Take the elements of `list` out of their `Rc`s, in the same order,
+/// cloning any that are shared.
+fn unwrap_all<T: Clone>(list: List<Rc<T>>) -> List<T> {
+    let mut unwrapped = list.into_iter()
+        .map(|elem| Rc::try_unwrap(elem).unwrap_or_else(|rc| (*rc).clone()))
+        .collect::<List<_>>();
+    unwrapped.reverse();
+    unwrapped
+}
+
+impl<T> From<ZipList<T>> for StableZipList<T> {
+    /// Wrap the elements of `zipper` so that they can be anchored, keeping
+    /// the cursor in the same place.
+    fn from(zipper: ZipList<T>) -> Self {
+        let ZipList { left, right, committed, generation, consumed } = zipper;
+        let wrap = |list: List<T>| {
+            let mut wrapped = list.into_iter().map(Rc::new)
+                                  .collect::<List<_>>();
+            wrapped.reverse();
+            wrapped
+        };
+        StableZipList { zipper: ZipList { left: wrap(left)
+                                         , right: wrap(right)
+                                         , committed
+                                         , generation
+                                         , consumed
+                                         }
+                      }
+    }
+}
+
+impl<T> Default for StableZipList<T> {
+    fn default() -> Self { StableZipList::new() }
+}
+
+impl<T> fmt::Debug for StableZipList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.zipper, f)
+    }
+}
+
+impl<T> Anchor<T> {
+    /// Returns true if `self` and `other` anchor the same element.
+    #[inline] pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Clone for Anchor<T> {
+    #[inline] fn clone(&self) -> Self { Anchor(self.0.clone()) }
+}
+
+impl<T> Deref for Anchor<T> {
+    type Target = T;
+    #[inline] fn deref(&self) -> &T { &self.0 }
+}
+
+impl<T> fmt::Debug for Anchor<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Anchor").field(&*self.0).finish()
+    }
+}
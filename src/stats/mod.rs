@@ -0,0 +1,86 @@
+//! Counting the operations performed on lists and zippers.
+//!
+//! With the `stats` feature, every `List` keeps a running count of the
+//! elements pushed onto and popped off it, the nodes it allocated, and the
+//! cursor moves made by a zipper it's one side of. A zipper's counts are
+//! the sum of its two sides'.
+//!
+//! The counts are plain integers updated in place, so they cost an add per
+//! operation and nothing at all without the feature.
+//!
+//! ```
+//! # use an_zipper::ZipList;
+//! let mut zipper = ZipList::from_iter_with_cursor(0..10, 5);
+//! zipper.reset_stats();
+//! zipper.seek_left(3);
+//! zipper.push_right(20);
+//! let stats = zipper.stats();
+//! assert_eq!((stats.seeks, stats.moved, stats.pushes), (1, 3, 1));
+//! ```
+use std::ops;
+
+use super::{List, ZipList};
+
+#[cfg(test)] mod test;
+
+/// Counts of the operations performed on a list or zipper since it was
+/// created or its counts were last reset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stats { /// The number of elements added, however they were
+                   /// added.
+                   pub pushes: u64
+                 , /// The number of elements removed with their nodes kept
+                   /// for reuse, as by `pop` or `drain`. Elements freed
+                   /// along with their nodes, as by `truncate`, aren't
+                   /// counted.
+                   pub pops: u64
+                 , /// The number of calls which moved a zipper's cursor.
+                   /// Calls which didn't move it, because it was already
+                   /// at the end or they were asked to move 0 positions,
+                   /// aren't counted.
+                   pub seeks: u64
+                 , /// The number of nodes moved from one side of a zipper
+                   /// to the other by those calls.
+                   pub moved: u64
+                 , /// The number of nodes allocated, rather than reused
+                   /// from the freelist.
+                   pub allocations: u64
+                 }
+
+impl Stats {
+    /// All counts at zero.
+    pub(crate) const fn new() -> Self {
+        Stats { pushes: 0, pops: 0, seeks: 0, moved: 0, allocations: 0 }
+    }
+}
+
+impl ops::Add for Stats {
+    type Output = Stats;
+    fn add(self, other: Stats) -> Stats {
+        Stats { pushes: self.pushes + other.pushes
+              , pops: self.pops + other.pops
+              , seeks: self.seeks + other.seeks
+              , moved: self.moved + other.moved
+              , allocations: self.allocations + other.allocations
+              }
+    }
+}
+
+impl<T> List<T> {
+    /// Returns the counts of operations performed on the list.
+    #[inline] pub fn stats(&self) -> Stats { self.stats }
+
+    /// Set all of the list's operation counts back to zero.
+    #[inline] pub fn reset_stats(&mut self) { self.stats = Stats::new() }
+}
+
+impl<T> ZipList<T> {
+    /// Returns the counts of operations performed on the zipper.
+    pub fn stats(&self) -> Stats { self.left.stats + self.right.stats }
+
+    /// Set all of the zipper's operation counts back to zero.
+    pub fn reset_stats(&mut self) {
+        self.left.reset_stats();
+        self.right.reset_stats();
+    }
+}
@@ -0,0 +1,46 @@
+use ::{List, Stack, ZipList};
+
+#[test]
+fn freelist_reuse_isnt_an_allocation() {
+    let mut list = List::new();
+    list.push(1).push(2);
+    list.pop();
+    list.push(3);
+    let stats = list.stats();
+    assert_eq!((stats.pushes, stats.pops, stats.allocations), (3, 1, 2));
+    list.reset_stats();
+    list.reserve(4);
+    assert_eq!(list.stats().allocations, 4);
+}
+
+#[test]
+fn seeks_count_nodes_moved() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..4, 0);
+    zipper.reset_stats();
+    zipper.seek_right(10);
+    assert!(!zipper.move_right());
+    zipper.seek_left(0);
+    zipper.move_left();
+    zipper.move_left();
+    let stats = zipper.stats();
+    assert_eq!((stats.seeks, stats.moved), (3, 6));
+    assert_eq!(stats.pushes + stats.pops + stats.allocations, 0);
+}
+
+#[test]
+fn commit_keeps_counts() {
+    let mut zipper = ZipList::new();
+    zipper.push_left(1).push_left(2);
+    zipper.commit_left();
+    assert_eq!(zipper.stats().pushes, 2);
+}
+
+#[test]
+fn built_lists_count_their_nodes() {
+    let list = List::from_fn(3, |i| i);
+    assert_eq!((list.stats().pushes, list.stats().allocations), (3, 3));
+    let clone = list.clone();
+    assert_eq!((clone.stats().pushes, clone.stats().allocations), (3, 3));
+    let mapped = clone.try_map(|elem| Ok::<_, ()>(elem * 2)).unwrap();
+    assert_eq!((mapped.stats().pushes, mapped.stats().allocations), (3, 3));
+}
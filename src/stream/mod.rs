@@ -0,0 +1,89 @@
+//! Asynchronous streams over lists and zippers, using [futures].
+//!
+//! A list's [`IntoIter`] is also a `Stream`, which is always ready. Draining
+//! a large zipper through an always-ready stream can keep a task busy for a
+//! long time without giving others a turn, so [`ZipList::into_stream`]
+//! returns a stream which yields back to the executor every so often.
+//!
+//! [futures]: https://docs.rs/futures
+//! [`IntoIter`]: ../list/struct.IntoIter.html
+//! [`ZipList::into_stream`]: ../struct.ZipList.html#method.into_stream
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+
+use super::{List, Stack, ZipList, list};
+
+#[cfg(test)] mod test;
+
+/// How many elements an `IntoStream` yields before returning `Pending` to
+/// let other tasks run.
+const BUDGET: usize = 32;
+
+impl<T> Stream for list::IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>)
+                 -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+/// A stream which takes the elements out of a `ZipList`, from left to
+/// right.
+///
+/// This is created by [`ZipList::into_stream`]. After every few elements,
+/// it wakes its task and returns `Pending` once, so that consuming a long
+/// zipper doesn't starve other tasks on the same executor.
+///
+/// [`ZipList::into_stream`]: ../struct.ZipList.html#method.into_stream
+pub struct IntoStream<T> { left: List<T>
+                         , right: List<T>
+                         , budget: usize
+                         }
+
+impl<T> ZipList<T> {
+    /// Consume the zipper, returning a stream of its elements from left to
+    /// right.
+    ///
+    /// # Time complexity
+    /// O(elements to the left of the cursor) to start, to put them in order,
+    /// and then O(1) per element.
+    pub fn into_stream(self) -> IntoStream<T> {
+        let ZipList { mut left, right, .. } = self;
+        left.reverse();
+        IntoStream { left, right, budget: BUDGET }
+    }
+}
+
+impl<T> Stream for IntoStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>)
+                 -> Poll<Option<T>> {
+        let this = self.get_mut();
+        if this.left.is_empty() && this.right.is_empty() {
+            return Poll::Ready(None)
+        }
+        if this.budget == 0 {
+            this.budget = BUDGET;
+            cx.waker().wake_by_ref();
+            return Poll::Pending
+        }
+        this.budget -= 1;
+        Poll::Ready(this.left.pop().or_else(|| this.right.pop()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.left.len() + self.right.len();
+        (len, Some(len))
+    }
+}
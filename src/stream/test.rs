@@ -0,0 +1,42 @@
+use std::task::{Context, Poll};
+
+use futures::executor::block_on;
+use futures::stream::{Stream, StreamExt};
+use futures::task::noop_waker;
+
+use ::{List, ZipList};
+
+#[test]
+fn list_streams_in_order() {
+    let list = (0..5).rev().collect::<List<_>>();
+    // `IntoIter` is an iterator too, so `collect` has to be disambiguated
+    let elems = block_on(StreamExt::collect::<Vec<_>>(list.into_iter()));
+    assert_eq!(elems, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn zipper_streams_left_to_right() {
+    let zipper = ZipList::from_iter_with_cursor(0..100, 40);
+    let stream = zipper.into_stream();
+    assert_eq!(stream.size_hint(), (100, Some(100)));
+    let elems = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(elems, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn zipper_stream_yields() {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut stream = ZipList::from_iter_with_cursor(0..100, 0).into_stream();
+    let mut pending = 0;
+    let mut elems = Vec::new();
+    loop {
+        match stream.poll_next_unpin(&mut cx) {
+            Poll::Ready(Some(elem)) => elems.push(elem)
+          , Poll::Ready(None) => break
+          , Poll::Pending => pending += 1
+        }
+    }
+    assert_eq!(elems.len(), 100);
+    assert_eq!(pending, 100 / super::BUDGET);
+}
@@ -0,0 +1,10 @@
+//! Data structures that can be shared between threads.
+//!
+//! The lock-free [`Stack`](struct.Stack.html) requires the `crossbeam-epoch`
+//! feature.
+#[cfg(feature = "crossbeam-epoch")] pub use self::stack::Stack;
+pub use self::zipper::{CursorGuard, ReadGuard, SharedZipList};
+
+#[cfg(feature = "crossbeam-epoch")] mod stack;
+mod zipper;
+#[cfg(test)] mod test;
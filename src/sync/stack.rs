@@ -0,0 +1,127 @@
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+
+/// A lock-free stack which can be pushed to and popped from by many threads
+/// at once.
+///
+/// This is a [Treiber stack]: `push` and `pop` swap the head of the stack
+/// with a single atomic compare-and-swap, retrying if another thread got
+/// there first. Popped nodes are freed using epoch-based reclamation, so a
+/// thread which is still looking at a node that was popped out from under it
+/// can't read freed memory.
+///
+/// Since any thread may pop the top item at any time, the top item can't be
+/// borrowed through a shared reference. That's why this doesn't implement
+/// the crate's [`Stack`](../trait.Stack.html) trait, whose `peek` would have
+/// nothing to return. [`peek_mut`](#method.peek_mut) has the stack to
+/// itself, so it can borrow the top item.
+///
+/// [Treiber stack]: https://en.wikipedia.org/wiki/Treiber_stack
+pub struct Stack<T> { head: Atomic<Node<T>> }
+
+struct Node<T> { elem: ManuallyDrop<T>
+               , next: Atomic<Node<T>>
+               }
+
+impl<T> Stack<T> {
+    /// Create a new empty `Stack`.
+    pub fn new() -> Self {
+        Stack { head: Atomic::null() }
+    }
+
+    /// Push `elem` to the stack.
+    ///
+    /// # Returns
+    /// `&Self` so that multiple `push`es can be chained.
+    pub fn push(&self, elem: T) -> &Self {
+        let mut node = Owned::new(Node { elem: ManuallyDrop::new(elem)
+                                       , next: Atomic::null()
+                                       });
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Relaxed, &guard);
+            node.next.store(head, Relaxed);
+            match self.head.compare_exchange(head, node, Release, Relaxed, &guard) {
+                Ok(_) => return self
+              , Err(err) => node = err.new
+            }
+        }
+    }
+
+    /// Remove the top item of the stack if it exists, returning it.
+    ///
+    /// # Returns
+    /// - `Some(T)` if an item was popped
+    /// - `None` if the stack is empty
+    pub fn pop(&self) -> Option<T> {
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Acquire, &guard);
+            let node = unsafe { head.as_ref() }?;
+            let next = node.next.load(Relaxed, &guard);
+            if self.head.compare_exchange(head, next, Relaxed, Relaxed, &guard)
+                   .is_ok() {
+                // this is safe because the successful CAS means that no
+                // other thread can pop this node, so we're the only one
+                // who'll move out of it or destroy it.
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Some(ManuallyDrop::into_inner(ptr::read(&node.elem)))
+                }
+            }
+        }
+    }
+
+    /// Borrow the top item of the stack mutably, if there is one.
+    ///
+    /// This takes `&mut self`, so no other thread can pop the item while
+    /// it's borrowed.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        // this is safe because `&mut self` means no other thread can be
+        // accessing the stack, so the top item can't be popped while it's
+        // borrowed.
+        unsafe {
+            let head = self.head.load(Relaxed, epoch::unprotected());
+            (head.as_raw() as *mut Node<T>).as_mut()
+                .map(|node| &mut *node.elem)
+        }
+    }
+
+    /// Returns true if there are no items on the stack.
+    ///
+    /// If other threads are pushing or popping, the answer may be out of date
+    /// by the time it's returned.
+    pub fn is_empty(&self) -> bool {
+        let guard = epoch::pin();
+        self.head.load(Acquire, &guard).is_null()
+    }
+}
+
+// items are only ever moved in and out of the stack, never shared, so it can
+// be shared between threads as long as the items can be sent between them.
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self { Stack::new() }
+}
+
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // this is safe because `&mut self` means no other thread can be
+        // accessing the stack.
+        unsafe {
+            let guard = epoch::unprotected();
+            let mut head = self.head.load(Relaxed, guard);
+            while let Some(node) = head.as_ref() {
+                let next = node.next.load(Relaxed, guard);
+                let mut node = head.into_owned();
+                ManuallyDrop::drop(&mut node.elem);
+                head = next;
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use std::thread;
+
+use super::SharedZipList;
+
+#[test]
+fn cursor_edits_are_visible_to_readers() {
+    let shared = SharedZipList::new();
+    {
+        let mut cursor = shared.cursor();
+        cursor.push_left(1).push_left(2);
+        cursor.move_left();
+    }
+    let reader = shared.read();
+    assert_eq!(reader.len(), 2);
+    assert_eq!(reader.peek_right(), Some(&2));
+}
+
+#[test]
+fn try_cursor_fails_while_reading() {
+    let shared = SharedZipList::<usize>::new();
+    let reader = shared.read();
+    assert!(shared.try_cursor().is_none());
+    drop(reader);
+    assert!(shared.try_cursor().is_some());
+}
+
+#[test]
+fn snapshots_are_consistent() {
+    let shared = Arc::new(SharedZipList::new());
+    let writer = {
+        let shared = shared.clone();
+        thread::spawn(move || {
+            for i in 0..1000usize {
+                // each edit pushes a pair of items, so a consistent snapshot
+                // always has an even length
+                let mut cursor = shared.cursor();
+                cursor.push_left(i).push_left(i);
+                cursor.seek_left(i % 3);
+            }
+        })
+    };
+    let readers = (0..4).map(|_| {
+        let shared = shared.clone();
+        thread::spawn(move || {
+            (0..1000).all(|_| shared.snapshot().len() % 2 == 0)
+        })
+    }).collect::<Vec<_>>();
+
+    writer.join().unwrap();
+    assert!(readers.into_iter().all(|reader| reader.join().unwrap()));
+    assert_eq!(shared.snapshot().len(), 2000);
+}
+
+#[cfg(feature = "crossbeam-epoch")]
+mod stack {
+    use std::sync::Arc;
+    use std::thread;
+
+    use sync::Stack;
+
+    const THREADS: usize = 4;
+    const PER_THREAD: usize = 1000;
+
+    #[test]
+    fn push_and_pop_same_item() {
+        let stack = Stack::new();
+        stack.push(1).push(2);
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn peek_mut_borrows_the_top_item() {
+        let mut stack = Stack::new();
+        stack.push(1).push(2);
+        *stack.peek_mut().unwrap() += 10;
+        assert_eq!(stack.pop(), Some(12));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.peek_mut(), None);
+    }
+
+    #[test]
+    fn concurrent_pushes_are_all_popped() {
+        let stack = Arc::new(Stack::new());
+        let producers = (0..THREADS).map(|t| {
+            let stack = stack.clone();
+            thread::spawn(move || {
+                for i in 0..PER_THREAD { stack.push(t * PER_THREAD + i); }
+            })
+        }).collect::<Vec<_>>();
+        for producer in producers { producer.join().unwrap(); }
+
+        let consumers = (0..THREADS).map(|_| {
+            let stack = stack.clone();
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                while let Some(elem) = stack.pop() { popped.push(elem); }
+                popped
+            })
+        }).collect::<Vec<_>>();
+        let mut popped = consumers.into_iter()
+            .flat_map(|consumer| consumer.join().unwrap())
+            .collect::<Vec<_>>();
+        popped.sort();
+
+        assert_eq!(popped, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn drop_drops_remaining_items() {
+        use std::rc::Rc;
+        let elem = Rc::new(());
+        {
+            let stack = Stack::new();
+            for _ in 0..10 { stack.push(elem.clone()); }
+            stack.pop();
+            assert_eq!(Rc::strong_count(&elem), 10);
+        }
+        assert_eq!(Rc::strong_count(&elem), 1);
+    }
+}
@@ -0,0 +1,103 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+                TryLockError};
+
+use ::ZipList;
+
+/// A `ZipList` which can be shared between threads.
+///
+/// At most one thread at a time may hold a [`CursorGuard`], which can move
+/// the cursor and edit the zipper. Any number of threads may hold a
+/// [`ReadGuard`] or take a [`snapshot`](#method.snapshot) while there is no
+/// cursor guard, and always see the zipper as the last cursor guard left it.
+///
+/// Every operation on a `ZipList` leaves it in a valid state, so if a thread
+/// panics while holding a guard, the zipper can still be used by other
+/// threads, and contains whatever edits were made before the panic.
+///
+/// [`CursorGuard`]: struct.CursorGuard.html
+/// [`ReadGuard`]: struct.ReadGuard.html
+pub struct SharedZipList<T> { zipper: RwLock<ZipList<T>> }
+
+/// Exclusive access to the cursor of a [`SharedZipList`].
+///
+/// This dereferences to the underlying `ZipList`. Other threads can't read
+/// the zipper until the guard is dropped.
+///
+/// [`SharedZipList`]: struct.SharedZipList.html
+pub struct CursorGuard<'a, T: 'a>(RwLockWriteGuard<'a, ZipList<T>>);
+
+/// Shared, read-only access to a [`SharedZipList`].
+///
+/// This dereferences to the underlying `ZipList`. The cursor can't be moved
+/// until the guard is dropped.
+///
+/// [`SharedZipList`]: struct.SharedZipList.html
+pub struct ReadGuard<'a, T: 'a>(RwLockReadGuard<'a, ZipList<T>>);
+
+impl<T> SharedZipList<T> {
+    /// Create a new empty `SharedZipList`.
+    pub fn new() -> Self { SharedZipList::from(ZipList::new()) }
+
+    /// Take the cursor, blocking until no other thread holds a guard.
+    pub fn cursor(&self) -> CursorGuard<'_, T> {
+        CursorGuard(self.zipper.write()
+                               .unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Take the cursor if no other thread holds a guard.
+    ///
+    /// # Returns
+    /// - `Some(CursorGuard)` if the cursor was taken
+    /// - `None` if another thread holds a guard
+    pub fn try_cursor(&self) -> Option<CursorGuard<'_, T>> {
+        match self.zipper.try_write() {
+            Ok(guard) => Some(CursorGuard(guard))
+          , Err(TryLockError::Poisoned(err)) =>
+                Some(CursorGuard(err.into_inner()))
+          , Err(TryLockError::WouldBlock) => None
+        }
+    }
+
+    /// Borrow the zipper for reading, blocking while another thread holds
+    /// the cursor.
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        ReadGuard(self.zipper.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Consume the `SharedZipList`, returning the zipper inside it.
+    pub fn into_inner(self) -> ZipList<T> {
+        self.zipper.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> SharedZipList<T>
+where T: Clone {
+    /// Returns a copy of the zipper, including its cursor position, as of
+    /// the last time a cursor guard was dropped.
+    pub fn snapshot(&self) -> ZipList<T> { self.read().clone() }
+}
+
+impl<T> Default for SharedZipList<T> {
+    fn default() -> Self { SharedZipList::new() }
+}
+
+impl<T> From<ZipList<T>> for SharedZipList<T> {
+    fn from(zipper: ZipList<T>) -> Self {
+        SharedZipList { zipper: RwLock::new(zipper) }
+    }
+}
+
+impl<'a, T> Deref for CursorGuard<'a, T> {
+    type Target = ZipList<T>;
+    #[inline] fn deref(&self) -> &ZipList<T> { &self.0 }
+}
+
+impl<'a, T> DerefMut for CursorGuard<'a, T> {
+    #[inline] fn deref_mut(&mut self) -> &mut ZipList<T> { &mut self.0 }
+}
+
+impl<'a, T> Deref for ReadGuard<'a, T> {
+    type Target = ZipList<T>;
+    #[inline] fn deref(&self) -> &ZipList<T> { &self.0 }
+}
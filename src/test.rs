@@ -0,0 +1,1412 @@
+//! Tests for `ZipList`, and for guarantees that apply across the whole
+//! crate, such as which types may be sent or shared between threads.
+use ::{EnumerateFromCursor, IntoChunks, KillRing, List, MoveToFrontList,
+      Position, ReadLeft, ReadRight, SlidingWindow, StableZipList, Stack,
+      TrackedZipper, ZipIter, ZipList, ZipListBuilder, list, skip, thin};
+use error::AnchoredError;
+use quickcheck::{Arbitrary, Gen};
+use std::sync::Arc;
+use std::thread;
+
+impl<T> Arbitrary for ZipList<T>
+where T: Arbitrary {
+
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        ZipList { left: List::arbitrary(g)
+                , right: List::arbitrary(g)
+                , committed: 0
+                , generation: 0
+                , consumed: 0 }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item=ZipList<T>>> {
+        // Shrink a zipper by shrinking each of its sides
+        let sides = (self.left.clone(), self.right.clone());
+        Box::new(sides.shrink()
+                      .map(|(left, right)| ZipList { left
+                                                   , right
+                                                   , committed: 0
+                                                   , generation: 0
+                                                   , consumed: 0 }))
+    }
+
+}
+
+/// Returns the elements of `zipper` in order, and the cursor position.
+pub(crate) fn contents<T: Clone>(zipper: &ZipList<T>) -> (Vec<T>, usize) {
+    joined(zipper.left_iter(), zipper.right_iter())
+}
+
+/// Returns the elements either side of a cursor in order, given those on
+/// the `left` nearest first and those on the `right`, and the cursor
+/// position.
+pub(crate) fn joined<'a, T, L, R>(left: L, right: R) -> (Vec<T>, usize)
+where T: Clone + 'a
+    , L: IntoIterator<Item = &'a T>
+    , R: IntoIterator<Item = &'a T> {
+    let mut elems = left.into_iter().cloned().collect::<Vec<_>>();
+    elems.reverse();
+    let cursor = elems.len();
+    elems.extend(right.into_iter().cloned());
+    (elems, cursor)
+}
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn lists_are_send_and_sync() {
+    assert_send::<List<usize>>();
+    assert_sync::<List<usize>>();
+    assert_send::<ZipList<usize>>();
+    assert_sync::<ZipList<usize>>();
+}
+
+#[test]
+fn iterators_are_send_and_sync() {
+    assert_send::<list::Iter<usize>>();
+    assert_sync::<list::Iter<usize>>();
+    assert_send::<list::IterMut<usize>>();
+    assert_sync::<list::IterMut<usize>>();
+    assert_send::<list::IntoIter<usize>>();
+    assert_sync::<list::IntoIter<usize>>();
+    assert_send::<list::Drain<usize>>();
+    assert_sync::<list::Drain<usize>>();
+    assert_send::<ZipIter<usize>>();
+    assert_sync::<ZipIter<usize>>();
+    assert_send::<EnumerateFromCursor<usize>>();
+    assert_sync::<EnumerateFromCursor<usize>>();
+    assert_send::<ReadLeft<usize>>();
+    assert_sync::<ReadLeft<usize>>();
+    assert_send::<ReadRight<usize>>();
+    assert_sync::<ReadRight<usize>>();
+    assert_send::<IntoChunks<usize>>();
+    assert_sync::<IntoChunks<usize>>();
+    assert_send::<thin::Iter<usize>>();
+    assert_sync::<thin::Iter<usize>>();
+    assert_send::<thin::IterMut<usize>>();
+    assert_sync::<thin::IterMut<usize>>();
+    assert_send::<thin::IntoIter<usize>>();
+    assert_sync::<thin::IntoIter<usize>>();
+    assert_send::<skip::Iter<usize>>();
+    assert_sync::<skip::Iter<usize>>();
+    assert_send::<skip::IntoIter<usize>>();
+    assert_sync::<skip::IntoIter<usize>>();
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_iterators_are_send_and_sync() {
+    use par;
+    assert_send::<par::Iter<usize>>();
+    assert_sync::<par::Iter<usize>>();
+    assert_send::<par::IntoIter<usize>>();
+    assert_sync::<par::IntoIter<usize>>();
+}
+
+#[cfg(feature = "crossbeam-epoch")]
+#[test]
+fn sync_stack_is_sync_for_send_items() {
+    use std::cell::Cell;
+    use sync;
+    assert_send::<sync::Stack<Cell<usize>>>();
+    assert_sync::<sync::Stack<Cell<usize>>>();
+}
+
+#[test]
+fn zipper_macro_places_cursor() {
+    let zipper = zipper![1, 2 ; 3, 4];
+    assert_eq!(contents(&zipper), (vec![1, 2, 3, 4], 2));
+    assert_eq!(contents(&zipper![; 1, 2]), (vec![1, 2], 0));
+    assert_eq!(contents(&zipper![1, 2 ;]), (vec![1, 2], 2));
+    let empty: ZipList<usize> = zipper![];
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn push_right_pushes_right_of_the_cursor() {
+    // `push_right` used to push onto the left of the cursor, like `push_left`
+    let mut zipper = ZipList::new();
+    zipper.push_left(1).push_right(3).push_right(2);
+    assert_eq!(zipper.peek_left(), Some(&1));
+    assert_eq!(zipper.peek_right(), Some(&2));
+    assert_eq!(contents(&zipper), (vec![1, 2, 3], 1));
+    assert_eq!(zipper.pop_right(), Some(2));
+    assert_eq!(zipper.pop_left(), Some(1));
+}
+
+#[test]
+fn entry_modifies_or_inserts() {
+    let mut zipper = zipper![1 ; 2];
+    *zipper.right_entry().and_modify(|n| *n *= 10).or_insert(0) += 1;
+    assert_eq!(contents(&zipper), (vec![1, 21], 1));
+
+    zipper.seek_right(1);
+    zipper.right_entry().and_modify(|n| *n *= 10).or_insert(5);
+    assert_eq!(contents(&zipper), (vec![1, 21, 5], 2));
+
+    zipper.left_entry().insert(3);
+    assert_eq!(contents(&zipper), (vec![1, 21, 3, 5], 3));
+    assert_eq!(zipper.left_entry().get(), Some(&3));
+}
+
+#[test]
+fn alternate_debug_marks_cursor() {
+    assert_eq!( format!("{:#?}", zipper![1, 2 ; 3])
+              , "[\n    1,\n    2,\n    >>> cursor <<<,\n    3,\n]");
+    assert_eq!( format!("{:#?}", ZipList::<usize>::new())
+              , "[\n    >>> cursor <<<,\n]");
+}
+
+#[test]
+fn position_kind_at_each_position() {
+    let mut zipper = zipper![; 1, 2];
+    assert!(zipper.is_at_start() && !zipper.is_at_end());
+    assert_eq!(zipper.position_kind(), Position::Start);
+    zipper.move_right();
+    assert_eq!(zipper.position_kind(), Position::Middle);
+    zipper.move_right();
+    assert!(!zipper.is_at_start() && zipper.is_at_end());
+    assert_eq!(zipper.position_kind(), Position::End);
+    assert_eq!(ZipList::<usize>::new().position_kind(), Position::Empty);
+}
+
+quickcheck! {
+    fn list_moves_between_threads(list: List<usize>, item: usize) -> bool {
+        let expected = list.len() + 1;
+        let list = thread::spawn(move || {
+            let mut list = list;
+            list.push(item);
+            list
+        }).join().unwrap();
+        list.len() == expected && list.peek() == Some(&item)
+    }
+
+    fn list_shared_between_threads(list: List<usize>) -> bool {
+        let expected = list.iter().cloned().collect::<Vec<_>>();
+        let list = Arc::new(list);
+        let threads = (0..4).map(|_| {
+            let list = list.clone();
+            thread::spawn(move || list.iter().cloned().collect::<Vec<_>>())
+        }).collect::<Vec<_>>();
+        threads.into_iter().all(|t| t.join().unwrap() == expected)
+    }
+
+    fn recycled_nodes_move_between_threads(list: List<usize>) -> bool {
+        let mut list = list;
+        let len = list.len();
+        while list.pop().is_some() { }
+        let list = thread::spawn(move || {
+            let mut list = list;
+            list.extend(0..len);
+            list
+        }).join().unwrap();
+        list.iter().cloned().eq((0..len).rev())
+    }
+
+    fn zip_list_moves_between_threads(items: Vec<usize>, n: usize) -> bool {
+        let mut zipper = ZipList::new();
+        for item in &items {
+            zipper.push_left(*item);
+        }
+        let zipper = thread::spawn(move || {
+            let mut zipper = zipper;
+            zipper.seek_left(n);
+            zipper
+        }).join().unwrap();
+        zipper.len() == items.len() &&
+        zipper.right_iter().count() == n.min(items.len())
+    }
+
+    fn seek_left_same_as_moves(zipper: ZipList<usize>, n: usize) -> bool {
+        let n = n % (zipper.len() + 2);
+        let mut seeked = zipper.clone();
+        let mut moved = zipper;
+        let amount = seeked.seek_left(n);
+        let moves = (0..n).take_while(|_| moved.move_left()).count();
+        amount == moves && contents(&seeked) == contents(&moved)
+    }
+
+    fn seek_right_same_as_moves(zipper: ZipList<usize>, n: usize) -> bool {
+        let n = n % (zipper.len() + 2);
+        let mut seeked = zipper.clone();
+        let mut moved = zipper;
+        let amount = seeked.seek_right(n);
+        let moves = (0..n).take_while(|_| moved.move_right()).count();
+        amount == moves && contents(&seeked) == contents(&moved)
+    }
+
+    fn absorb_inserts_at_cursor(zipper: ZipList<usize>, other: ZipList<usize>)
+                                -> bool {
+        let (mut expected, cursor) = contents(&zipper);
+        let (inserted, other_cursor) = contents(&other);
+        let tail = expected.split_off(cursor);
+        expected.extend(inserted);
+        expected.extend(tail);
+
+        let mut zipper = zipper;
+        zipper.absorb(other);
+        contents(&zipper) == (expected, cursor + other_cursor)
+    }
+
+    fn builder_places_cursor(items: Vec<usize>, cursor: usize) -> bool {
+        let cursor = cursor % (items.len() + 1);
+        let mut builder = ZipListBuilder::new();
+        builder.extend(items[..cursor].iter().cloned());
+        builder.mark_cursor();
+        builder.extend(items[cursor..].iter().cloned());
+        contents(&builder.build()) == (items, cursor)
+    }
+
+    fn builder_moves_cursor_when_remarked(items: Vec<usize>) -> bool {
+        let mut builder = ZipListBuilder::new();
+        for item in &items {
+            builder.mark_cursor().push(*item);
+        }
+        let expected = items.len().saturating_sub(1);
+        contents(&builder.build()) == (items, expected)
+    }
+
+    fn add_appends_at_right_end(a: ZipList<usize>, b: ZipList<usize>)
+                                -> bool {
+        let (mut expected, cursor) = contents(&a);
+        expected.extend(contents(&b).0);
+        contents(&(a + b)) == (expected, cursor)
+    }
+
+    fn try_seek_moves_all_or_nothing(zipper: ZipList<usize>, n: usize)
+                                     -> bool {
+        let n = n % (zipper.len() + 2);
+        let (elems, cursor) = contents(&zipper);
+        let mut left = zipper.clone();
+        let mut right = zipper;
+        let left_ok = match left.try_seek_left(n) {
+            Ok(()) => contents(&left) == (elems.clone(), cursor - n)
+          , Err(err) => n > cursor && err.moved == cursor &&
+                        contents(&left) == (elems.clone(), cursor)
+        };
+        let right_ok = match right.try_seek_right(n) {
+            Ok(()) => contents(&right) == (elems.clone(), cursor + n)
+          , Err(err) => n > elems.len() - cursor &&
+                        err.requested == n &&
+                        contents(&right) == (elems.clone(), cursor)
+        };
+        left_ok && right_ok
+    }
+
+    fn for_each_with_offset_matches_positions(zipper: ZipList<usize>) -> bool {
+        let (elems, cursor) = contents(&zipper);
+        let mut ok = true;
+        let mut visited = 0;
+        zipper.for_each_with_offset(|offset, elem| {
+            let index = if offset < 0 { cursor as isize + offset }
+                        else { cursor as isize + offset - 1 };
+            ok &= offset != 0 && elems[index as usize] == *elem;
+            visited += 1;
+        });
+        ok && visited == elems.len()
+    }
+
+    fn for_each_with_offset_mut_edits_in_place(zipper: ZipList<usize>)
+                                               -> bool {
+        let (elems, cursor) = contents(&zipper);
+        let mut zipper = zipper;
+        zipper.for_each_with_offset_mut(|offset, elem| {
+            *elem = offset as usize;
+        });
+        let expected = (0..elems.len())
+            .map(|i| if i < cursor { (i as isize - cursor as isize) as usize }
+                     else { i - cursor + 1 })
+            .collect::<Vec<_>>();
+        contents(&zipper) == (expected, cursor)
+    }
+
+    fn from_iter_with_cursor_places_cursor(items: Vec<usize>, cursor: usize)
+                                           -> bool {
+        let cursor = cursor % (items.len() + 2);
+        let zipper = ZipList::from_iter_with_cursor(items.clone(), cursor);
+        contents(&zipper) == (items.clone(), cursor.min(items.len()))
+    }
+
+    fn take_left_detaches_before_cursor(zipper: ZipList<usize>, n: usize)
+                                        -> bool {
+        let n = n % (zipper.len() + 2);
+        let (mut elems, cursor) = contents(&zipper);
+        let taken = cursor.min(n);
+        let expected_taken = elems.drain(cursor - taken..cursor)
+                                  .collect::<Vec<_>>();
+        let mut zipper = zipper;
+        let took = zipper.take_left(n);
+        contents(&took) == (expected_taken, taken) &&
+        contents(&zipper) == (elems, cursor - taken)
+    }
+
+    fn take_right_detaches_after_cursor(zipper: ZipList<usize>, n: usize)
+                                        -> bool {
+        let n = n % (zipper.len() + 2);
+        let (mut elems, cursor) = contents(&zipper);
+        let taken = (elems.len() - cursor).min(n);
+        let expected_taken = elems.drain(cursor..cursor + taken)
+                                  .collect::<Vec<_>>();
+        let mut zipper = zipper;
+        let took = zipper.take_right(n);
+        contents(&took) == (expected_taken, 0) &&
+        contents(&zipper) == (elems, cursor)
+    }
+}
+
+#[test]
+fn to_dot_highlights_cursor() {
+    let zipper = zipper![1, 2 ; 3];
+    assert_eq!(zipper.to_dot(), "digraph zipper {
+    rankdir=LR;
+    node [shape=record];
+    cursor [label=\"cursor|{left: 2|right: 1}\", style=filled, fillcolor=yellow];
+    left0 [label=\"<next>|<elem> 2\"];
+    left1 [label=\"<next>|<elem> 1\"];
+    left1:elem -> left0:next [dir=back];
+    right0 [label=\"<elem> 3|<next>\"];
+    left0:elem -> cursor [dir=back];
+    cursor -> right0:elem;
+}
+");
+}
+
+quickcheck! {
+    fn seek_to_sorted_position_finds_upper_bound( elems: Vec<usize>
+                                                , cursor: usize
+                                                , x: usize ) -> bool {
+        let mut elems = elems;
+        elems.sort();
+        let cursor = cursor % (elems.len() + 1);
+        let mut zipper = ZipList::from_iter_with_cursor(elems.clone(), cursor);
+        let moved = zipper.seek_to_sorted_position(&x);
+        let expected = elems.iter().filter(|&&elem| elem <= x).count();
+        let (after, new_cursor) = contents(&zipper);
+        after == elems && new_cursor == expected &&
+        moved == new_cursor.max(cursor) - new_cursor.min(cursor)
+    }
+}
+
+#[test]
+fn seek_to_sorted_position_keeps_insertion_order() {
+    let mut zipper = ZipList::new();
+    for &(key, n) in &[(2, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (2, 'e')] {
+        zipper.seek_to_sorted_position(&(key, n));
+        zipper.push_left((key, n));
+    }
+    assert_eq!( contents(&zipper).0
+              , vec![(1, 'b'), (2, 'a'), (2, 'c'), (2, 'e'), (3, 'd')] );
+}
+
+#[test]
+fn word_motion_skips_space_then_word() {
+    let text = "let x_1 = foo(bar);  ";
+    let mut zipper = ZipList::from_iter_with_cursor(text.chars(), 0);
+    let mut stops = Vec::new();
+    loop {
+        let moved = zipper.seek_word_right();
+        if moved == 0 { break }
+        stops.push(zipper.left_iter().len());
+    }
+    assert_eq!(stops, vec![3, 7, 9, 13, 14, 17, 19, 21]);
+
+    let mut stops = Vec::new();
+    while zipper.seek_word_left() > 0 {
+        stops.push(zipper.left_iter().len());
+    }
+    assert_eq!(stops, vec![17, 14, 13, 10, 8, 4, 0]);
+}
+
+/// Returns the line and column of `zipper`'s cursor, found by scanning.
+fn line_col(zipper: &ZipList<char>) -> (usize, usize) {
+    let line = zipper.left_iter().filter(|&&c| c == '\n').count();
+    let column = zipper.left_iter().take_while(|&&c| c != '\n').count();
+    (line, column)
+}
+
+quickcheck! {
+    fn tracked_zipper_line_col_matches_scan( start: ZipList<char>
+                                           , ops: Vec<(u8, usize)> ) -> bool {
+        let mut tracked = TrackedZipper::from(start);
+        for (op, n) in ops {
+            match op % 10 {
+                0 => { tracked.push_left('\n'); }
+              , 1 => { tracked.push_left('x'); }
+              , 2 => { tracked.push_right('\n'); }
+              , 3 => { tracked.pop_left(); }
+              , 4 => { tracked.pop_right(); }
+              , 5 => { tracked.seek_left(n % 8); }
+              , 6 => { tracked.seek_right(n % 8); }
+              , 7 => { tracked.seek_word_left(); }
+              , 8 => { tracked.seek_word_right(); }
+              , _ => { tracked.seek_to_line_col(n % 4, n % 7); }
+            }
+            if (tracked.line(), tracked.column()) != line_col(&tracked) {
+                return false
+            }
+        }
+        true
+    }
+}
+
+#[test]
+fn seek_to_line_col_clamps_to_line() {
+    let text = "ab\ncdef\n\ng";
+    let mut tracked = TrackedZipper::from(
+        ZipList::from_iter_with_cursor(text.chars(), 0));
+    assert!(tracked.seek_to_line_col(1, 3));
+    assert_eq!(tracked.peek_right(), Some(&'f'));
+    assert!(!tracked.seek_to_line_col(0, 5));
+    assert_eq!((tracked.line(), tracked.column()), (0, 2));
+    assert!(!tracked.seek_to_line_col(2, 1));
+    assert_eq!((tracked.line(), tracked.column()), (2, 0));
+    assert!(!tracked.seek_to_line_col(9, 0));
+    assert_eq!((tracked.line(), tracked.column()), (3, 1));
+    assert!(tracked.seek_to_line_col(0, 0));
+    assert!(tracked.is_at_start());
+}
+
+quickcheck! {
+    fn search_finds_nearest_match(text: Vec<bool>, cursor: usize
+                                 , needle: Vec<bool>) -> bool {
+        // a two-letter alphabet makes matches likely
+        let to_str = |bits: &[bool]| bits.iter()
+                                         .map(|&b| if b { 'a' } else { 'b' })
+                                         .collect::<String>();
+        let text = to_str(&text);
+        let needle = to_str(&needle[..needle.len() % 4]);
+        let cursor = cursor % (text.len() + 1);
+        let zipper = ZipList::from_iter_with_cursor(text.chars(), cursor);
+        let expected_right = text[cursor..].find(&needle);
+        let expected_left = if needle.is_empty() { Some(0) } else {
+            text[..(cursor + needle.len() - 1).min(text.len())]
+                .rfind(&needle)
+                .map(|start| cursor - start)
+        };
+        zipper.search_right(&needle) == expected_right &&
+        zipper.search_left(&needle) == expected_left
+    }
+}
+
+#[test]
+fn seek_search_moves_to_match() {
+    let text = "one two one two";
+    let mut zipper = ZipList::from_iter_with_cursor(text.chars(), 5);
+    assert_eq!(zipper.seek_search_left("two"), Some(1));
+    assert_eq!(zipper.left_iter().len(), 4);
+    assert_eq!(zipper.seek_search_right("one"), Some(4));
+    assert_eq!(zipper.left_iter().len(), 8);
+    assert_eq!(zipper.seek_search_right("three"), None);
+    assert_eq!(zipper.left_iter().len(), 8);
+    // a match spanning the cursor is found searching left
+    zipper.seek_right(2);
+    assert_eq!(zipper.search_left("one"), Some(2));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_search_across_cursor() {
+    use regex::Regex;
+    let text = "fn foo() { bar(baz) }";
+    let re = Regex::new(r"\b[a-z]+\(").unwrap();
+    // the cursor is in the middle of `bar(`
+    let mut zipper = ZipList::from_iter_with_cursor(text.chars(), 12);
+    assert_eq!(zipper.find_next(&re), None);
+    assert_eq!(zipper.find_prev(&re), Some(11..15));
+    assert_eq!(zipper.seek_find_prev(&re), Some(11..15));
+    assert_eq!(zipper.left_iter().len(), 11);
+    assert_eq!(zipper.seek_find_prev(&re), Some(3..7));
+    assert_eq!(zipper.seek_find_next(&re), Some(3..7));
+    zipper.move_right();
+    assert_eq!(zipper.seek_find_next(&re), Some(11..15));
+
+    let mut tracked = TrackedZipper::from(
+        ZipList::from_iter_with_cursor("a\nb√ c\nd√".chars(), 0));
+    let re = Regex::new("√").unwrap();
+    assert_eq!(tracked.seek_find_next(&re), Some(3..4));
+    assert_eq!((tracked.line(), tracked.column()), (1, 1));
+    tracked.move_right();
+    assert_eq!(tracked.seek_find_next(&re), Some(8..9));
+    assert_eq!((tracked.line(), tracked.column()), (2, 1));
+    assert_eq!(tracked.seek_find_prev(&re), Some(3..4));
+    assert_eq!((tracked.line(), tracked.column()), (1, 1));
+}
+
+quickcheck! {
+    fn cut_and_paste_round_trip(zipper: ZipList<usize>, n: usize) -> bool {
+        let (elems, cursor) = contents(&zipper);
+        let n = n % (zipper.len() + 2);
+        let mut zipper = zipper;
+        let cut = zipper.cut_left(n);
+        let taken = cursor.min(n);
+        let cut_ok = cut.iter().cloned().eq(elems[cursor - taken..cursor]
+                                            .iter().cloned());
+        zipper.paste(cut);
+        let left_ok = contents(&zipper) == (elems.clone(), cursor);
+
+        let cut = zipper.cut_right(n);
+        let taken = (elems.len() - cursor).min(n);
+        let cut_ok = cut_ok && cut.iter().cloned()
+                                  .eq(elems[cursor..cursor + taken]
+                                      .iter().cloned());
+        zipper.paste(cut);
+        cut_ok && left_ok && contents(&zipper) == (elems, cursor + taken)
+    }
+}
+
+#[test]
+fn kill_ring_cycles_through_kills() {
+    let mut zipper = ZipList::from_iter_with_cursor(vec![1, 2, 3, 4], 0);
+    let mut ring = KillRing::new(2);
+    assert!(!ring.yank(&mut zipper));
+    ring.kill(zipper.cut_right(1));
+    ring.kill(zipper.cut_right(1));
+    ring.kill(zipper.cut_right(1));
+    // the oldest kill was dropped
+    assert_eq!(ring.len(), 2);
+    assert_eq!( ring.latest().map(|kill| kill.iter().collect::<Vec<_>>())
+              , Some(vec![&3]) );
+
+    assert!(!ring.yank_pop(&mut zipper));
+    assert!(ring.yank(&mut zipper));
+    assert_eq!(contents(&zipper), (vec![3, 4], 1));
+    assert!(ring.yank_pop(&mut zipper));
+    assert_eq!(contents(&zipper), (vec![2, 4], 1));
+    assert!(ring.yank_pop(&mut zipper));
+    assert_eq!(contents(&zipper), (vec![3, 4], 1));
+
+    ring.kill(List::new());
+    assert!(!ring.yank_pop(&mut zipper));
+    assert_eq!(ring.len(), 2);
+}
+
+#[test]
+fn seek_to_matching_tracks_depth() {
+    let text = "f(a, (b), c) (";
+    let open = |&c: &char| c == '(';
+    let close = |&c: &char| c == ')';
+    let mut zipper = ZipList::from_iter_with_cursor(text.chars(), 1);
+    assert!(zipper.seek_to_matching(open, close));
+    assert_eq!(zipper.left_iter().len(), 11);
+    assert!(zipper.seek_to_matching(open, close));
+    assert_eq!(zipper.left_iter().len(), 1);
+    // not on a delimiter
+    zipper.move_left();
+    assert!(!zipper.seek_to_matching(open, close));
+    // unbalanced
+    zipper.seek_right(13);
+    assert!(!zipper.seek_to_matching(open, close));
+    assert_eq!(zipper.left_iter().len(), 13);
+
+    // quotes match the next quote
+    let quote = |&c: &char| c == '"';
+    let mut zipper = ZipList::from_iter_with_cursor("\"a\" \"b\"".chars(), 0);
+    assert!(zipper.seek_to_matching(quote, quote));
+    assert_eq!(zipper.left_iter().len(), 2);
+}
+
+#[test]
+fn commit_left_drops_consumed() {
+    let mut zipper = ZipList::from_iter_with_cursor(1..6, 3);
+    zipper.left.pop();
+    assert_eq!(zipper.commit_left(), 2);
+    assert!(zipper.is_at_start());
+    assert_eq!(zipper.memory_footprint(), ::std::mem::size_of::<ZipList<i32>>()
+               + zipper.right.heap_size_of_children());
+    assert_eq!(contents(&zipper), (vec![4, 5], 0));
+    assert_eq!(zipper.commit_left(), 0);
+}
+
+#[test]
+fn offset_survives_commit() {
+    let mut zipper = ZipList::from_iter_with_cursor("let x = 1;".chars(), 0);
+    zipper.seek_right(4);
+    assert_eq!(zipper.offset(), 4);
+    zipper.commit_left();
+    assert_eq!(zipper.offset(), 4);
+    let start = zipper.offset();
+    zipper.seek_right(5);
+    let slice = zipper.slice_consumed(start..zipper.offset());
+    assert_eq!(slice.iter().collect::<String>(), "x = 1");
+    assert_eq!(zipper.slice_consumed(6..8).iter().collect::<String>(), "= ");
+    assert!(zipper.slice_consumed(9..9).is_empty());
+    zipper.move_left();
+    assert_eq!(zipper.offset(), 8);
+}
+
+#[test]
+fn consumed_never_decreases() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..10, 0);
+    zipper.seek_right(4);
+    zipper.move_right();
+    assert_eq!(zipper.consumed(), 5);
+    zipper.seek_left(3);
+    zipper.pop_left();
+    zipper.commit_left();
+    assert_eq!((zipper.offset(), zipper.consumed()), (1, 5));
+    zipper.push_left(20);
+    zipper.seek_right_map(2, |elem| *elem += 1);
+    assert_eq!(zipper.consumed(), 7);
+    assert_eq!(zipper.skip_right_until(|&elem| elem == 8).len(), 4);
+    assert_eq!((zipper.offset(), zipper.consumed()), (8, 11));
+    zipper.truncate(0);
+    assert!(!zipper.move_right());
+    assert_eq!(zipper.consumed(), 11);
+}
+
+#[test]
+#[should_panic]
+fn slice_consumed_before_commit() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..10, 5);
+    zipper.commit_left();
+    zipper.slice_consumed(4..5);
+}
+
+#[test]
+fn skip_right_until_detaches() {
+    let mut zipper = ZipList::from_iter_with_cursor("a b; c;".chars(), 1);
+    let skipped = zipper.skip_right_until(|&c| c == ';');
+    assert_eq!(skipped.iter().collect::<String>(), " b");
+    assert_eq!(zipper.offset(), 3);
+    assert_eq!(contents(&zipper), ("a; c;".chars().collect(), 1));
+    // stops at a match right next to the zipper
+    assert!(zipper.skip_right_until(|&c| c == ';').is_empty());
+    zipper.move_right();
+    let skipped = zipper.skip_right_until(|&c| c == '!');
+    assert_eq!(skipped.iter().collect::<String>(), " c;");
+    assert!(zipper.is_at_end());
+    assert_eq!(zipper.offset(), 7);
+}
+
+quickcheck! {
+    fn into_chunks_preserves_order(zipper: ZipList<usize>, n: usize) -> bool {
+        let n = n % 5 + 1;
+        let (expected, _) = contents(&zipper);
+        let chunks = zipper.into_chunks(n);
+        let count = chunks.len();
+        let lists = chunks.collect::<Vec<_>>();
+        lists.len() == count &&
+        lists.iter().rev().skip(1).all(|chunk| chunk.len() == n) &&
+        lists.into_iter().flat_map(List::into_iter).eq(expected)
+    }
+}
+
+#[test]
+fn lines_round_trip() {
+    let text = "first\r\nsecond\n\nfourth";
+    let mut zipper = ZipList::from_reader(text.as_bytes()).unwrap();
+    assert!(zipper.is_at_start());
+    assert_eq!(zipper.right_iter().collect::<Vec<_>>()
+              , vec!["first", "second", "", "fourth"]);
+    zipper.seek_right(2);
+    let mut written = Vec::new();
+    zipper.write_lines(&mut written).unwrap();
+    assert_eq!(written, b"first\nsecond\n\nfourth\n");
+    assert!(ZipList::from_reader(&b""[..]).unwrap().is_empty());
+    assert_eq!(ZipList::from_reader(&b"\n"[..]).unwrap().len(), 1);
+}
+
+quickcheck! {
+    fn view_get_matches_contents(zipper: ZipList<usize>) -> bool {
+        let (elems, cursor) = contents(&zipper);
+        let view = zipper.as_view();
+        let copy = view;
+        view.cursor() == cursor && copy.len() == elems.len() &&
+        (0..elems.len() + 1).all(|i| view.get(i) == elems.get(i)) &&
+        (1..cursor + 1).all(|i| {
+            view.peek(-(i as isize)) == elems.get(cursor - i)
+        })
+    }
+}
+
+#[test]
+fn frozen_clones_share_and_thaw() {
+    let mut zipper = ZipList::from_iter_with_cursor(vec![1, 2, 3], 1);
+    zipper.pop_right();
+    zipper.push_right(2);
+    let frozen = zipper.freeze();
+    assert_eq!(frozen.memory_footprint()
+              , ::std::mem::size_of::<ZipList<i32>>()
+                + frozen.left.heap_size_of_children()
+                + frozen.right.heap_size_of_children());
+    let copy = frozen.clone();
+    assert!(copy.ptr_eq(&frozen));
+    let mut thawed = copy.thaw();
+    thawed.push_left(0);
+    assert_eq!(contents(&frozen), (vec![1, 2, 3], 1));
+    assert_eq!(contents(&thawed), (vec![1, 0, 2, 3], 2));
+    assert_eq!(contents(&frozen.thaw()), (vec![1, 2, 3], 1));
+}
+
+#[test]
+fn reserve_covers_both_sides() {
+    let mut zipper = ZipList::new();
+    zipper.reserve(3);
+    let size = zipper.memory_footprint();
+    for i in 0..3 { zipper.push_left(i).push_right(i); }
+    assert_eq!(zipper.memory_footprint(), size);
+    zipper.reserve_left(1);
+    assert!(zipper.memory_footprint() > size);
+}
+
+#[test]
+fn read_head_moves_cursor() {
+    let mut zipper = ZipList::from_iter_with_cursor("let x".chars(), 0);
+    assert_eq!(zipper.next_right(), Some(&'l'));
+    assert_eq!(zipper.next_left(), Some(&'l'));
+    assert_eq!(zipper.next_left(), None);
+    let word = zipper.read_right()
+                     .take_while(|c| c.is_alphabetic())
+                     .collect::<String>();
+    assert_eq!(word, "let");
+    // `take_while` consumed the space too
+    assert_eq!(zipper.left_iter().len(), 4);
+    assert_eq!(zipper.read_right().len(), 1);
+    assert_eq!(zipper.read_left().collect::<String>(), " tel");
+    assert!(zipper.is_at_start());
+}
+
+#[test]
+fn windows_mut_slides() {
+    use lending::LendingIterator;
+    let mut zipper = ZipList::from_iter_with_cursor(vec![1, 4, 2, 8, 5], 3);
+    // smooth each interior element with its neighbours, in place
+    let mut windows = zipper.windows_mut(3);
+    while let Some(window) = windows.next() {
+        *window[1] = (*window[0] + *window[1] + *window[2]) / 3;
+    }
+    assert_eq!(contents(&zipper), (vec![1, 2, 4, 5, 5], 3));
+    let mut windows = zipper.windows_mut(5);
+    assert!(windows.next().is_some());
+    assert!(windows.next().is_none());
+    assert!(zipper.windows_mut(6).next().is_none());
+}
+
+#[test]
+fn drain_around_cursor() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..10, 5);
+    let drained = zipper.drain(-3..2).collect::<Vec<_>>();
+    assert_eq!(drained, vec![2, 3, 4, 5, 6]);
+    assert_eq!(contents(&zipper), (vec![0, 1, 7, 8, 9], 2));
+    // a range on one side of the cursor
+    assert_eq!(zipper.drain(1..=2).collect::<Vec<_>>(), vec![8, 9]);
+    assert_eq!(zipper.drain(-2..-1).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(contents(&zipper), (vec![1, 7], 1));
+    zipper.drain(..);
+    assert!(zipper.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn drain_past_end_panics() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..4, 2);
+    zipper.drain(-3..0);
+}
+
+quickcheck! {
+    fn insert_at_matches_vec(zipper: ZipList<u8>, index: usize) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let index = index % (elems.len() + 1);
+        let mut zipper = zipper;
+        zipper.insert_at(index, 42);
+        elems.insert(index, 42);
+        let cursor = if index < cursor { cursor + 1 } else { cursor };
+        contents(&zipper) == (elems, cursor)
+    }
+
+    fn remove_at_matches_vec(zipper: ZipList<u8>, index: usize) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let index = index % (elems.len() + 1);
+        let mut zipper = zipper;
+        let removed = zipper.remove_at(index);
+        if index == elems.len() { return removed.is_none() }
+        let expected = elems.remove(index);
+        let cursor = if index < cursor { cursor - 1 } else { cursor };
+        removed == Some(expected) && contents(&zipper) == (elems, cursor)
+    }
+}
+
+#[test]
+fn insert_at_cursor_goes_right() {
+    let mut zipper = ZipList::from_iter_with_cursor(vec![1, 3], 1);
+    zipper.insert_at(1, 2);
+    assert_eq!(zipper.peek_right(), Some(&2));
+    zipper.insert_at(0, 0);
+    assert_eq!(contents(&zipper), (vec![0, 1, 2, 3], 2));
+}
+
+quickcheck! {
+    fn swap_matches_vec(zipper: ZipList<u8>, i: usize, j: usize) -> bool {
+        if zipper.is_empty() { return true }
+        let (mut elems, cursor) = contents(&zipper);
+        let (i, j) = (i % elems.len(), j % elems.len());
+        let mut zipper = zipper;
+        zipper.swap(i, j);
+        elems.swap(i, j);
+        contents(&zipper) == (elems, cursor)
+    }
+}
+
+#[test]
+fn as_lists_borrow_each_side() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..6, 2);
+    {
+        let (left, right) = zipper.as_lists();
+        assert_eq!(left.iter().collect::<Vec<_>>(), vec![&1, &0]);
+        assert_eq!(right.len(), 4);
+    }
+    {
+        let (left, right) = zipper.as_lists_mut();
+        left.push(9);
+        right.drain(1..3);
+    }
+    assert_eq!(contents(&zipper), (vec![0, 1, 9, 2, 5], 3));
+}
+
+quickcheck! {
+    fn truncate_matches_vec(zipper: ZipList<u8>, len: usize) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let len = len % (elems.len() + 2);
+        let mut zipper = zipper;
+        zipper.truncate(len);
+        elems.truncate(len);
+        let cursor = cursor.min(elems.len());
+        contents(&zipper) == (elems, cursor)
+    }
+}
+
+quickcheck! {
+    fn resize_matches_vec(zipper: ZipList<u8>, len: usize) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let len = len % (elems.len() * 2 + 2);
+        let mut zipper = zipper;
+        zipper.resize(len, 7);
+        elems.resize(len, 7);
+        let cursor = cursor.min(elems.len());
+        contents(&zipper) == (elems, cursor)
+    }
+}
+
+quickcheck! {
+    fn retain_mut_matches_vec(zipper: ZipList<u8>) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let kept_left = elems[..cursor].iter().filter(|&&x| x / 2 % 3 != 0)
+                                      .count();
+        let mut zipper = zipper;
+        let mut visited = Vec::new();
+        zipper.retain_mut(|x| { visited.push(*x); *x /= 2; *x % 3 != 0 });
+        let expected = elems.clone();
+        elems.retain_mut(|x| { *x /= 2; *x % 3 != 0 });
+        visited == expected && contents(&zipper) == (elems, kept_left)
+    }
+}
+
+quickcheck! {
+    fn move_to_front_keeps_other_elements_in_order(elems: Vec<u8>, x: u8)
+                                                   -> bool {
+        let mut list = elems.iter().rev().cloned()
+                            .collect::<MoveToFrontList<_>>();
+        let mut expected = elems.clone();
+        let found = list.position(&x);
+        let index = expected.iter().position(|&e| e == x);
+        if let Some(i) = index {
+            let elem = expected.remove(i);
+            expected.insert(0, elem);
+        }
+        found == index && list.iter().eq(expected.iter())
+    }
+}
+
+#[test]
+fn move_to_front_find_get_and_remove() {
+    let mut list = vec![3, 2, 1].into_iter().collect::<MoveToFrontList<_>>();
+    *list.find(&2).unwrap() = 20;
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![20, 1, 3]);
+    assert_eq!(list.find(&2), None);
+    assert_eq!(list.get(2), Some(&mut 3));
+    assert_eq!(list.get(3), None);
+    assert_eq!(list.remove(&1), Some(1));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.into_list().into_iter().collect::<Vec<_>>(), vec![3, 20]);
+}
+
+#[test]
+fn anchors_survive_moves_and_block_removal() {
+    let zipper = ZipList::from_iter_with_cursor(0..4, 2);
+    let mut zipper = StableZipList::from(zipper);
+    let two = zipper.anchor_right().unwrap();
+    assert!(zipper.peek_right_mut().is_none());
+    zipper.seek_left(2);
+    zipper.push_right(9);
+    zipper.seek_right(4);
+    assert_eq!(*two, 2);
+    zipper.seek_left(1);
+    assert_eq!(zipper.pop_right(), Err(AnchoredError { anchors: 1 }));
+    assert_eq!(zipper.len(), 5);
+    let again = zipper.anchor_right().unwrap();
+    assert!(again.ptr_eq(&two));
+    drop(two);
+    drop(again);
+    *zipper.peek_right_mut().unwrap() = 20;
+    assert_eq!(contents(&zipper.into_zipper()), (vec![9, 0, 1, 20, 3], 3));
+}
+
+#[test]
+fn anchored_elements_are_cloned_out() {
+    let mut zipper = StableZipList::new();
+    zipper.push_right(String::from("a"));
+    let a = zipper.anchor_right().unwrap();
+    let plain = zipper.into_zipper();
+    assert_eq!(plain.peek_right().map(String::as_str), Some("a"));
+    assert_eq!(*a, "a");
+}
+
+quickcheck! {
+    fn zipper_clone_from_matches_clone(zipper: ZipList<String>,
+                                       source: ZipList<String>) -> bool {
+        let mut zipper = zipper;
+        zipper.clone_from(&source);
+        contents(&zipper) == contents(&source)
+    }
+}
+
+#[test]
+fn zipper_clone_from_moves_nodes_between_sides() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..8u64, 8);
+    let size = zipper.memory_footprint();
+    zipper.clone_from(&ZipList::from_iter_with_cursor(0..8, 1));
+    assert_eq!(zipper.memory_footprint(), size);
+    assert_eq!(contents(&zipper), ((0..8).collect(), 1));
+}
+
+#[test]
+fn empty_statics() {
+    static LIST: List<String> = List::new();
+    static ZIPPER: ZipList<String> = ZipList::new();
+    assert!(LIST.is_empty());
+    assert!(ZIPPER.is_empty());
+}
+
+#[test]
+fn walk_visits_with_offsets() {
+    use std::ops::ControlFlow;
+    let mut zipper = ZipList::from_iter_with_cursor(0..5, 2);
+    let mut seen = Vec::new();
+    let all = zipper.walk(|offset, &elem| {
+        seen.push((offset, elem));
+        ControlFlow::<()>::Continue(())
+    });
+    assert_eq!(all, ControlFlow::Continue(()));
+    assert_eq!(seen, vec![(-1, 1), (-2, 0), (1, 2), (2, 3), (3, 4)]);
+    let broke = zipper.walk_mut(|offset, elem| {
+        *elem *= 10;
+        if offset == 2 { ControlFlow::Break(*elem) }
+        else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(broke, ControlFlow::Break(30));
+    assert_eq!(contents(&zipper), (vec![0, 10, 20, 30, 4], 2));
+}
+
+#[test]
+fn replace_right_n_splices_in_order() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..6, 2);
+    let removed = zipper.replace_right_n(2, vec![20, 30, 35]);
+    assert_eq!(removed.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    assert_eq!(contents(&zipper), (vec![0, 1, 20, 30, 35, 4, 5], 2));
+    // overwriting past the end removes what's there and appends the rest
+    zipper.seek_right(4);
+    let removed = zipper.replace_right_n(3, vec![50, 60]);
+    assert_eq!(removed.iter().collect::<Vec<_>>(), vec![&5]);
+    assert_eq!(contents(&zipper), (vec![0, 1, 20, 30, 35, 4, 50, 60], 6));
+    assert_eq!(zipper.replace_right_n(0, None).len(), 0);
+    assert_eq!(zipper.len(), 8);
+}
+
+quickcheck! {
+    fn replace_right_n_matches_splice( zipper: ZipList<usize>, n: usize
+                                     , items: Vec<usize> ) -> bool {
+        let (mut elems, cursor) = contents(&zipper);
+        let end = cursor + n.min(elems.len() - cursor);
+        let expected_removed = elems.splice(cursor..end, items.clone())
+                                    .collect::<Vec<_>>();
+        let mut zipper = zipper;
+        let removed = zipper.replace_right_n(n, items);
+        removed.into_iter().collect::<Vec<_>>() == expected_removed &&
+        contents(&zipper) == (elems, cursor)
+    }
+}
+
+#[test]
+fn try_methods_report_bad_positions() {
+    use error::RangeError;
+    let mut zipper = ZipList::from_iter_with_cursor(0..5, 2);
+    assert_eq!(zipper.try_insert_at(6, 10), Err(10));
+    assert_eq!( zipper.try_swap(1, 5)
+              , Err(RangeError { start: 5, end: 6, lower: 0, upper: 5 }) );
+    assert_eq!( zipper.try_drain(-3..1).err()
+              , Some(RangeError { start: -3, end: 1, lower: -2, upper: 3 }) );
+    zipper.commit_left();
+    zipper.seek_right(2);
+    assert_eq!( zipper.try_slice_consumed(1..3).err()
+              , Some(RangeError { start: 1, end: 3, lower: 2, upper: 4 }) );
+    assert_eq!(contents(&zipper), (vec![2, 3, 4], 2));
+    assert_eq!(zipper.try_insert_at(3, 5), Ok(()));
+    assert_eq!(zipper.try_swap(0, 3), Ok(()));
+    assert_eq!(zipper.try_slice_consumed(2..4).unwrap().len(), 2);
+    let drained = zipper.try_drain(-1..1).unwrap().collect::<Vec<_>>();
+    assert_eq!(drained, vec![3, 4]);
+    assert_eq!(contents(&zipper), (vec![5, 2], 1));
+}
+
+quickcheck! {
+    // the movement, push, pop and try_ methods never panic, whatever the
+    // zipper's shape and however far out of bounds their arguments are.
+    fn core_operations_never_panic( zipper: ZipList<usize>
+                                  , ops: Vec<(u8, usize, isize)> ) -> bool {
+        use std::panic::{self, AssertUnwindSafe};
+        let mut zipper = zipper;
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            for (op, n, offset) in ops {
+                match op % 12 {
+                    0 => { zipper.move_left(); }
+                  , 1 => { zipper.move_right(); }
+                  , 2 => { zipper.seek_left(n); }
+                  , 3 => { zipper.seek_right(n); }
+                  , 4 => { let _ = zipper.try_seek_left(n); }
+                  , 5 => { let _ = zipper.try_seek_right(n); }
+                  , 6 => { zipper.push_left(n).push_right(n); }
+                  , 7 => { zipper.pop_left(); zipper.pop_right(); }
+                  , 8 => { let _ = zipper.try_insert_at(n, n); }
+                  , 9 => { zipper.remove_at(n); }
+                  , 10 => { let _ = zipper.try_swap(n, n / 2); }
+                  , _ => { let _ = zipper.try_drain(offset..offset / 2); }
+                }
+                zipper.peek_left();
+                zipper.peek_right();
+            }
+        })).is_ok()
+    }
+}
+
+#[test]
+fn marks_survive_moves_and_in_place_edits() {
+    let mut zipper = ZipList::from_iter_with_cursor(0..6, 4);
+    let mark = zipper.mark();
+    let start = zipper.mark_at(0).unwrap();
+    assert_eq!(zipper.mark_at(7), None);
+    zipper.seek_left(3);
+    *zipper.peek_right_mut().unwrap() = 10;
+    assert_eq!(zipper.seek_to_mark(mark), Ok(()));
+    assert_eq!(contents(&zipper), (vec![0, 10, 2, 3, 4, 5], 4));
+    assert_eq!(zipper.check_mark(start), Ok(0));
+    assert_eq!(zipper.seek_to_mark(start), Ok(()));
+    assert_eq!(zipper.peek_right(), Some(&0));
+}
+
+#[test]
+fn marks_are_stale_after_structural_changes() {
+    let edits: Vec<fn(&mut ZipList<u8>)> = vec![
+        |z| { z.push_left(9); }
+      , |z| { z.push_right(9); }
+      , |z| { z.pop_left(); }
+      , |z| { z.pop_right(); }
+      , |z| { z.remove_at(0); }
+      , |z| { z.drain(-1..1); }
+      , |z| { z.commit_left(); }
+      , |z| { z.as_lists_mut(); }
+    ];
+    for edit in edits {
+        let mut zipper = ZipList::from_iter_with_cursor(0..4, 2);
+        let mark = zipper.mark();
+        zipper.move_right();
+        edit(&mut zipper);
+        let before = contents(&zipper);
+        let err = zipper.seek_to_mark(mark).unwrap_err();
+        assert_eq!(err.made, mark.generation);
+        assert_eq!(err.current, zipper.generation());
+        assert_eq!(contents(&zipper), before);
+    }
+}
+
+#[test]
+fn marks_are_stale_after_lists_are_replaced() {
+    let mut zipper = ZipList::new();
+    let mark = zipper.mark();
+    zipper.push_right(1);
+    {
+        let (left, right) = zipper.as_lists_mut();
+        *left = List::new();
+        *right = List::new();
+    }
+    assert_ne!(zipper.generation(), mark.generation);
+    assert!(zipper.check_mark(mark).is_err());
+}
+
+#[test]
+fn from_elem_starts_at_left_end() {
+    let zipper = ZipList::from_elem(3, 'x');
+    assert_eq!(contents(&zipper), (vec!['x'; 3], 0));
+}
+
+#[test]
+fn from_str_at_counts_chars() {
+    use error::SeekError;
+    let zipper = ZipList::from_str_at("héllo", 2).unwrap();
+    assert_eq!(zipper.peek_left(), Some(&'é'));
+    assert_eq!(zipper.peek_right(), Some(&'l'));
+    assert_eq!(contents(&zipper).0.into_iter().collect::<String>(), "héllo");
+    assert_eq!(ZipList::from_str_at("héllo", 5).unwrap().peek_right(), None);
+    assert_eq!( ZipList::from_str_at("héllo", 6).unwrap_err()
+              , SeekError { requested: 6, moved: 5 } );
+    let zipper = ZipList::from("ab");
+    assert_eq!(contents(&zipper), (vec!['a', 'b'], 0));
+}
+
+#[test]
+fn try_map_keeps_cursor() {
+    let zipper = ZipList::from_iter_with_cursor(vec!["1", "2", "3"], 2);
+    let mut seen = Vec::new();
+    let mapped = zipper.clone()
+                       .try_map(|s| { seen.push(s); s.parse::<u8>() })
+                       .unwrap();
+    assert_eq!(seen, vec!["1", "2", "3"]);
+    assert_eq!(contents(&mapped), (vec![1, 2, 3], 2));
+    assert!(zipper.try_map(|s| if s == "2" { Err(s) } else { Ok(s) })
+                  .is_err());
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_events {
+    use std::fmt::{self, Write};
+    use std::sync::{Arc, Mutex};
+    use tracing::{self, field, span, Event, Metadata, Subscriber};
+    use ::ZipList;
+
+    /// Records the names of spans and the fields of events, one line each.
+    #[derive(Clone, Default)]
+    struct Recorder { log: Arc<Mutex<Vec<String>>> }
+
+    struct Line(String);
+
+    impl field::Visit for Line {
+        fn record_debug( &mut self, field: &field::Field
+                       , value: &dyn fmt::Debug ) {
+            write!(self.0, " {}={:?}", field.name(), value).unwrap();
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata) -> bool { true }
+        fn new_span(&self, attrs: &span::Attributes) -> span::Id {
+            let mut line = Line(format!("span {}", attrs.metadata().name()));
+            attrs.record(&mut line);
+            self.log.lock().unwrap().push(line.0);
+            span::Id::from_u64(1)
+        }
+        fn record(&self, _: &span::Id, _: &span::Record) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn event(&self, event: &Event) {
+            let mut line = Line("event".to_owned());
+            event.record(&mut line);
+            self.log.lock().unwrap().push(line.0);
+        }
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn structural_operations_are_traced() {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut zipper = ZipList::from_iter_with_cursor(0..6, 3);
+            zipper.seek_right(5);
+            zipper.drain(-2..0).count();
+            zipper.as_lists_mut().1.splice(.., vec![7]);
+        });
+        assert_eq!( *recorder.log.lock().unwrap()
+                  , vec![ "event message=seek_right requested=5 moved=3"
+                        , "event message=drain start=-2 end=0 left=6 right=0"
+                        , "span splice start=0 end=0 len=0"
+                        ] );
+    }
+}
+
+quickcheck! {
+    fn merge_sorted_matches_sorting( mine: Vec<(u8, u8)>, theirs: Vec<u8>
+                                   , cursor: usize, other_cursor: usize )
+                                   -> bool {
+        // the second half of each pair tags which zipper an element came
+        // from, so that the order of equal elements can be checked
+        let mut mine = mine.into_iter().map(|(x, _)| (x % 8, 0))
+                           .collect::<Vec<_>>();
+        let mut theirs = theirs.into_iter().map(|x| (x % 8, 1))
+                               .collect::<Vec<_>>();
+        mine.sort();
+        theirs.sort();
+        let cursor = cursor % (mine.len() + 1);
+        let mut zipper = ZipList::from_iter_with_cursor(mine.clone(), cursor);
+        zipper.merge_sorted(ZipList::from_iter_with_cursor( theirs.clone()
+                                                          , other_cursor ));
+        let before = cursor.checked_sub(1).map(|i| mine[i]);
+        let mut expected = mine;
+        expected.extend(theirs);
+        expected.sort();
+        let (elems, at) = contents(&zipper);
+        elems == expected && at.checked_sub(1).map(|i| elems[i]) == before &&
+        zipper.len() == elems.len()
+    }
+}
+
+#[test]
+fn keep_window_bounds_the_zipper() {
+    let mut zipper = ZipList::new();
+    for i in 0..10 {
+        zipper.push_left(i);
+        zipper.keep_window(3, 0);
+        assert!(zipper.len() <= 3);
+    }
+    assert_eq!(contents(&zipper), (vec![7, 8, 9], 3));
+    assert_eq!(zipper.offset(), 10);
+    zipper.seek_left(2);
+    assert_eq!(zipper.keep_window(0, 1), 2);
+    assert_eq!(contents(&zipper), (vec![8], 0));
+    assert_eq!(zipper.offset(), 8);
+}
+
+quickcheck! {
+    fn sliding_window_matches_deque(width: usize, ops: Vec<Option<u8>>)
+                                    -> bool {
+        use std::collections::VecDeque;
+        let width = width % 5;
+        let mut window = SlidingWindow::new(width);
+        let mut model = VecDeque::new();
+        ops.into_iter().all(|op| {
+            let ok = match op {
+                Some(elem) => {
+                    model.push_back(elem);
+                    let evicted = if model.len() > width { model.pop_front() }
+                                  else { None };
+                    window.push(elem) == evicted
+                }
+              , None => window.pop_front() == model.pop_front()
+            };
+            ok && window.len() == model.len() &&
+            window.front() == model.front() &&
+            window.back() == model.back() &&
+            window.iter().eq(model.iter())
+        })
+    }
+}
+
+quickcheck! {
+    fn partition_at_cursor_is_stable(zipper: ZipList<u8>) -> bool {
+        let mut zipper = zipper;
+        let (elems, _) = contents(&zipper);
+        let (evens, odds): (Vec<u8>, Vec<u8>) =
+            elems.iter().partition(|elem| *elem % 2 == 0);
+        let mut visited = Vec::new();
+        let at = zipper.partition_at_cursor(|elem| {
+            visited.push(*elem);
+            elem % 2 == 0
+        });
+        let (after, cursor) = contents(&zipper);
+        visited == elems && at == evens.len() && cursor == at &&
+        after == [evens, odds].concat() && zipper.len() == elems.len()
+    }
+}
+
+quickcheck! {
+    fn sort_keeps_cursor_after_same_element( elems: Vec<u8>, cursor: usize )
+                                           -> bool {
+        // tag each element with its index, and sort by the element alone,
+        // so that the element left of the cursor can be told apart from
+        // equal ones
+        let tagged = elems.iter().map(|elem| elem % 4).enumerate()
+                          .map(|(i, elem)| (elem, i))
+                          .collect::<Vec<_>>();
+        let cursor = cursor % (tagged.len() + 1);
+        let mut zipper = ZipList::from_iter_with_cursor(tagged.clone(), cursor);
+        let before = zipper.peek_left().cloned();
+        zipper.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut expected = tagged;
+        expected.sort_by_key(|pair| pair.0);
+        let (sorted, at) = contents(&zipper);
+        sorted == expected && at.checked_sub(1).map(|i| sorted[i]) == before
+    }
+}
+
+#[test]
+fn sort_from_left_end() {
+    let mut zipper = ZipList::from_iter_with_cursor(vec![3, 1, 2], 0);
+    zipper.sort();
+    assert_eq!(contents(&zipper), (vec![1, 2, 3], 0));
+}
+
+#[test]
+fn seek_map_marks_passed_elements() {
+    let mut zipper = ZipList::from_iter_with_cursor(vec![(0, false); 6], 3);
+    let mut order = 0;
+    let moved = zipper.seek_right_map(2, |elem| {
+        order += 1;
+        *elem = (order, true);
+    });
+    assert_eq!(moved, 2);
+    assert_eq!( contents(&zipper).0.iter().map(|e| e.0).collect::<Vec<_>>()
+              , vec![0, 0, 0, 1, 2, 0] );
+    assert_eq!(zipper.seek_left_map(10, |elem| elem.1 = !elem.1), 5);
+    assert_eq!( contents(&zipper)
+              , ( vec![ (0, true), (0, true), (0, true), (1, false)
+                      , (2, false), (0, false) ]
+                , 0 ) );
+}
+
+quickcheck! {
+    fn zip_iter_matches_deque(zipper: ZipList<u8>, from_back: Vec<bool>)
+                              -> bool {
+        use std::collections::VecDeque;
+        let (elems, _) = contents(&zipper);
+        let mut model = elems.iter().collect::<VecDeque<_>>();
+        let mut iter = zipper.iter();
+        from_back.into_iter().chain(vec![false; elems.len()])
+            .all(|back| {
+                let ok = if back { iter.next_back() == model.pop_back() }
+                         else { iter.next() == model.pop_front() };
+                ok && iter.len() == model.len()
+            }) &&
+        zipper.iter().rev().eq(elems.iter().rev()) &&
+        (&zipper).into_iter().eq(elems.iter())
+    }
+}
+
+#[test]
+fn enumerate_from_cursor_skips_zero() {
+    let zipper = ZipList::from_iter_with_cursor("abcde".chars(), 2);
+    let pairs = zipper.enumerate_from_cursor().collect::<Vec<_>>();
+    assert_eq!( pairs
+              , vec![(-2, &'a'), (-1, &'b'), (1, &'c'), (2, &'d'), (3, &'e')] );
+    let mut iter = zipper.enumerate_from_cursor();
+    assert_eq!(iter.next_back(), Some((3, &'e')));
+    assert_eq!(iter.next(), Some((-2, &'a')));
+    assert_eq!(iter.len(), 3);
+    assert!(iter.rev().map(|pair| pair.0).eq(vec![2, 1, -1]));
+    let at_end = ZipList::from_iter_with_cursor("ab".chars(), 2);
+    assert!(at_end.enumerate_from_cursor().rev().map(|pair| pair.0)
+                  .eq(vec![-1, -2]));
+}
+
+quickcheck! {
+    fn interleave_alternates(ours: ZipList<u8>, theirs: ZipList<u8>) -> bool {
+        let (mine, cursor) = contents(&ours);
+        let (other, _) = contents(&theirs);
+        let mut expected = Vec::new();
+        for i in 0..mine.len().max(other.len()) {
+            expected.extend(mine.get(i));
+            expected.extend(other.get(i));
+        }
+        let before = cursor.checked_sub(1);
+        let at = before.map_or(0, |i| if i < other.len() { 2 * i + 1 }
+                                      else { i + other.len() + 1 });
+        contents(&ours.interleave(theirs)) == (expected, at)
+    }
+}
@@ -0,0 +1,56 @@
+//! Reading and writing text files as zippers of lines.
+use std::io::{self, BufRead, Write};
+
+use {List, Stack, ZipList};
+
+impl ZipList<String> {
+    /// Read the lines of `r` into a zipper, one element per line, with the
+    /// cursor before the first line.
+    ///
+    /// Lines are split on `\n`, and a `\r` before it is removed as well. The
+    /// line terminators aren't included in the elements, and a final empty
+    /// line after the last terminator isn't either.
+    ///
+    /// Each line is pushed onto the zipper as it's read, so there's no
+    /// intermediate buffer of the whole file.
+    ///
+    /// # Returns
+    /// - `Ok(ZipList)` with the lines of `r`
+    /// - `Err(io::Error)` if reading fails, including if a line isn't UTF-8
+    ///
+    /// # Time complexity
+    /// O(the length of the text)
+    pub fn from_reader<R: BufRead>(mut r: R) -> io::Result<Self> {
+        let mut lines = List::new();
+        loop {
+            let mut line = String::new();
+            if r.read_line(&mut line)? == 0 { break }
+            if line.ends_with('\n') {
+                line.pop();
+                if line.ends_with('\r') { line.pop(); }
+            }
+            lines.push(line);
+        }
+        // pushing stacks the lines up last-first
+        lines.reverse();
+        Ok(ZipList { left: List::new()
+                   , right: lines
+                   , committed: 0
+                   , generation: 0
+                   , consumed: 0 })
+    }
+
+    /// Write the lines in this zipper to `w`, each followed by `\n`.
+    ///
+    /// # Time complexity
+    /// O(the length of the text), plus O(lines to the left of the cursor)
+    /// extra memory to write them in order.
+    pub fn write_lines<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let left = self.left.iter().collect::<Vec<_>>();
+        for line in left.into_iter().rev().chain(self.right.iter()) {
+            w.write_all(line.as_bytes())?;
+            w.write_all(b"\n")?;
+        }
+        w.flush()
+    }
+}
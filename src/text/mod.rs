@@ -0,0 +1,404 @@
+use std::ops::Deref;
+
+use super::{List, Stack, ZipList};
+use error::SeekError;
+
+mod lines;
+#[cfg(feature = "regex")] mod regex;
+
+/// The kinds of characters that word motion treats as separate runs.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CharClass { Space, Word, Punctuation }
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() { CharClass::Space }
+        else if c.is_alphanumeric() || c == '_' { CharClass::Word }
+        else { CharClass::Punctuation }
+    }
+}
+
+/// Move characters from the top of `from` to `to` while they're in `class`,
+/// returning how many were moved.
+fn skip_class(from: &mut List<char>, to: &mut List<char>, class: CharClass)
+              -> usize {
+    let mut moved = 0;
+    while from.peek().map(|&c| CharClass::of(c)) == Some(class) {
+        let node = from.uncons().expect("peeked a node");
+        to.cons(node);
+        moved += 1;
+    }
+    moved
+}
+
+/// Move over any whitespace at the top of `from`, then over the run of word
+/// or punctuation characters after it.
+fn skip_word(from: &mut List<char>, to: &mut List<char>) -> usize {
+    let moved = skip_class(from, to, CharClass::Space);
+    match from.peek().map(|&c| CharClass::of(c)) {
+        Some(class) => moved + skip_class(from, to, class)
+      , None => moved
+    }
+}
+
+/// Returns the index in `haystack` of the last element of the first match
+/// of `needle`, using the Knuth-Morris-Pratt algorithm.
+///
+/// KMP never looks at an element of the haystack twice, so it works on
+/// iterators, which suits linked lists, where every node has to be visited
+/// anyway.
+fn find<'a, T, I>(needle: &[T], haystack: I) -> Option<usize>
+where T: PartialEq + 'a
+    , I: Iterator<Item = &'a T> {
+    // `fallback[i]` is the length of the longest proper prefix of
+    // `needle[..=i]` which is also a suffix of it.
+    let mut fallback = vec![0; needle.len()];
+    let mut k = 0;
+    for i in 1..needle.len() {
+        while k > 0 && needle[i] != needle[k] { k = fallback[k - 1] }
+        if needle[i] == needle[k] { k += 1 }
+        fallback[i] = k;
+    }
+
+    let mut matched = 0;
+    for (i, elem) in haystack.enumerate() {
+        while matched > 0 && *elem != needle[matched] {
+            matched = fallback[matched - 1]
+        }
+        if *elem == needle[matched] { matched += 1 }
+        if matched == needle.len() { return Some(i) }
+    }
+    None
+}
+
+impl ZipList<char> {
+    /// Create a `ZipList` of the characters of `s`, with the cursor before
+    /// the character at index `cursor`.
+    ///
+    /// `cursor` counts `char`s, not bytes, so it can't fall inside a
+    /// multi-byte character.
+    ///
+    /// # Returns
+    /// - `Ok(ZipList)` if `cursor` is at most the number of characters in `s`
+    /// - `Err(SeekError)` otherwise, with the number of characters in `s` as
+    ///   the number of positions moved
+    ///
+    /// # Time complexity
+    /// O(`s.len()`)
+    pub fn from_str_at(s: &str, cursor: usize) -> Result<Self, SeekError> {
+        let mut chars = s.chars();
+        let mut left = List::new();
+        left.extend(chars.by_ref().take(cursor));
+        if left.len() < cursor {
+            return Err(SeekError { requested: cursor, moved: left.len() })
+        }
+        let mut right = chars.collect::<List<_>>();
+        right.reverse();
+        Ok(ZipList { left, right, committed: 0, generation: 0, consumed: 0 })
+    }
+
+    /// Move the cursor to the start of the word to its left, like an
+    /// editor's "previous word" motion.
+    ///
+    /// Any whitespace to the left of the cursor is skipped, and then the run
+    /// of characters before it: either a word made of alphanumeric
+    /// characters and underscores, or a run of punctuation.
+    ///
+    /// # Returns
+    /// - the number of positions moved
+    pub fn seek_word_left(&mut self) -> usize {
+        skip_word(&mut self.left, &mut self.right)
+    }
+
+    /// Move the cursor to the end of the word to its right, like an
+    /// editor's "next word" motion.
+    ///
+    /// Any whitespace to the right of the cursor is skipped, and then the
+    /// run of characters after it: either a word made of alphanumeric
+    /// characters and underscores, or a run of punctuation.
+    ///
+    /// # Returns
+    /// - the number of positions moved
+    pub fn seek_word_right(&mut self) -> usize {
+        skip_word(&mut self.right, &mut self.left)
+    }
+
+    /// Search for the first occurrence of `needle` which starts at or after
+    /// the cursor.
+    ///
+    /// # Returns
+    /// - `Some(n)` if `needle` occurs `n` positions to the right of the
+    ///   cursor. An empty `needle` is found at the cursor.
+    /// - `None` if `needle` doesn't occur to the right of the cursor
+    ///
+    /// # Time complexity
+    /// O(`n + needle.len()`), or O(elements to the right of the cursor) if
+    /// `needle` isn't found
+    pub fn search_right(&self, needle: &str) -> Option<usize> {
+        let needle = needle.chars().collect::<Vec<_>>();
+        if needle.is_empty() { return Some(0) }
+        find(&needle, self.right.iter()).map(|end| end + 1 - needle.len())
+    }
+
+    /// Search for the last occurrence of `needle` which starts before the
+    /// cursor. It may end after the cursor.
+    ///
+    /// # Returns
+    /// - `Some(n)` if `needle` occurs `n` positions to the left of the
+    ///   cursor. An empty `needle` is found at the cursor.
+    /// - `None` if `needle` doesn't occur to the left of the cursor
+    ///
+    /// # Time complexity
+    /// O(`n + needle.len()`), or O(elements to the left of the cursor) if
+    /// `needle` isn't found
+    pub fn search_left(&self, needle: &str) -> Option<usize> {
+        let mut needle = needle.chars().collect::<Vec<_>>();
+        if needle.is_empty() { return Some(0) }
+        needle.reverse();
+        // search backwards, starting from the furthest point right of the
+        // cursor that a match starting before it could reach
+        let mut overlap = self.right.iter().take(needle.len() - 1)
+                              .collect::<Vec<_>>();
+        let skipped = overlap.len();
+        overlap.reverse();
+        find(&needle, overlap.into_iter().chain(self.left.iter()))
+            .map(|end| end + 1 - skipped)
+    }
+
+    /// Move the cursor to the start of the first occurrence of `needle`
+    /// which starts at or after the cursor, as found by
+    /// [`search_right`](#method.search_right).
+    ///
+    /// # Returns
+    /// - `Some(n)` if the cursor was moved `n` positions to the right
+    /// - `None` if `needle` wasn't found, in which case the cursor isn't
+    ///   moved
+    pub fn seek_search_right(&mut self, needle: &str) -> Option<usize> {
+        let n = self.search_right(needle)?;
+        self.seek_right(n);
+        Some(n)
+    }
+
+    /// Move the cursor to the start of the last occurrence of `needle`
+    /// which starts before the cursor, as found by
+    /// [`search_left`](#method.search_left).
+    ///
+    /// # Returns
+    /// - `Some(n)` if the cursor was moved `n` positions to the left
+    /// - `None` if `needle` wasn't found, in which case the cursor isn't
+    ///   moved
+    pub fn seek_search_left(&mut self, needle: &str) -> Option<usize> {
+        let n = self.search_left(needle)?;
+        self.seek_left(n);
+        Some(n)
+    }
+}
+
+/// A `ZipList<char>` which keeps track of the cursor's line and column.
+///
+/// Lines and columns are counted from zero, in `char`s, with lines ending
+/// at `'\n'`. They're updated in O(1) time for each character the cursor
+/// moves over, or that's inserted or removed to the left of it, by keeping
+/// the lengths of the lines to the left of the cursor.
+///
+/// This dereferences to the underlying `ZipList` for reading. Changes have
+/// to be made through the methods on `TrackedZipper`, so that the line and
+/// column stay correct.
+#[derive(Clone, Debug)]
+pub struct TrackedZipper { zipper: ZipList<char>
+                         , column: usize
+                         , /// The lengths of the lines before the cursor's.
+                           lines: Vec<usize>
+                         }
+
+impl TrackedZipper {
+    /// Create a new empty `TrackedZipper`.
+    pub fn new() -> Self { TrackedZipper::from(ZipList::new()) }
+
+    /// Returns the line the cursor is on.
+    #[inline] pub fn line(&self) -> usize { self.lines.len() }
+
+    /// Returns the cursor's column, which is the number of characters
+    /// between it and the start of its line.
+    #[inline] pub fn column(&self) -> usize { self.column }
+
+    /// Stop tracking, returning the zipper.
+    pub fn into_inner(self) -> ZipList<char> { self.zipper }
+
+    /// Account for the cursor moving right over `c`.
+    fn passed_right(&mut self, c: char) {
+        if c == '\n' {
+            self.lines.push(self.column);
+            self.column = 0;
+        } else {
+            self.column += 1;
+        }
+    }
+
+    /// Account for the cursor moving left over `c`.
+    fn passed_left(&mut self, c: char) {
+        if c == '\n' {
+            self.column = self.lines.pop()
+                              .expect("passed a newline on the first line");
+        } else {
+            self.column -= 1;
+        }
+    }
+
+    /// Pop the character to the left of the cursor and return it.
+    pub fn pop_left(&mut self) -> Option<char> {
+        let c = self.zipper.pop_left()?;
+        self.passed_left(c);
+        Some(c)
+    }
+
+    /// Pop the character to the right of the cursor and return it.
+    #[inline] pub fn pop_right(&mut self) -> Option<char> {
+        self.zipper.pop_right()
+    }
+
+    /// Push `c` to the left of the cursor.
+    pub fn push_left(&mut self, c: char) -> &mut Self {
+        self.zipper.push_left(c);
+        self.passed_right(c);
+        self
+    }
+
+    /// Push `c` to the right of the cursor.
+    #[inline] pub fn push_right(&mut self, c: char) -> &mut Self {
+        self.zipper.push_right(c);
+        self
+    }
+
+    /// Move the cursor one position to the left.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved to the left
+    /// - `false` if the cursor is already at the left
+    pub fn move_left(&mut self) -> bool {
+        match self.zipper.peek_left().cloned() {
+            Some(c) => { self.zipper.move_left(); self.passed_left(c); true }
+          , None => false
+        }
+    }
+
+    /// Move the cursor one position to the right.
+    ///
+    /// # Returns
+    /// - `true` if the cursor was moved to the right
+    /// - `false` if the cursor is already at the right
+    pub fn move_right(&mut self) -> bool {
+        match self.zipper.peek_right().cloned() {
+            Some(c) => { self.zipper.move_right(); self.passed_right(c); true }
+          , None => false
+        }
+    }
+
+    /// Move the cursor `n` positions to the left.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   cursor reached the start before it finished moving.
+    pub fn seek_left(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_left()).count()
+    }
+
+    /// Move the cursor `n` positions to the right.
+    ///
+    /// # Returns
+    /// - the number of positions moved. If this is less than `n`, then the
+    ///   cursor reached the end before it finished moving.
+    pub fn seek_right(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.move_right()).count()
+    }
+
+    /// Move the cursor to the start of the word to its left, as with
+    /// [`ZipList::seek_word_left`].
+    ///
+    /// [`ZipList::seek_word_left`]: struct.ZipList.html#method.seek_word_left
+    pub fn seek_word_left(&mut self) -> usize {
+        let moved = self.zipper.seek_word_left();
+        let passed = self.zipper.right_iter().take(moved).cloned()
+                         .collect::<Vec<_>>();
+        for c in passed.into_iter().rev() { self.passed_left(c) }
+        moved
+    }
+
+    /// Move the cursor to the end of the word to its right, as with
+    /// [`ZipList::seek_word_right`].
+    ///
+    /// [`ZipList::seek_word_right`]: struct.ZipList.html#method.seek_word_right
+    pub fn seek_word_right(&mut self) -> usize {
+        let moved = self.zipper.seek_word_right();
+        let passed = self.zipper.left_iter().take(moved).cloned()
+                         .collect::<Vec<_>>();
+        for c in passed.into_iter().rev() { self.passed_right(c) }
+        moved
+    }
+
+    /// Move the cursor to `column` on `line`.
+    ///
+    /// If `line` is past the last line, the cursor is moved to the end. If
+    /// `column` is past the end of the line, the cursor is moved to the end
+    /// of the line.
+    ///
+    /// # Returns
+    /// - `true` if the cursor is at exactly `line` and `column`
+    /// - `false` if it was moved to the end of the text or of the line
+    ///   instead
+    ///
+    /// # Time complexity
+    /// O(number of positions moved)
+    pub fn seek_to_line_col(&mut self, line: usize, column: usize) -> bool {
+        while (self.line(), self.column) > (line, column) {
+            if !self.move_left() { break }
+        }
+        while self.line() < line {
+            if !self.move_right() { break }
+        }
+        while self.line() == line && self.column < column {
+            if self.zipper.peek_right() == Some(&'\n') || !self.move_right() {
+                break
+            }
+        }
+        (self.line(), self.column) == (line, column)
+    }
+}
+
+impl<'a> From<&'a str> for ZipList<char> {
+    /// Create a `ZipList` of the characters of `s`, with the cursor before
+    /// the first one.
+    ///
+    /// This also provides `TryFrom<&str>`, which can't fail; use
+    /// [`ZipList::from_str_at`] to place the cursor elsewhere.
+    ///
+    /// [`ZipList::from_str_at`]: struct.ZipList.html#method.from_str_at
+    fn from(s: &'a str) -> Self {
+        ZipList::from_iter_with_cursor(s.chars(), 0)
+    }
+}
+
+impl From<ZipList<char>> for TrackedZipper {
+    /// Start tracking the line and column of `zipper`'s cursor.
+    ///
+    /// # Time complexity
+    /// O(characters to the left of the cursor)
+    fn from(zipper: ZipList<char>) -> Self {
+        let before = zipper.left_iter().cloned().collect::<Vec<_>>();
+        let mut tracked = TrackedZipper { zipper
+                                        , column: 0
+                                        , lines: Vec::new()
+                                        };
+        for c in before.into_iter().rev() { tracked.passed_right(c) }
+        tracked
+    }
+}
+
+impl Default for TrackedZipper {
+    fn default() -> Self { TrackedZipper::new() }
+}
+
+impl Deref for TrackedZipper {
+    type Target = ZipList<char>;
+    #[inline] fn deref(&self) -> &ZipList<char> { &self.zipper }
+}
@@ -0,0 +1,120 @@
+//! Regular expression search, with the `regex` feature.
+use std::ops::Range;
+
+use regex::Regex;
+
+use super::TrackedZipper;
+use ZipList;
+
+/// Returns the text of `zipper` and the byte offset of its cursor.
+fn text(zipper: &ZipList<char>) -> (String, usize) {
+    let left = zipper.left_iter().collect::<Vec<_>>();
+    let mut text = left.into_iter().rev().collect::<String>();
+    let cursor = text.len();
+    text.extend(zipper.right_iter());
+    (text, cursor)
+}
+
+/// Convert a byte range in `text` to a range of `char` positions.
+fn char_range(text: &str, bytes: Range<usize>) -> Range<usize> {
+    let start = text[..bytes.start].chars().count();
+    start..start + text[bytes].chars().count()
+}
+
+impl ZipList<char> {
+    /// Search for the first match of `re` which starts at or after the
+    /// cursor.
+    ///
+    /// The text before the cursor is taken into account by anchors like
+    /// `\b`.
+    ///
+    /// # Returns
+    /// - `Some(range)` with the positions of the start and end of the match
+    ///   in the whole zipper, counted in `char`s from the left
+    /// - `None` if there is no match to the right of the cursor
+    ///
+    /// # Time complexity
+    /// O(`self.len()`), since the text is collected into a string to search
+    pub fn find_next(&self, re: &Regex) -> Option<Range<usize>> {
+        let (text, cursor) = text(self);
+        re.find_at(&text, cursor)
+          .map(|found| char_range(&text, found.range()))
+    }
+
+    /// Search for the last match of `re` which starts before the cursor. It
+    /// may end after the cursor.
+    ///
+    /// Matches are found from the start of the zipper, without overlapping,
+    /// so a match which overlaps an earlier one isn't found.
+    ///
+    /// # Returns
+    /// - `Some(range)` with the positions of the start and end of the match
+    ///   in the whole zipper, counted in `char`s from the left
+    /// - `None` if there is no match to the left of the cursor
+    ///
+    /// # Time complexity
+    /// O(`self.len()`), since the text is collected into a string to search
+    pub fn find_prev(&self, re: &Regex) -> Option<Range<usize>> {
+        let (text, cursor) = text(self);
+        re.find_iter(&text)
+          .take_while(|found| found.start() < cursor)
+          .last()
+          .map(|found| char_range(&text, found.range()))
+    }
+
+    /// Move the cursor to the start of the match found by
+    /// [`find_next`](#method.find_next).
+    ///
+    /// To move to the end of the match instead, seek right by the length of
+    /// the returned range.
+    ///
+    /// # Returns
+    /// The match's range, or `None` if there's no match, in which case the
+    /// cursor isn't moved.
+    pub fn seek_find_next(&mut self, re: &Regex) -> Option<Range<usize>> {
+        let found = self.find_next(re)?;
+        let cursor = self.left.len();
+        self.seek_right(found.start - cursor);
+        Some(found)
+    }
+
+    /// Move the cursor to the start of the match found by
+    /// [`find_prev`](#method.find_prev).
+    ///
+    /// To move to the end of the match instead, seek right by the length of
+    /// the returned range.
+    ///
+    /// # Returns
+    /// The match's range, or `None` if there's no match, in which case the
+    /// cursor isn't moved.
+    pub fn seek_find_prev(&mut self, re: &Regex) -> Option<Range<usize>> {
+        let found = self.find_prev(re)?;
+        let cursor = self.left.len();
+        self.seek_left(cursor - found.start);
+        Some(found)
+    }
+}
+
+impl TrackedZipper {
+    /// Move the cursor to the start of the match found by
+    /// [`ZipList::find_next`], keeping track of its line and column.
+    ///
+    /// [`ZipList::find_next`]: struct.ZipList.html#method.find_next
+    pub fn seek_find_next(&mut self, re: &Regex) -> Option<Range<usize>> {
+        let found = self.find_next(re)?;
+        let cursor = self.left_iter().len();
+        self.seek_right(found.start - cursor);
+        Some(found)
+    }
+
+    /// Move the cursor to the start of the match found by
+    /// [`ZipList::find_prev`], keeping track of its line and column.
+    ///
+    /// [`ZipList::find_prev`]: struct.ZipList.html#method.find_prev
+    pub fn seek_find_prev(&mut self, re: &Regex) -> Option<Range<usize>> {
+        let found = self.find_prev(re)?;
+        let cursor = self.left_iter().len();
+        self.seek_left(cursor - found.start);
+        Some(found)
+    }
+}
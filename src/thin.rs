@@ -0,0 +1,209 @@
+//! A singly-linked list without any bookkeeping.
+//!
+//! A [`ThinList`] is a single pointer to its first node, so it takes up one
+//! word, where a `List` also keeps its length, a freelist and a generation
+//! count. In exchange, it doesn't reuse nodes, and its length is counted
+//! when it's asked for, rather than cached. This makes it better suited to
+//! large numbers of small lists than `List` is.
+//!
+//! [`ThinList`]: struct.ThinList.html
+use std::fmt;
+use std::iter;
+use std::mem;
+
+use super::{Link, List, Node, Stack};
+
+/// A singly-linked list which is a single pointer in size.
+///
+/// This has the same nodes as a [`List`](../struct.List.html), and can be
+/// converted to and from one without reallocating them.
+pub struct ThinList<T> { head: Link<T> }
+
+/// An iterator over borrowed elements of a `ThinList`.
+pub struct Iter<'a, T: 'a> { next: Option<&'a Node<T>> }
+
+/// An iterator over mutably borrowed elements of a `ThinList`.
+pub struct IterMut<'a, T: 'a> { next: Option<&'a mut Node<T>> }
+
+/// An iterator over the elements of a `ThinList`, which it consumes.
+pub struct IntoIter<T>(ThinList<T>);
+
+impl<T> Stack<T> for ThinList<T> {
+    fn push(&mut self, elem: T) -> &mut Self {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { elem, next }));
+        self
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            let Node { elem, next } = *node;
+            self.head = next;
+            elem
+        })
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.elem)
+    }
+}
+
+impl<T> ThinList<T> {
+    /// Create a new empty `ThinList`.
+    pub const fn new() -> Self { ThinList { head: None } }
+
+    /// Returns the length of the list.
+    ///
+    /// # Time complexity
+    /// O(`self.len()`), since the length is counted rather than cached.
+    pub fn len(&self) -> usize { self.iter().count() }
+
+    /// Returns true if the list has no elements.
+    ///
+    /// # Time complexity
+    /// O(1)
+    #[inline] pub fn is_empty(&self) -> bool { self.head.is_none() }
+
+    /// Returns an iterator over borrowed elements, from the front.
+    pub fn iter(&self) -> Iter<'_, T> { Iter { next: self.head.as_deref() } }
+
+    /// Returns an iterator over mutably borrowed elements, from the front.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut() }
+    }
+
+    /// Reverse the order of the elements in place, by relinking the nodes.
+    pub fn reverse(&mut self) {
+        let mut head = self.head.take();
+        let mut reversed = None;
+        while let Some(mut node) = head {
+            head = mem::replace(&mut node.next, reversed);
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    #[inline] fn next(&mut self) -> Option<Self::Item> { self.0.pop() }
+}
+
+impl<'a, T> IntoIterator for &'a ThinList<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T> IntoIterator for &'a mut ThinList<T> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T> IntoIterator for ThinList<T> {
+    type IntoIter = IntoIter<T>;
+    type Item = T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { IntoIter(self) }
+}
+
+impl<T> iter::FromIterator<T> for ThinList<T> {
+    /// Push each element of `iter` in turn, so that the last one is at the
+    /// front, as with `List`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = ThinList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> iter::Extend<T> for ThinList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter { self.push(elem); }
+    }
+}
+
+impl<T> From<List<T>> for ThinList<T> {
+    /// Convert a `List` to a `ThinList` without reallocating its nodes.
+    /// Nodes on the list's freelist are released.
+    fn from(mut list: List<T>) -> Self {
+        list.len = 0;
+        ThinList { head: list.head.take() }
+    }
+}
+
+impl<T> From<ThinList<T>> for List<T> {
+    /// Convert a `ThinList` to a `List` without reallocating its nodes.
+    ///
+    /// # Time complexity
+    /// O(`thin.len()`), to count the elements.
+    fn from(mut thin: ThinList<T>) -> Self {
+        let mut list = List::new();
+        list.len = thin.len();
+        list.head = thin.head.take();
+        list
+    }
+}
+
+impl<T> Default for ThinList<T> {
+    fn default() -> Self { ThinList::new() }
+}
+
+impl<T> Clone for ThinList<T>
+where T: Clone {
+    fn clone(&self) -> Self {
+        let mut list = ThinList::new();
+        {
+            let mut tail = &mut list.head;
+            for elem in self {
+                *tail = Some(Box::new(Node::new(elem.clone())));
+                tail = &mut tail.as_mut().unwrap().next;
+            }
+        }
+        list
+    }
+}
+
+impl<T> fmt::Debug for ThinList<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Drop for ThinList<T> {
+    fn drop(&mut self) {
+        // unlink the nodes one at a time, so that dropping a long list
+        // doesn't recurse once per node.
+        let mut head = self.head.take();
+        while let Some(mut node) = head {
+            head = node.next.take();
+        }
+    }
+}
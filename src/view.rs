@@ -0,0 +1,100 @@
+use super::{List, Stack, ZipList, list};
+
+/// A read-only view of a `ZipList`, fixed at the cursor position it had when
+/// the view was taken.
+///
+/// This is created by [`ZipList::as_view`]. A view is `Copy`, so it can be
+/// handed to as many readers as need it, such as rendering code, while the
+/// zipper it borrows can't change underneath them.
+///
+/// ```
+/// # use an_zipper::ZipList;
+/// let zipper = ZipList::from_iter_with_cursor("hello".chars(), 2);
+/// let view = zipper.as_view();
+/// assert_eq!(view.cursor(), 2);
+/// assert_eq!(view.peek(-1), Some(&'e'));
+/// assert_eq!(view.peek(1), Some(&'l'));
+/// assert_eq!(view.get(4), Some(&'o'));
+/// ```
+///
+/// [`ZipList::as_view`]: struct.ZipList.html#method.as_view
+pub struct ZipView<'a, T: 'a> { left: &'a List<T>
+                              , right: &'a List<T>
+                              }
+
+impl<T> ZipList<T> {
+    /// Returns a read-only view of the zipper.
+    #[inline] pub fn as_view(&self) -> ZipView<'_, T> {
+        ZipView { left: &self.left, right: &self.right }
+    }
+}
+
+impl<'a, T> ZipView<'a, T> {
+    /// Returns the number of elements to the left of the cursor.
+    #[inline] pub fn cursor(&self) -> usize { self.left.len() }
+
+    /// Returns the number of elements in the view.
+    #[inline] pub fn len(&self) -> usize { self.left.len() + self.right.len() }
+
+    /// Returns true if there are no elements in the view.
+    #[inline] pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+
+    /// Returns an iterator over the elements to the left of the cursor,
+    /// nearest first.
+    #[inline] pub fn left_iter(&self) -> list::Iter<'a, T> { self.left.iter() }
+
+    /// Returns an iterator over the elements to the right of the cursor,
+    /// nearest first.
+    #[inline] pub fn right_iter(&self) -> list::Iter<'a, T> {
+        self.right.iter()
+    }
+
+    /// Borrow the element to the left of the cursor.
+    #[inline] pub fn peek_left(&self) -> Option<&'a T> { self.left.peek() }
+
+    /// Borrow the element to the right of the cursor.
+    #[inline] pub fn peek_right(&self) -> Option<&'a T> { self.right.peek() }
+
+    /// Borrow the element at `offset` from the cursor.
+    ///
+    /// As with [`ZipList::for_each_with_offset`], elements to the left of
+    /// the cursor have negative offsets starting at -1, and elements to the
+    /// right have positive offsets starting at 1. There's no element at
+    /// offset 0.
+    ///
+    /// # Time complexity
+    /// O(`offset`)
+    ///
+    /// [`ZipList::for_each_with_offset`]:
+    ///     struct.ZipList.html#method.for_each_with_offset
+    pub fn peek(&self, offset: isize) -> Option<&'a T> {
+        if offset < 0 {
+            self.left.iter().nth((-(offset + 1)) as usize)
+        } else if offset > 0 {
+            self.right.iter().nth((offset - 1) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Borrow the element at `index`, counting from the left end.
+    ///
+    /// # Time complexity
+    /// O(distance from the cursor to `index`)
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let cursor = self.cursor();
+        if index < cursor {
+            self.left.iter().nth(cursor - 1 - index)
+        } else {
+            self.right.iter().nth(index - cursor)
+        }
+    }
+}
+
+impl<'a, T> Clone for ZipView<'a, T> {
+    #[inline] fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Copy for ZipView<'a, T> {}
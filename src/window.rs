@@ -0,0 +1,123 @@
+use std::fmt;
+
+use super::ZipList;
+
+/// A window over the most recent elements of a stream, which holds at most
+/// a fixed number of them, evicting the oldest as new ones are pushed.
+///
+/// The elements are kept in a zipper, used as a pair of stacks: new
+/// elements are pushed on the left, and the oldest are popped from the
+/// right. When the right runs out, the elements on the left are moved over
+/// to it in one go, so pushing takes amortized O(1) time.
+///
+/// ```
+/// # use an_zipper::SlidingWindow;
+/// let mut window = SlidingWindow::new(3);
+/// for i in 0..5 { window.push(i); }
+/// assert_eq!(window.front(), Some(&2));
+/// assert_eq!(window.back(), Some(&4));
+/// assert_eq!(window.iter().sum::<i32>(), 9);
+/// ```
+#[derive(Clone)]
+pub struct SlidingWindow<T> { /// Newer elements on the left, newest
+                              /// nearest the cursor, and older elements on
+                              /// the right, oldest nearest. If there are two
+                              /// or more elements, neither side is empty.
+                              zipper: ZipList<T>
+                            , width: usize
+                            }
+
+impl<T> SlidingWindow<T> {
+    /// Create a new empty `SlidingWindow` which holds up to `width`
+    /// elements.
+    pub const fn new(width: usize) -> Self {
+        SlidingWindow { zipper: ZipList::new(), width }
+    }
+
+    /// Returns the most elements the window holds.
+    #[inline] pub fn width(&self) -> usize { self.width }
+
+    /// Returns the number of elements in the window.
+    #[inline] pub fn len(&self) -> usize { self.zipper.len() }
+
+    /// Returns true if the window has no elements.
+    #[inline] pub fn is_empty(&self) -> bool { self.zipper.is_empty() }
+
+    /// Returns true if pushing another element will evict the oldest one.
+    #[inline] pub fn is_full(&self) -> bool { self.len() >= self.width }
+
+    /// Add `elem` as the newest element of the window, evicting the oldest
+    /// element if the window was full.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the evicted element, if the window was full. If the
+    ///   window's width is 0, this is `elem` itself.
+    /// - `None` otherwise
+    ///
+    /// # Time complexity
+    /// Amortized O(1)
+    pub fn push(&mut self, elem: T) -> Option<T> {
+        if self.width == 0 { return Some(elem) }
+        self.zipper.push_left(elem);
+        self.refill();
+        if self.len() > self.width { self.pop_front() } else { None }
+    }
+
+    /// Remove the oldest element of the window.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the window wasn't empty
+    /// - `None` otherwise
+    ///
+    /// # Time complexity
+    /// Amortized O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        let oldest = self.zipper.pop_right()
+                         .or_else(|| self.zipper.pop_left());
+        self.refill();
+        oldest
+    }
+
+    /// Borrow the oldest element of the window.
+    #[inline] pub fn front(&self) -> Option<&T> {
+        self.zipper.peek_right().or_else(|| self.zipper.peek_left())
+    }
+
+    /// Borrow the newest element of the window.
+    #[inline] pub fn back(&self) -> Option<&T> {
+        self.zipper.peek_left().or_else(|| self.zipper.peek_right())
+    }
+
+    /// Returns an iterator over the elements of the window, from oldest to
+    /// newest.
+    ///
+    /// # Time complexity
+    /// O(number of elements pushed since the older elements were last
+    /// moved over) to create the iterator, and O(1) per element.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let newer = self.zipper.left_iter().collect::<Vec<_>>();
+        self.zipper.right_iter().chain(newer.into_iter().rev())
+    }
+
+    /// Remove every element from the window.
+    pub fn clear(&mut self) { self.zipper.truncate(0) }
+
+    /// Move all but the newest element over to the right once the right
+    /// runs out, so that both the oldest and the newest are at the cursor.
+    fn refill(&mut self) {
+        let newer = self.zipper.as_lists().0.len();
+        if self.zipper.peek_right().is_some() || newer < 2 { return }
+        // moving the left side over reverses it, so the oldest element ends
+        // up nearest the cursor on the right.
+        let newest = self.zipper.pop_left();
+        self.zipper.seek_left(newer - 1);
+        self.zipper.push_left(newest.unwrap());
+    }
+}
+
+impl<T> fmt::Debug for SlidingWindow<T>
+where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,68 @@
+//! Wiping lists and zippers which hold secrets, using [zeroize].
+//!
+//! With the `zeroize` feature, a node's memory is wiped as soon as its
+//! element is moved out, before the node goes onto the freelist. Popping a
+//! passphrase's characters one at a time leaves no copies of them behind in
+//! free nodes, to be read back by a later push or left in freed memory.
+//! Nodes freed along with a list, including the parts of a zipper thrown
+//! away by `truncate`, are wiped after their elements are dropped, so
+//! elements such as `[u8; 32]` which don't wipe themselves aren't left
+//! behind either.
+//!
+//! [`Zeroize`] wipes every element in place and then removes them, and a
+//! list or zipper of elements which wipe themselves when they're dropped
+//! does too, so it's [`ZeroizeOnDrop`].
+//!
+//! [zeroize]: https://docs.rs/zeroize
+//! [`Zeroize`]: https://docs.rs/zeroize/1/zeroize/trait.Zeroize.html
+//! [`ZeroizeOnDrop`]: https://docs.rs/zeroize/1/zeroize/trait.ZeroizeOnDrop.html
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::{List, Node, Stack, ZipList};
+
+#[cfg(test)] mod test;
+
+/// Drop the element in an unlinked `node`, and wipe the node's memory
+/// before it's freed.
+pub(crate) fn drop_node<T>(node: Box<Node<T>>) {
+    debug_assert!(node.next.is_none(), "dropped a linked node");
+    // this is safe because the two node types have the same layout, and
+    // after `elem` is dropped the node is only treated as uninitialized.
+    let mut node = unsafe {
+        Box::from_raw(Box::into_raw(node) as *mut Node<MaybeUninit<T>>)
+    };
+    unsafe { ptr::drop_in_place(node.elem.as_mut_ptr()) };
+    node.elem.zeroize();
+}
+
+impl<T> Zeroize for List<T>
+where T: Zeroize {
+    /// Wipe every element in place, and then remove them all.
+    ///
+    /// The nodes stay on the freelist to be reused, wiped.
+    fn zeroize(&mut self) {
+        for elem in self.iter_mut() { elem.zeroize() }
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T> ZeroizeOnDrop for List<T>
+where T: ZeroizeOnDrop {}
+
+impl<T> Zeroize for ZipList<T>
+where T: Zeroize {
+    /// Wipe every element in place, and then remove them all, leaving the
+    /// zipper as if it were new.
+    fn zeroize(&mut self) {
+        self.left.zeroize();
+        self.right.zeroize();
+        self.committed = 0;
+        self.consumed = 0;
+    }
+}
+
+impl<T> ZeroizeOnDrop for ZipList<T>
+where T: ZeroizeOnDrop {}
@@ -0,0 +1,101 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::slice;
+
+use zeroize::Zeroize;
+
+use ::{List, Stack, ZipList};
+
+const SECRET: [u8; 16] = [0xA5; 16];
+
+thread_local! {
+    static SCANNING: Cell<bool> = const { Cell::new(false) };
+    static LEFT_BEHIND: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Passes everything through to the system allocator, but while
+/// `SCANNING` is set, counts the blocks freed on this thread which still
+/// hold a copy of `SECRET`.
+struct Scanner;
+
+unsafe impl GlobalAlloc for Scanner {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, block: *mut u8, layout: Layout) {
+        if SCANNING.try_with(Cell::get).unwrap_or(false) {
+            let bytes = slice::from_raw_parts(block, layout.size());
+            if bytes.windows(SECRET.len()).any(|window| window == SECRET) {
+                LEFT_BEHIND.with(|n| n.set(n.get() + 1));
+            }
+        }
+        System.dealloc(block, layout)
+    }
+}
+
+#[global_allocator]
+static SCANNER: Scanner = Scanner;
+
+/// Returns the number of blocks freed by `f` which still held `SECRET`.
+fn secrets_freed_by<F: FnOnce()>(f: F) -> usize {
+    LEFT_BEHIND.with(|n| n.set(0));
+    SCANNING.with(|scanning| scanning.set(true));
+    f();
+    SCANNING.with(|scanning| scanning.set(false));
+    LEFT_BEHIND.with(Cell::get)
+}
+
+/// Returns the bytes left in the nodes on `list`'s freelist.
+fn free_bytes(list: &List<[u8; 4]>) -> Vec<[u8; 4]> {
+    let mut bytes = Vec::new();
+    let mut free = list.free.as_ref();
+    while let Some(node) = free {
+        // free nodes are wiped, and zeroed bytes are a valid `[u8; 4]`
+        bytes.push(unsafe { node.elem.assume_init() });
+        free = node.next.as_ref();
+    }
+    bytes
+}
+
+#[test]
+fn popped_nodes_are_wiped() {
+    let mut list = List::new();
+    list.push([0xAA; 4]).push([0xBB; 4]);
+    assert_eq!(list.pop(), Some([0xBB; 4]));
+    assert_eq!(free_bytes(&list), vec![[0; 4]]);
+    // reusing the wiped node works as usual
+    list.push([0xCC; 4]);
+    assert_eq!(list.iter().collect::<Vec<_>>(), vec![&[0xCC; 4], &[0xAA; 4]]);
+}
+
+#[test]
+fn zeroize_wipes_and_empties() {
+    let mut list = vec![vec![1u8, 2, 3], vec![4]].into_iter()
+                                                  .collect::<List<_>>();
+    list.zeroize();
+    assert!(list.is_empty());
+
+    let mut zipper = ZipList::from_iter_with_cursor(vec![[1u8; 4]; 4], 2);
+    zipper.commit_left();
+    zipper.seek_right(1);
+    zipper.zeroize();
+    assert!(zipper.is_empty());
+    assert_eq!(zipper.offset(), 0);
+    assert_eq!(free_bytes(&zipper.left), vec![[0; 4]]);
+    assert_eq!(free_bytes(&zipper.right), vec![[0; 4]]);
+}
+
+#[test]
+fn freed_nodes_are_wiped() {
+    // the scanner does see secrets in memory that isn't wiped
+    let unwiped = vec![SECRET; 4];
+    assert_eq!(secrets_freed_by(|| drop(unwiped)), 1);
+
+    let list = List::from_fn(4, |_| SECRET);
+    assert_eq!(secrets_freed_by(|| drop(list)), 0);
+
+    let mut zipper = ZipList::from_iter_with_cursor(vec![SECRET; 6], 3);
+    assert_eq!(secrets_freed_by(|| zipper.truncate(1)), 0);
+    assert_eq!(zipper.len(), 1);
+}
@@ -0,0 +1,192 @@
+use std::iter;
+use std::vec;
+
+use super::{ZipList, list};
+use error;
+
+impl<T> ZipList<T> {
+    /// Returns an iterator over all of the zipper's elements, from left to
+    /// right, which can also be iterated from the right end.
+    ///
+    /// The left side is stored nearest-first, so the first time the iterator
+    /// is advanced from the front into it, the references to the elements
+    /// of the left side that remain are collected, to be yielded in reverse.
+    /// Advancing from the back into the right side does the same for the
+    /// right side.
+    ///
+    /// # Time complexity
+    /// O(1) to create the iterator, and amortized O(1) per element.
+    pub fn iter(&self) -> ZipIter<'_, T> {
+        ZipIter { left: Half::Linked(self.left.iter())
+                , right: Half::Linked(self.right.iter())
+                }
+    }
+
+    /// Returns an iterator over all of the zipper's elements, from left to
+    /// right, paired with their offsets from the cursor.
+    ///
+    /// As with [`walk`](#method.walk), the elements to the left of the
+    /// cursor have offsets counting down from -1 as they get farther away,
+    /// and those to its right count up from 1, so the first element yielded
+    /// has offset `-n`, where `n` is the number of elements to the left.
+    ///
+    /// # Time complexity
+    /// As for [`iter`](#method.iter).
+    pub fn enumerate_from_cursor(&self) -> EnumerateFromCursor<'_, T> {
+        let left = error::signed(self.left.len());
+        let right = error::signed(self.right.len());
+        EnumerateFromCursor { iter: self.iter()
+                            , front: if left > 0 { -left } else { 1 }
+                            , back: if right > 0 { right } else { -1 }
+                            }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ZipList<T> {
+    type IntoIter = ZipIter<'a, T>;
+    type Item = &'a T;
+    #[inline] fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// A double-ended iterator over references to the elements of a `ZipList`,
+/// in order.
+///
+/// This is created by [`ZipList::iter`].
+///
+/// [`ZipList::iter`]: struct.ZipList.html#method.iter
+pub struct ZipIter<'a, T: 'a> { left: Half<'a, T>
+                              , right: Half<'a, T>
+                              }
+
+/// The elements remaining on one side of the zipper, nearest the cursor
+/// first.
+enum Half<'a, T: 'a> { /// Still walking the list.
+                       Linked(list::Iter<'a, T>)
+                     , /// Collected so that the far end can be reached.
+                       Flipped(vec::IntoIter<&'a T>)
+                     }
+
+impl<'a, T> Half<'a, T> {
+    /// Returns the remaining element nearest the cursor.
+    fn near(&mut self) -> Option<&'a T> {
+        match *self {
+            Half::Linked(ref mut iter) => iter.next()
+          , Half::Flipped(ref mut iter) => iter.next()
+        }
+    }
+
+    /// Returns the remaining element farthest from the cursor.
+    fn far(&mut self) -> Option<&'a T> {
+        if let Half::Linked(ref mut iter) = *self {
+            let elems = iter.collect::<Vec<_>>();
+            *self = Half::Flipped(elems.into_iter());
+        }
+        match *self {
+            Half::Flipped(ref mut iter) => iter.next_back()
+          , Half::Linked(_) => unreachable!()
+        }
+    }
+
+    fn len(&self) -> usize {
+        match *self {
+            Half::Linked(ref iter) => iter.len()
+          , Half::Flipped(ref iter) => iter.len()
+        }
+    }
+}
+
+impl<'a, T> Iterator for ZipIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.left.len() > 0 { self.left.far() } else { self.right.near() }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline] fn count(self) -> usize { self.len() }
+}
+
+impl<'a, T> DoubleEndedIterator for ZipIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.right.len() > 0 { self.right.far() } else { self.left.near() }
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for ZipIter<'a, T> {
+    #[inline] fn len(&self) -> usize { self.left.len() + self.right.len() }
+}
+
+impl<'a, T> iter::FusedIterator for ZipIter<'a, T> {}
+
+impl<'a, T> Clone for ZipIter<'a, T> {
+    fn clone(&self) -> Self {
+        ZipIter { left: self.left.clone(), right: self.right.clone() }
+    }
+}
+
+impl<'a, T> Clone for Half<'a, T> {
+    fn clone(&self) -> Self {
+        match *self {
+            Half::Linked(ref iter) => Half::Linked(iter.clone())
+          , Half::Flipped(ref iter) => Half::Flipped(iter.clone())
+        }
+    }
+}
+
+/// An iterator over the elements of a `ZipList`, in order, paired with their
+/// offsets from the cursor.
+///
+/// This is created by calling `enumerate_from_cursor()` on a `ZipList`.
+pub struct EnumerateFromCursor<'a, T: 'a> { iter: ZipIter<'a, T>
+                                          , /// The offset of the next
+                                            /// element from the front.
+                                            front: isize
+                                          , /// The offset of the next
+                                            /// element from the back.
+                                            back: isize
+                                          }
+
+/// Returns the offset after `offset`, skipping over the cursor at 0.
+fn step(offset: isize, by: isize) -> isize {
+    match offset + by { 0 => by, next => next }
+}
+
+impl<'a, T> Iterator for EnumerateFromCursor<'a, T> {
+    type Item = (isize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.iter.next()?;
+        let offset = self.front;
+        self.front = step(offset, 1);
+        Some((offset, elem))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator for EnumerateFromCursor<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let elem = self.iter.next_back()?;
+        let offset = self.back;
+        self.back = step(offset, -1);
+        Some((offset, elem))
+    }
+}
+
+impl<'a, T> iter::ExactSizeIterator for EnumerateFromCursor<'a, T> {
+    #[inline] fn len(&self) -> usize { self.iter.len() }
+}
+
+impl<'a, T> iter::FusedIterator for EnumerateFromCursor<'a, T> {}
+
+impl<'a, T> Clone for EnumerateFromCursor<'a, T> {
+    fn clone(&self) -> Self {
+        EnumerateFromCursor { iter: self.iter.clone(), ..*self }
+    }
+}